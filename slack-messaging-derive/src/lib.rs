@@ -2,20 +2,23 @@ use darling::FromDeriveInput;
 use proc_macro::TokenStream;
 use proc_macro_error::{abort, proc_macro_error};
 use quote::{format_ident, quote};
-use syn::{DeriveInput, parse_macro_input};
+use syn::{DeriveInput, Token, parse_macro_input, punctuated::Punctuated};
 
 mod types;
 mod utils;
+mod validate;
 
 // The main struct we get from parsing the attributes
 // Ref: https://github.com/TedDriggs/darling?tab=readme-ov-file#shape-validation
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(builder), supports(struct_named))]
+#[darling(attributes(builder), forward_attrs(serde), supports(struct_named))]
 struct Target {
     ident: syn::Ident,
     generics: syn::Generics,
+    attrs: Vec<syn::Attribute>,
     data: darling::ast::Data<(), types::Field>,
     validate: Option<darling::Result<syn::Path>>,
+    no_deserialize: Option<bool>,
 }
 
 impl Target {
@@ -40,6 +43,284 @@ impl Target {
         self.fields().len() > 1
     }
 
+    // Splits the serde attributes of the struct into the `tag` and `rename` pair, which identifies
+    // the `type` field of the JSON object, and the rest of them.
+    fn serde_attrs(&self) -> (Option<(syn::LitStr, syn::LitStr)>, Vec<syn::Meta>) {
+        let mut tag: Option<syn::LitStr> = None;
+        let mut rename: Option<syn::LitStr> = None;
+        let mut others: Vec<syn::Meta> = vec![];
+
+        for attr in self.attrs.iter() {
+            let metas =
+                match attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated) {
+                    Ok(metas) => metas,
+                    Err(err) => abort!(err.span(), "Invalid attribute #[serde(...)]"),
+                };
+
+            for meta in metas {
+                match &meta {
+                    syn::Meta::NameValue(nv) if nv.path.is_ident("tag") => {
+                        tag = utils::get_lit_str(&nv.value);
+                    }
+                    syn::Meta::NameValue(nv) if nv.path.is_ident("rename") => {
+                        rename = utils::get_lit_str(&nv.value);
+                    }
+                    // these attributes are for the target object, not for the intermediate one.
+                    syn::Meta::NameValue(nv)
+                        if nv.path.is_ident("try_from") || nv.path.is_ident("from") => {}
+                    _ => others.push(meta),
+                }
+            }
+        }
+
+        match (tag, rename) {
+            (Some(tag), Some(rename)) => (Some((tag, rename)), others),
+            (tag, rename) => {
+                others.extend(tag.map(|v| syn::parse_quote! { tag = #v }));
+                others.extend(rename.map(|v| syn::parse_quote! { rename = #v }));
+                (None, others)
+            }
+        }
+    }
+
+    // Deserialize implementations of the builder object and the target object. The JSON object
+    // is deserialized into an intermediate object first, and then each field is set to the
+    // builder through the same constructor the setter methods use. The target object is built
+    // from the builder, so that deserializing runs the same validations as the build method.
+    //
+    // DeserializeLenient of the target object, used for payloads Slack sends, deserializes the
+    // fields leniently in turn and builds the object without the validations.
+    fn deserialize_impl(&self) -> proc_macro2::TokenStream {
+        if self.no_deserialize.is_some_and(|v| v) {
+            return quote! {};
+        }
+
+        let ident = &self.ident;
+        let builder = format_ident!("{ident}Builder");
+        let (imp, ty, whr) = self.generics.split_for_impl();
+
+        let fields = self.fields();
+        let phantom_params: Vec<String> = fields
+            .iter()
+            .filter_map(types::Field::phantom_param)
+            .collect();
+
+        let mut de_generics = self.generics.clone();
+        de_generics.params.insert(0, syn::parse_quote! { 'de });
+        let mut lenient_generics = de_generics.clone();
+        let de_where = de_generics.make_where_clause();
+        let lenient_where = lenient_generics.make_where_clause();
+        for param in self.generics.type_params() {
+            let param = &param.ident;
+            if !phantom_params.contains(&param.to_string()) {
+                de_where
+                    .predicates
+                    .push(syn::parse_quote! { #param: ::serde::Deserialize<'de> });
+                lenient_where
+                    .predicates
+                    .push(syn::parse_quote! { #param: crate::de::DeserializeLenient<'de> });
+            }
+        }
+        let (de_imp, _, de_whr) = de_generics.split_for_impl();
+        let (_, _, lenient_whr) = lenient_generics.split_for_impl();
+
+        // serde bounds the type parameters of the intermediate object by Deserialize, while the
+        // lenient one deserializes them through DeserializeLenient instead.
+        let lenient_bound = {
+            let bounds: Vec<String> = self
+                .generics
+                .type_params()
+                .map(|param| param.ident.to_string())
+                .filter(|param| !phantom_params.contains(param))
+                .map(|param| format!("{param}: crate::de::DeserializeLenient<'de>"))
+                .collect();
+            let bounds = bounds.join(", ");
+            quote! { #[serde(bound(deserialize = #bounds))] }
+        };
+
+        let raw_fields: Vec<proc_macro2::TokenStream> =
+            fields.iter().map(types::Field::raw_field).collect();
+        let lenient_raw_fields: Vec<proc_macro2::TokenStream> =
+            fields.iter().map(types::Field::lenient_raw_field).collect();
+        let field_names: Vec<proc_macro2::TokenStream> =
+            fields.iter().map(types::Field::field_name).collect();
+        let set_fields = fields.iter().map(|f| {
+            let ident = f.ident();
+            if f.is_phantom() {
+                quote! { #ident }
+            } else {
+                let constructor = f.field_constructor_name();
+                quote! { #ident: Self::#constructor(#ident) }
+            }
+        });
+        let set_lenient_fields = fields.iter().map(|f| {
+            let ident = f.ident();
+            if f.is_phantom() {
+                quote! { #ident }
+            } else {
+                let constructor = f.field_constructor_name();
+                let value = f.lenient_raw_value();
+                quote! { #ident: <#builder #ty>::#constructor(#value) }
+            }
+        });
+
+        let static_str_alias = if fields.iter().any(types::Field::is_static_str) {
+            quote! { type StaticStr = &'static str; }
+        } else {
+            quote! {}
+        };
+
+        let (serde_tag, serde_others) = self.serde_attrs();
+        let raw_object = |raw_fields: &[proc_macro2::TokenStream], bound| match &serde_tag {
+            Some((tag, rename)) => quote! {
+                #[derive(::serde::Deserialize)]
+                #bound
+                #[serde(tag = #tag, #(#serde_others),*)]
+                enum Raw #imp #whr {
+                    #[serde(rename = #rename)]
+                    Tagged { #(#raw_fields),* }
+                }
+            },
+            None => {
+                let attrs = if serde_others.is_empty() {
+                    quote! {}
+                } else {
+                    quote! { #[serde(#(#serde_others),*)] }
+                };
+                quote! {
+                    #[derive(::serde::Deserialize)]
+                    #bound
+                    #attrs
+                    struct Raw #imp #whr { #(#raw_fields),* }
+                }
+            }
+        };
+        let raw = raw_object(&raw_fields, quote! {});
+        let lenient_raw = raw_object(&lenient_raw_fields, lenient_bound);
+        let destruct = match &serde_tag {
+            Some(_) => quote! { Raw::Tagged { #(#field_names),* } },
+            None => quote! { Raw { #(#field_names),* } },
+        };
+
+        quote! {
+            impl #de_imp ::serde::Deserialize<'de> for #builder #ty #de_whr {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    #static_str_alias
+
+                    #raw
+
+                    let #destruct = <Raw #ty as ::serde::Deserialize>::deserialize(deserializer)?;
+
+                    ::std::result::Result::Ok(Self {
+                        #(#set_fields),*
+                    })
+                }
+            }
+
+            impl #de_imp ::serde::Deserialize<'de> for #ident #ty #de_whr {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    <#builder #ty as ::serde::Deserialize>::deserialize(deserializer)?
+                        .build()
+                        .map_err(<D::Error as ::serde::de::Error>::custom)
+                }
+            }
+
+            impl #de_imp crate::de::DeserializeLenient<'de> for #ident #ty #lenient_whr {
+                fn deserialize_lenient<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    #static_str_alias
+
+                    #lenient_raw
+
+                    let #destruct = <Raw #ty as ::serde::Deserialize>::deserialize(deserializer)?;
+
+                    let builder = #builder {
+                        #(#set_lenient_fields),*
+                    };
+                    ::std::result::Result::Ok(builder.build_unchecked())
+                }
+            }
+        }
+    }
+
+    // Validate implementation of the target object. It runs the validations of each field and
+    // across fields again against the built object, borrowing the fields rather than cloning them,
+    // then descends into the field values so that errors of nested objects are reported with the
+    // path to them.
+    fn validate_impl(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let builder = format_ident!("{ident}Builder");
+        let (_, ty, _) = self.generics.split_for_impl();
+
+        let fields: Vec<types::Field> = self
+            .fields()
+            .into_iter()
+            .filter(|f| !f.is_phantom())
+            .collect();
+        let phantom_params: Vec<String> = self
+            .fields()
+            .iter()
+            .filter_map(types::Field::phantom_param)
+            .collect();
+
+        let mut generics = self.generics.clone();
+        let where_clause = generics.make_where_clause();
+        for param in self.generics.type_params() {
+            let param = &param.ident;
+            if !phantom_params.contains(&param.to_string()) {
+                where_clause.predicates.push(syn::parse_quote! {
+                    #param: crate::validate::Validate
+                });
+            }
+        }
+        let (imp, _, whr) = generics.split_for_impl();
+
+        let validate_across_fields = match self.validate() {
+            Some(path) => quote! { #path(self) },
+            None => quote! { ::std::vec::Vec::new() },
+        };
+
+        let validate_fields = fields.iter().map(|f| {
+            let field = f.ident();
+            let name = utils::strip_raw_ident(&f.ident_str()).to_string();
+            let validate_field = if f.has_validators() {
+                let validator = f.field_validator_name();
+                quote! {
+                    let field_errors = <#builder #ty>::#validator(self.#field.as_ref());
+                    crate::validate::push_errors(errors, &field_path, stringify!(#ident), field_errors);
+                }
+            } else {
+                quote! {}
+            };
+            quote! {
+                let field_path = crate::validate::field_path(path, #name);
+                #validate_field
+                crate::validate::Validate::validate_at(&self.#field, &field_path, errors);
+            }
+        });
+
+        quote! {
+            impl #imp crate::validate::Validate for #ident #ty #whr {
+                fn validate_at(
+                    &self,
+                    path: &str,
+                    errors: &mut ::std::vec::Vec<crate::errors::NestedValidationError>,
+                ) {
+                    crate::validate::push_errors(errors, path, stringify!(#ident), #validate_across_fields);
+                    #({ #validate_fields })*
+                }
+            }
+        }
+    }
+
     fn into_token_strem(self) -> TokenStream {
         if self.data.is_enum() {
             panic!("enum is not supported")
@@ -52,6 +333,9 @@ impl Target {
         let doc_builder_method = format!("constract [`{builder}`] object.");
         let doc_builder = format!("Builder for [`{ident}`] object.");
         let doc_build_method = format!("build [`{ident}`] object.");
+        let doc_build_unchecked_method = format!(
+            "build [`{ident}`] object without validations. Call [`Validate::validate`](crate::Validate::validate) on the object containing it to get all errors at once."
+        );
 
         let validate_across_fields = match self.validate() {
             Some(path) => quote! { #path(value) },
@@ -80,7 +364,10 @@ impl Target {
             .map(|f| f.builder_accessors(has_multi_fields));
 
         let fields = self.fields();
-        let build_target_fields = fields.iter().map(types::Field::build_target_field);
+        let build_target_fields: Vec<proc_macro2::TokenStream> = fields
+            .iter()
+            .map(types::Field::build_target_field)
+            .collect();
         let init_builder_fields = fields.iter().map(types::Field::init_builder_field);
         let expand_builder_fields = if fields.iter().any(types::Field::is_phantom) {
             quote! { #(#builder_field_names_0),* , .. }
@@ -88,6 +375,8 @@ impl Target {
             quote! { #(#builder_field_names_0),* }
         };
         let default_fields = fields.iter().map(types::Field::default_field);
+        let deserialize = self.deserialize_impl();
+        let validate = self.validate_impl();
 
         quote! {
             impl #imp #ident #ty #whr {
@@ -136,6 +425,15 @@ impl Target {
                         return ::std::result::Result::Ok(built);
                     }
                 }
+
+                #[doc = #doc_build_unchecked_method]
+                pub fn build_unchecked(self) -> #ident #ty {
+                    let Self { #expand_builder_fields } = self;
+
+                    #ident {
+                        #(#build_target_fields),*
+                    }
+                }
             }
 
             impl #imp ::std::default::Default for #builder #ty #whr {
@@ -145,11 +443,22 @@ impl Target {
                     }
                 }
             }
+
+            #deserialize
+
+            #validate
         }
         .into()
     }
 }
 
+#[proc_macro_error]
+#[proc_macro_derive(Validate)]
+pub fn validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    validate::expand(input).into()
+}
+
 #[proc_macro_error]
 #[proc_macro_derive(Builder, attributes(builder))]
 pub fn builder(input: TokenStream) -> TokenStream {
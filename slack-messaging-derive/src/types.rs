@@ -2,13 +2,14 @@ use super::utils::*;
 
 use darling::FromField;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote};
 
 #[derive(Debug, FromField, Clone)]
-#[darling(attributes(builder), forward_attrs)]
+#[darling(attributes(builder), forward_attrs(serde))]
 pub struct Field {
     pub ident: Option<syn::Ident>,
     pub ty: syn::Type,
+    pub attrs: Vec<syn::Attribute>,
     pub private_setter: Option<bool>,
     pub push_item: Option<syn::Expr>,
     pub no_accessors: Option<bool>,
@@ -54,6 +55,15 @@ impl Field {
         quote! { #constructor_name }
     }
 
+    pub fn field_validator_name(&self) -> TokenStream {
+        let validator_name = format_ident!("validate_{}", strip_raw_ident(&self.ident_str()));
+        quote! { #validator_name }
+    }
+
+    pub fn has_validators(&self) -> bool {
+        !self.validate.is_empty()
+    }
+
     pub fn default_field(&self) -> TokenStream {
         let ident = self.ident();
 
@@ -77,23 +87,116 @@ impl Field {
         }
     }
 
+    pub fn phantom_param(&self) -> Option<String> {
+        self.phantom.as_ref().map(syn::LitStr::value)
+    }
+
+    pub fn raw_field(&self) -> TokenStream {
+        let ident = self.ident();
+        let ty = &self.ty;
+
+        if self.is_phantom() {
+            quote! {
+                #[serde(skip)]
+                #ident: #ty
+            }
+        } else if self.is_static_str() {
+            // serde implicitly borrows `&str` fields from the input, which requires `'de: 'static`
+            // for the deserializer. The `StaticStr` alias hides the reference from serde.
+            let attrs = &self.attrs;
+            quote! {
+                #(#attrs)*
+                #ident: ::std::option::Option<StaticStr>
+            }
+        } else {
+            let attrs = &self.attrs;
+            quote! {
+                #(#attrs)*
+                #ident: #ty
+            }
+        }
+    }
+
+    // Field of the intermediate object for DeserializeLenient. The value is deserialized through
+    // `crate::de::Lenient`, unless the field has its own deserializer.
+    pub fn lenient_raw_field(&self) -> TokenStream {
+        if self.is_phantom() || self.has_custom_deserializer() {
+            return self.raw_field();
+        }
+
+        let ident = self.ident();
+        let ty = self.inner_ty();
+        let attrs = &self.attrs;
+        quote! {
+            #(#attrs)*
+            #ident: ::std::option::Option<crate::de::Lenient<#ty>>
+        }
+    }
+
+    pub fn lenient_raw_value(&self) -> TokenStream {
+        let ident = self.ident();
+
+        if self.is_phantom() || self.has_custom_deserializer() {
+            quote! { #ident }
+        } else {
+            quote! { #ident.map(|v| v.0) }
+        }
+    }
+
+    fn has_custom_deserializer(&self) -> bool {
+        self.attrs.iter().any(|attr| {
+            let tokens = attr.meta.to_token_stream().to_string();
+            tokens.contains("deserialize_with") || tokens.contains("with =")
+        })
+    }
+
+    // Maximum length of the text field taken from the `text::max_*` or `text_object::max_*`
+    // validator, if any.
+    pub fn max_text_length(&self) -> Option<usize> {
+        self.validate.iter().find_map(|lit| {
+            let value = lit.value();
+            let (module, func) = value.trim().rsplit_once("::")?;
+            if module != "text" && module != "text_object" {
+                return None;
+            }
+            func.strip_prefix("max_")?.parse().ok()
+        })
+    }
+
+    pub fn is_static_str(&self) -> bool {
+        !self.is_phantom() && is_static_str_ref(self.inner_ty())
+    }
+
     pub fn builder_accessors(&self, has_multi_fields: bool) -> TokenStream {
         let ident = self.ident();
         let ty = self.inner_ty();
 
         let constructor_name = self.field_constructor_name();
-        let constructor_fn = if !self.validate.is_empty() {
+        let validator_name = self.field_validator_name();
+        let (constructor_fn, validator_fn) = if self.has_validators() {
             let exprs: Vec<TokenStream> = self
                 .validate
                 .clone()
                 .iter()
                 .map(|lit| lit.value().parse().unwrap())
                 .collect();
-            quote! {
-                pipe! { crate::value::Value::new(value) => #(#exprs)|* }
-            }
+            (
+                quote! {
+                    crate::value::Value {
+                        errors: Self::#validator_name(value.as_ref()),
+                        inner: value,
+                    }
+                },
+                quote! {
+                    fn #validator_name(
+                        value: ::std::option::Option<&#ty>,
+                    ) -> ::std::vec::Vec<crate::errors::ValidationErrorKind> {
+                        pipe! { crate::value::ValueRef::new(value) => #(#exprs)|* }.errors
+                    }
+                },
+            )
         } else {
-            quote! { crate::value::Value::new(value) }
+            (quote! { crate::value::Value::new(value) }, quote! {})
         };
 
         let getter = format_ident!("get_{}", strip_raw_ident(&self.ident_str()));
@@ -146,6 +249,28 @@ impl Field {
             _ => quote! {},
         };
 
+        let remaining = match self.max_text_length() {
+            Some(max) => {
+                let remaining = format_ident!("remaining_{}", strip_raw_ident(&self.ident_str()));
+                let doc = format!(
+                    "get the number of characters {ident} field can take more, up to {max}."
+                );
+
+                quote! {
+                    #[doc = #doc]
+                    pub fn #remaining(&self) -> usize {
+                        let len = self
+                            .#ident
+                            .inner_ref()
+                            .map(crate::validators::TextLength::text_length)
+                            .unwrap_or_default();
+                        #max.saturating_sub(len)
+                    }
+                }
+            }
+            None => quote! {},
+        };
+
         let doc_getter = format!("get {ident} field value.");
         let doc_setter = format!("set {ident} field value.");
 
@@ -172,6 +297,8 @@ impl Field {
                 }
 
                 #push_item
+
+                #remaining
             }
         };
 
@@ -180,6 +307,8 @@ impl Field {
                 #constructor_fn
             }
 
+            #validator_fn
+
             #accessors
         }
     }
@@ -150,3 +150,14 @@ pub fn get_vec_inner_type(ty: &syn::Type) -> Option<syn::Type> {
 pub fn strip_raw_ident(ident: &str) -> &str {
     ident.trim_start_matches("r#")
 }
+
+pub fn get_lit_str(expr: &syn::Expr) -> Option<syn::LitStr> {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    }) = expr
+    {
+        return Some(lit.clone());
+    }
+    None
+}
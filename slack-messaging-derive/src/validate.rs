@@ -0,0 +1,55 @@
+use proc_macro_error::abort;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+// Validate implementation of enums. Each variant either has no fields or wraps exactly one value,
+// whose validation is delegated to at the same path, since the variants do not appear in JSON.
+pub fn expand(input: DeriveInput) -> TokenStream {
+    let ident = &input.ident;
+    let (imp, ty, whr) = input.generics.split_for_impl();
+
+    let Data::Enum(data) = &input.data else {
+        abort!(
+            ident, "#[derive(Validate)] supports only enums";
+            help = "Structs implement Validate through #[derive(Builder)]"
+        );
+    };
+
+    let arms = data.variants.iter().map(|variant| {
+        let name = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote! { Self::#name => {} },
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                Self::#name(inner) => crate::validate::Validate::validate_at(inner, path, errors)
+            },
+            _ => abort!(
+                variant,
+                "#[derive(Validate)] supports only unit variants and variants with one field"
+            ),
+        }
+    });
+
+    let body = if data.variants.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            match self {
+                #(#arms),*
+            }
+        }
+    };
+
+    quote! {
+        impl #imp crate::validate::Validate for #ident #ty #whr {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                path: &str,
+                errors: &mut ::std::vec::Vec<crate::errors::NestedValidationError>,
+            ) {
+                #body
+            }
+        }
+    }
+}
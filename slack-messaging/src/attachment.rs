@@ -0,0 +1,445 @@
+use crate::blocks::Block;
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// [Secondary attachment](https://docs.slack.dev/messaging/formatting-message-text#attachments)
+/// representation.
+///
+/// Attachments are legacy but still the only way to show a colored bar alongside the message
+/// content. Blocks can be nested in the `blocks` field.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/messaging/attachments).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | color | String | No | Hex color code or one of `good`, `warning` and `danger` |
+/// | fallback | String | Conditionally* | N/A |
+/// | blocks | Vec<[Block]> | Conditionally* | Maximum 50 items, available in [attachment](crate::Surface) |
+/// | pretext | String | No | N/A |
+/// | author_name | String | No | N/A |
+/// | author_link | String | No | N/A |
+/// | author_icon | String | No | N/A |
+/// | title | String | No | N/A |
+/// | title_link | String | No | N/A |
+/// | text | String | Conditionally* | N/A |
+/// | fields | Vec<[AttachmentField]> | Conditionally* | N/A |
+/// | image_url | String | No | N/A |
+/// | thumb_url | String | No | N/A |
+/// | footer | String | No | Max length 300 characters |
+/// | footer_icon | String | No | N/A |
+/// | ts | i64 | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * At least one of `blocks`, `text`, `fallback` or `fields` is required.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{mrkdwn, Attachment, Message};
+/// use slack_messaging::blocks::Section;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let message = Message::builder()
+///     .text("Deploy failed")
+///     .attachment(
+///         Attachment::builder()
+///             .color("danger")
+///             .fallback("Deploy of api failed")
+///             .block(
+///                 Section::builder()
+///                     .text(mrkdwn!("*api* failed to deploy")?)
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "text": "Deploy failed",
+///     "attachments": [
+///         {
+///             "color": "danger",
+///             "fallback": "Deploy of api failed",
+///             "blocks": [
+///                 {
+///                     "type": "section",
+///                     "text": {
+///                         "type": "mrkdwn",
+///                         "text": "*api* failed to deploy"
+///                     }
+///                 }
+///             ]
+///         }
+///     ]
+/// });
+///
+/// let json = serde_json::to_value(message).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let attachment = Attachment::builder()
+///     .color("red")
+///     .text("foo")
+///     .build();
+///
+/// assert!(attachment.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate")]
+pub struct Attachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::color_format"))]
+    pub(crate) color: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fallback: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(
+        push_item = "block",
+        validate("list::max_item_50", "surface::attachment")
+    )]
+    pub(crate) blocks: Option<Vec<Block>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) pretext: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) author_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) author_link: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) author_icon: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title_link: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(push_item = "field")]
+    pub(crate) fields: Option<Vec<AttachmentField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) image_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) thumb_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::max_300"))]
+    pub(crate) footer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) footer_icon: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ts: Option<i64>,
+}
+
+impl Attachment {
+    pub(crate) fn block_count(&self) -> usize {
+        self.blocks.as_ref().map(Vec::len).unwrap_or_default()
+    }
+}
+
+fn validate(val: &Attachment) -> Vec<ValidationErrorKind> {
+    match (&val.blocks, &val.text, &val.fallback, &val.fields) {
+        (None, None, None, None) => vec![ValidationErrorKind::AtLeastOneOf4(
+            "blocks", "text", "fallback", "fields",
+        )],
+        _ => vec![],
+    }
+}
+
+/// Field displayed in a table inside of [Attachment].
+///
+/// # Fields and Validations
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | title | String | No | N/A |
+/// | value | String | No | N/A |
+/// | short | bool | No | N/A |
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::AttachmentField;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let field = AttachmentField::builder()
+///     .title("Priority")
+///     .value("High")
+///     .short(true)
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "title": "Priority",
+///     "value": "High",
+///     "short": true
+/// });
+///
+/// let json = serde_json::to_value(field).unwrap();
+///
+/// assert_eq!(json, expected);
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct AttachmentField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) short: Option<bool>,
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+
+    pub fn attachment(blocks: Vec<Block>) -> Attachment {
+        Attachment {
+            color: Some("good".into()),
+            fallback: Some("fallback".into()),
+            blocks: Some(blocks),
+            pretext: None,
+            author_name: None,
+            author_link: None,
+            author_icon: None,
+            title: None,
+            title_link: None,
+            text: None,
+            fields: None,
+            image_url: None,
+            thumb_url: None,
+            footer: None,
+            footer_icon: None,
+            ts: None,
+        }
+    }
+
+    pub fn attachment_field(title: impl Into<String>, value: impl Into<String>) -> AttachmentField {
+        AttachmentField {
+            title: Some(title.into()),
+            value: Some(value.into()),
+            short: Some(true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_helpers::*;
+    use super::*;
+    use crate::Surface;
+    use crate::blocks::Input;
+    use crate::blocks::elements::test_helpers::file_input;
+    use crate::blocks::test_helpers::*;
+    use crate::composition_objects::test_helpers::plain_text;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = Attachment {
+            color: Some("#36a64f".into()),
+            fallback: Some("Required plain-text summary of the attachment.".into()),
+            blocks: Some(vec![section("this is a section block").into()]),
+            pretext: Some("Optional text that appears above the attachment block".into()),
+            author_name: Some("Bobby Tables".into()),
+            author_link: Some("http://flickr.com/bobby/".into()),
+            author_icon: Some("http://flickr.com/icons/bobby.jpg".into()),
+            title: Some("Slack API Documentation".into()),
+            title_link: Some("https://api.slack.com/".into()),
+            text: Some("Optional text that appears within the attachment".into()),
+            fields: Some(vec![attachment_field("Priority", "High")]),
+            image_url: Some("http://my-website.com/path/to/image.jpg".into()),
+            thumb_url: Some("http://example.com/path/to/thumb.png".into()),
+            footer: Some("Slack API".into()),
+            footer_icon: Some(
+                "https://platform.slack-edge.com/img/default_application_icon.png".into(),
+            ),
+            ts: Some(123456789),
+        };
+
+        let val = Attachment::builder()
+            .set_color(Some("#36a64f"))
+            .set_fallback(Some("Required plain-text summary of the attachment."))
+            .set_blocks(Some(
+                vec![section("this is a section block").into()] as Vec<Block>
+            ))
+            .set_pretext(Some(
+                "Optional text that appears above the attachment block",
+            ))
+            .set_author_name(Some("Bobby Tables"))
+            .set_author_link(Some("http://flickr.com/bobby/"))
+            .set_author_icon(Some("http://flickr.com/icons/bobby.jpg"))
+            .set_title(Some("Slack API Documentation"))
+            .set_title_link(Some("https://api.slack.com/"))
+            .set_text(Some("Optional text that appears within the attachment"))
+            .set_fields(Some(vec![attachment_field("Priority", "High")]))
+            .set_image_url(Some("http://my-website.com/path/to/image.jpg"))
+            .set_thumb_url(Some("http://example.com/path/to/thumb.png"))
+            .set_footer(Some("Slack API"))
+            .set_footer_icon(Some(
+                "https://platform.slack-edge.com/img/default_application_icon.png",
+            ))
+            .set_ts(Some(123456789))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = Attachment::builder()
+            .color("#36a64f")
+            .fallback("Required plain-text summary of the attachment.")
+            .blocks(vec![section("this is a section block").into()] as Vec<Block>)
+            .pretext("Optional text that appears above the attachment block")
+            .author_name("Bobby Tables")
+            .author_link("http://flickr.com/bobby/")
+            .author_icon("http://flickr.com/icons/bobby.jpg")
+            .title("Slack API Documentation")
+            .title_link("https://api.slack.com/")
+            .text("Optional text that appears within the attachment")
+            .fields(vec![attachment_field("Priority", "High")])
+            .image_url("http://my-website.com/path/to/image.jpg")
+            .thumb_url("http://example.com/path/to/thumb.png")
+            .footer("Slack API")
+            .footer_icon("https://platform.slack-edge.com/img/default_application_icon.png")
+            .ts(123456789)
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_implements_push_item_method() {
+        let val = Attachment::builder()
+            .block(header("this is a header block"))
+            .block(section("this is a section block"))
+            .field(attachment_field("Priority", "High"))
+            .field(attachment_field("Status", "Open"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            val.blocks,
+            Some(vec![
+                header("this is a header block").into(),
+                section("this is a section block").into(),
+            ])
+        );
+        assert_eq!(
+            val.fields,
+            Some(vec![
+                attachment_field("Priority", "High"),
+                attachment_field("Status", "Open"),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_requires_color_to_be_hex_code_or_predefined_color() {
+        let err = Attachment::builder()
+            .color("red")
+            .text("foo")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Attachment");
+
+        let errors = err.field("color");
+        assert!(errors.includes(ValidationErrorKind::InvalidFormat(
+            "hex color code or good, warning, danger"
+        )));
+    }
+
+    #[test]
+    fn it_requires_blocks_list_size_less_than_50() {
+        let blocks: Vec<Block> = (0..51).map(|_| section("foo").into()).collect();
+        let err = Attachment::builder().blocks(blocks).build().unwrap_err();
+        assert_eq!(err.object(), "Attachment");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 50,
+            actual: 51
+        }));
+    }
+
+    #[test]
+    fn it_requires_blocks_available_in_attachments() {
+        let input = Input {
+            label: Some(plain_text("Upload")),
+            element: Some(file_input().into()),
+            dispatch_action: None,
+            block_id: None,
+            hint: None,
+            optional: None,
+        };
+        let err = Attachment::builder().block(input).build().unwrap_err();
+        assert_eq!(err.object(), "Attachment");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::UnsupportedElement(
+            0,
+            "input",
+            "file_input",
+            Surface::Attachment
+        )));
+    }
+
+    #[test]
+    fn it_requires_footer_less_than_300_characters_long() {
+        let err = Attachment::builder()
+            .text("foo")
+            .footer("a".repeat(301))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Attachment");
+
+        let errors = err.field("footer");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 300,
+            actual: 301
+        }));
+    }
+
+    #[test]
+    fn it_requires_at_least_one_of_content_fields() {
+        let err = Attachment::builder().color("good").build().unwrap_err();
+        assert_eq!(err.object(), "Attachment");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::AtLeastOneOf4(
+            "blocks", "text", "fallback", "fields"
+        )));
+    }
+}
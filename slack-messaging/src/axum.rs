@@ -0,0 +1,371 @@
+use crate::Message;
+use crate::commands::SlashCommand;
+use crate::errors::{ExtractError, ValidationErrors};
+use crate::events::EventEnvelope;
+use crate::interactions::{Interaction, OptionsResponse, ViewSubmissionResponse};
+use crate::verify::{SIGNATURE_HEADER, TIMESTAMP_HEADER, Verifier};
+
+use ::axum::Json;
+use ::axum::body::Bytes;
+use ::axum::extract::{FromRef, FromRequest, Request};
+use ::axum::http::{HeaderMap, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+/// Extractor of the [`Interaction`] payload, posted as the `payload` field of the
+/// `application/x-www-form-urlencoded` body. The request is verified with the [`Verifier`] of
+/// the router state.
+///
+/// # Example
+///
+/// ```
+/// use axum::{Router, routing::post};
+/// use slack_messaging::Message;
+/// use slack_messaging::axum::VerifiedInteraction;
+/// use slack_messaging::errors::ValidationErrors;
+/// use slack_messaging::interactions::Interaction;
+/// use slack_messaging::verify::Verifier;
+///
+/// async fn interactions(
+///     VerifiedInteraction(interaction): VerifiedInteraction,
+/// ) -> Result<Message, ValidationErrors> {
+///     match interaction {
+///         Interaction::BlockActions(_) => Message::builder().text("Got it!").build(),
+///         _ => Message::builder().text("Not supported").build(),
+///     }
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/slack/interactions", post(interactions))
+///     .with_state(Verifier::new("8f742231b10e8888abcd99yyyzzz85a5"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedInteraction(pub Interaction);
+
+/// Extractor of the [`SlashCommand`] payload, posted as the
+/// `application/x-www-form-urlencoded` body. The request is verified with the [`Verifier`] of
+/// the router state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedSlashCommand(pub SlashCommand);
+
+/// Extractor of the [`EventEnvelope`] the Events API posts as the JSON body. The request is
+/// verified with the [`Verifier`] of the router state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedEvent(pub EventEnvelope);
+
+#[derive(Deserialize)]
+struct InteractionForm {
+    payload: String,
+}
+
+impl<S> FromRequest<S> for VerifiedInteraction
+where
+    Verifier: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ExtractError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = verified_body(req, state).await?;
+        let form: InteractionForm = serde_urlencoded::from_bytes(&body)
+            .map_err(|err| ExtractError::InvalidPayload(err.to_string()))?;
+
+        serde_json::from_str(&form.payload)
+            .map(Self)
+            .map_err(|err| ExtractError::InvalidPayload(err.to_string()))
+    }
+}
+
+impl<S> FromRequest<S> for VerifiedSlashCommand
+where
+    Verifier: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ExtractError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = verified_body(req, state).await?;
+
+        SlashCommand::from_form(&body)
+            .map(Self)
+            .map_err(|err| ExtractError::InvalidPayload(err.to_string()))
+    }
+}
+
+impl<S> FromRequest<S> for VerifiedEvent
+where
+    Verifier: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ExtractError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = verified_body(req, state).await?;
+
+        serde_json::from_slice(&body)
+            .map(Self)
+            .map_err(|err| ExtractError::InvalidPayload(err.to_string()))
+    }
+}
+
+async fn verified_body<S>(req: Request, state: &S) -> Result<Bytes, ExtractError>
+where
+    Verifier: FromRef<S>,
+    S: Send + Sync,
+{
+    let headers = req.headers().clone();
+    let body = Bytes::from_request(req, state).await?;
+
+    Verifier::from_ref(state).verify(
+        header(&headers, TIMESTAMP_HEADER)?,
+        header(&headers, SIGNATURE_HEADER)?,
+        &body,
+    )?;
+
+    Ok(body)
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &'static str) -> Result<&'a str, ExtractError> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .ok_or(ExtractError::MissingHeader(name))
+}
+
+impl IntoResponse for ExtractError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::MissingHeader(_) | Self::Verification(_) => {
+                (StatusCode::UNAUTHORIZED, self.to_string()).into_response()
+            }
+            Self::Body(rejection) => rejection.into_response(),
+            Self::InvalidPayload(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for Message {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+impl IntoResponse for ViewSubmissionResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+impl IntoResponse for OptionsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+/// Building the response failed, which is a bug of the handler rather than of the request, so
+/// the error is logged and Slack receives `500 Internal Server Error`.
+impl IntoResponse for ValidationErrors {
+    fn into_response(self) -> Response {
+        tracing::error!("failed to build the response to Slack: {}", self.report());
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition_objects::test_helpers::*;
+
+    use ::axum::Router;
+    use ::axum::body::{Body, to_bytes};
+    use ::axum::routing::post;
+    use serde_json::{Value, json};
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tower::ServiceExt;
+
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/interactions",
+                post(
+                    |VerifiedInteraction(interaction): VerifiedInteraction| async move {
+                        match interaction {
+                            Interaction::BlockActions(payload) => Message::builder()
+                                .text(format!("clicked by {}", payload.user.id))
+                                .build(),
+                            _ => Message::builder().build(),
+                        }
+                    },
+                ),
+            )
+            .route(
+                "/commands",
+                post(
+                    |VerifiedSlashCommand(command): VerifiedSlashCommand| async move {
+                        Message::builder()
+                            .text(format!("{} {}", command.command, command.text))
+                            .ephemeral()
+                            .build()
+                    },
+                ),
+            )
+            .route(
+                "/events",
+                post(|VerifiedEvent(envelope): VerifiedEvent| async move {
+                    match envelope {
+                        EventEnvelope::UrlVerification(verification) => verification.challenge,
+                        _ => String::new(),
+                    }
+                }),
+            )
+            .route(
+                "/options",
+                post(|| async {
+                    OptionsResponse::builder()
+                        .option(option("Foo", "foo"))
+                        .build()
+                }),
+            )
+            .route(
+                "/invalid",
+                post(|| async { OptionsResponse::builder().build() }),
+            )
+            .route("/views", post(|| async { ViewSubmissionResponse::Clear }))
+            .with_state(Verifier::new(SECRET))
+    }
+
+    fn signed(path: &str, body: String) -> Request {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+        let signature = Verifier::new(SECRET).sign(&timestamp, body.as_bytes());
+
+        Request::post(path)
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, signature)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn send(req: Request) -> (StatusCode, Bytes) {
+        let response = app().oneshot(req).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, body)
+    }
+
+    fn json_body(body: &Bytes) -> Value {
+        serde_json::from_slice(body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_extracts_interaction_from_payload_field() {
+        let payload = json!({
+            "type": "block_actions",
+            "user": { "id": "U1" },
+            "api_app_id": "A1",
+            "container": { "type": "view", "view_id": "V1" },
+            "trigger_id": "1.2.3",
+            "actions": []
+        });
+        let body = serde_urlencoded::to_string([("payload", payload.to_string())]).unwrap();
+
+        let (status, body) = send(signed("/interactions", body)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json_body(&body), json!({ "text": "clicked by U1" }));
+    }
+
+    #[tokio::test]
+    async fn it_extracts_slash_command() {
+        let body = "team_id=T1&channel_id=C1&user_id=U1&command=%2Fdeploy&text=app\
+            &response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2F1&trigger_id=1.2.3";
+
+        let (status, body) = send(signed("/commands", body.into())).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            json_body(&body),
+            json!({ "text": "/deploy app", "response_type": "ephemeral" })
+        );
+    }
+
+    #[tokio::test]
+    async fn it_extracts_event_envelope() {
+        let body = json!({
+            "type": "url_verification",
+            "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+            "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+        });
+
+        let (status, body) = send(signed("/events", body.to_string())).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_tampered_request() {
+        let mut req = signed("/commands", "team_id=T1".into());
+        *req.body_mut() = Body::from("team_id=T2");
+
+        let (status, body) = send(req).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body, "signature mismatch");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_request_without_signature() {
+        let req = Request::post("/commands")
+            .body(Body::from("team_id=T1"))
+            .unwrap();
+
+        let (status, body) = send(req).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body, "missing header `X-Slack-Request-Timestamp`");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_payload() {
+        let (status, _) = send(signed("/interactions", "foo=bar".into())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send(signed("/commands", "team_id=T1".into())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send(signed("/events", "{}".into())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn it_responds_with_validation_errors_as_internal_server_error() {
+        let (status, body) = send(signed("/invalid", String::new())).await;
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_responds_with_options_and_view_submission_responses() {
+        let (status, body) = send(signed("/options", String::new())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            json_body(&body),
+            json!({
+                "options": [
+                    { "text": { "type": "plain_text", "text": "Foo" }, "value": "foo" }
+                ]
+            })
+        );
+
+        let (status, body) = send(signed("/views", String::new())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json_body(&body), json!({ "response_action": "clear" }));
+    }
+}
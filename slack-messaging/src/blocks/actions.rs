@@ -8,7 +8,7 @@ use crate::blocks::elements::{
 use crate::validators::*;
 
 use serde::Serialize;
-use slack_messaging_derive::Builder;
+use slack_messaging_derive::{Builder, Validate};
 
 /// [Actions block](https://docs.slack.dev/reference/block-kit/blocks/actions-block)
 /// representation.
@@ -287,7 +287,7 @@ pub struct Actions {
 }
 
 /// Objects that can be an element of the [Actions]'s elements field.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum ActionsElement {
     /// [Button element](https://docs.slack.dev/reference/block-kit/block-elements/button-element)
@@ -396,6 +396,29 @@ actions_from! {
     WorkflowButton,
 }
 
+deserialize_by_type! {
+    ActionsElement {
+        "button" => Button,
+        "checkboxes" => Checkboxes,
+        "datepicker" => DatePicker,
+        "datetimepicker" => DatetimePicker,
+        "multi_static_select" => MultiSelectMenuStaticOptions,
+        "multi_external_select" => MultiSelectMenuExternalDataSource,
+        "multi_users_select" => MultiSelectMenuUsers,
+        "multi_conversations_select" => MultiSelectMenuConversations,
+        "multi_channels_select" => MultiSelectMenuPublicChannels,
+        "overflow" => OverflowMenu,
+        "radio_buttons" => RadioButtonGroup,
+        "static_select" => SelectMenuStaticOptions,
+        "external_select" => SelectMenuExternalDataSource,
+        "users_select" => SelectMenuUsers,
+        "conversations_select" => SelectMenuConversations,
+        "channels_select" => SelectMenuPublicChannels,
+        "timepicker" => TimePicker,
+        "workflow_button" => WorkflowButton,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -464,7 +487,10 @@ mod tests {
         assert_eq!(err.object(), "Actions");
 
         let errors = err.field("elements");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(25)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 25,
+            actual: 26
+        }));
     }
 
     #[test]
@@ -477,6 +503,9 @@ mod tests {
         assert_eq!(err.object(), "Actions");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
@@ -1,7 +1,7 @@
 use crate::composition_objects::TextContent;
 use crate::validators::*;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use slack_messaging_derive::Builder;
 
 /// [Alert block](https://docs.slack.dev/reference/block-kit/blocks/alert-block) representation.
@@ -63,7 +63,7 @@ pub struct Alert {
 }
 
 /// Values that can be set to the `level` field of [Alert].
-#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum AlertLevel {
     Default,
@@ -125,6 +125,9 @@ mod tests {
         assert_eq!(err.object(), "Alert");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
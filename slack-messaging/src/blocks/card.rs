@@ -296,7 +296,10 @@ mod tests {
         assert_eq!(err.object(), "Card");
 
         let errors = err.field("title");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 
     #[test]
@@ -309,7 +312,10 @@ mod tests {
         assert_eq!(err.object(), "Card");
 
         let errors = err.field("subtitle");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 
     #[test]
@@ -321,7 +327,10 @@ mod tests {
         assert_eq!(err.object(), "Card");
 
         let errors = err.field("body");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(200)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 200,
+            actual: 201
+        }));
     }
 
     #[test]
@@ -379,6 +388,9 @@ mod tests {
         assert_eq!(err.object(), "Card");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
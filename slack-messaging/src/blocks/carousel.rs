@@ -300,7 +300,10 @@ mod tests {
         assert_eq!(err.object(), "Carousel");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -345,7 +348,10 @@ mod tests {
         assert_eq!(err.object(), "Carousel");
 
         let errors = err.field("elements");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(10)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 10,
+            actual: 11
+        }));
     }
 
     fn card(title: &str) -> Card {
@@ -3,7 +3,7 @@ use crate::composition_objects::TextContent;
 use crate::validators::*;
 
 use serde::Serialize;
-use slack_messaging_derive::Builder;
+use slack_messaging_derive::{Builder, Validate};
 
 /// [Context block](https://docs.slack.dev/reference/block-kit/blocks/context-block)
 /// representation.
@@ -75,7 +75,7 @@ pub struct Context {
 }
 
 /// Objects that can be an element of the [Context]'s elements field.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum ContextElement {
     /// [Image element](https://docs.slack.dev/reference/block-kit/block-elements/image-element)
@@ -102,6 +102,13 @@ where
     }
 }
 
+deserialize_by_type! {
+    ContextElement {
+        "image" => Image,
+        "plain_text" | "mrkdwn" => Text,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,7 +170,10 @@ mod tests {
         assert_eq!(err.object(), "Context");
 
         let errors = err.field("elements");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(10)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 10,
+            actual: 11
+        }));
     }
 
     #[test]
@@ -176,6 +186,9 @@ mod tests {
         assert_eq!(err.object(), "Context");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
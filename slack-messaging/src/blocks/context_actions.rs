@@ -2,7 +2,7 @@ use crate::blocks::elements::{FeedbackButtons, IconButton};
 use crate::validators::*;
 
 use serde::Serialize;
-use slack_messaging_derive::Builder;
+use slack_messaging_derive::{Builder, Validate};
 
 /// [Context actions block](https://docs.slack.dev/reference/block-kit/blocks/context-actions-block)
 /// representation.
@@ -141,7 +141,7 @@ pub struct ContextActions {
 }
 
 /// Objects that can be an element of the [ContextActions] block.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum ContextActionsElement {
     /// [Feedback buttons element](https://docs.slack.dev/reference/block-kit/block-elements/feedback-buttons-element) representation
@@ -168,6 +168,13 @@ context_actions_from! {
     IconButton,
 }
 
+deserialize_by_type! {
+    ContextActionsElement {
+        "feedback_buttons" => FeedbackButtons,
+        "icon_button" => IconButton,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,7 +239,7 @@ mod tests {
         assert_eq!(err.object(), "ContextActions");
 
         let errors = err.field("elements");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(5)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize { max: 5, actual: 6 }));
     }
 
     #[test]
@@ -245,6 +252,9 @@ mod tests {
         assert_eq!(err.object(), "ContextActions");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
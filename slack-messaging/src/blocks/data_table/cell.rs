@@ -1,10 +1,11 @@
 use crate::blocks::RichText;
 use crate::blocks::table::RawText;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Number;
+use slack_messaging_derive::Validate;
 
 /// A table cell for DataTable block.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum DataTableCell {
     /// A plain text table cell
@@ -17,6 +18,14 @@ pub enum DataTableCell {
     RichText(RichText),
 }
 
+deserialize_by_type! {
+    DataTableCell {
+        "raw_text" => RawText,
+        "raw_number" => RawNumber,
+        "rich_text" => RichText,
+    }
+}
+
 impl DataTableCell {
     pub fn is_raw_text(&self) -> bool {
         matches!(self, Self::RawText(_))
@@ -86,6 +95,22 @@ impl Serialize for RawNumber {
     }
 }
 
+impl<'de> Deserialize<'de> for RawNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum Raw {
+            RawNumber { value: Number, text: String },
+        }
+
+        let Raw::RawNumber { value, text } = Raw::deserialize(deserializer)?;
+        Ok(Self(value, text))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
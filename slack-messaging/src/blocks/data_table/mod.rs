@@ -1,6 +1,6 @@
 use crate::errors::ValidationErrorKind;
-use crate::value::Value;
 use crate::validators::*;
+use crate::value::ValueRef;
 
 use serde::Serialize;
 use slack_messaging_derive::Builder;
@@ -267,7 +267,7 @@ pub struct DataTable {
     pub(crate) row_header_column_index: Option<i64>,
 }
 
-fn valid_header(value: Value<Vec<DataTableRow>>) -> Value<Vec<DataTableRow>> {
+fn valid_header(value: ValueRef<Vec<DataTableRow>>) -> ValueRef<Vec<DataTableRow>> {
     list::inner_validator(value, ValidationErrorKind::RichTextTableHeader, |rows| {
         rows.first().and_then(|row| row.cells.as_ref()).is_some_and(|cells| {
             cells.iter().any(DataTableCell::is_rich_text)
@@ -358,7 +358,10 @@ mod tests {
         assert_eq!(err.object(), "DataTable");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -383,7 +386,7 @@ mod tests {
         assert_eq!(err.object(), "DataTable");
 
         let errors = err.field("rows");
-        assert!(errors.includes(ValidationErrorKind::MinArraySize(2)));
+        assert!(errors.includes(ValidationErrorKind::MinArraySize { min: 2, actual: 1 }));
     }
 
     #[test]
@@ -393,7 +396,10 @@ mod tests {
         assert_eq!(err.object(), "DataTable");
 
         let errors = err.field("rows");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(101)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 101,
+            actual: 102
+        }));
     }
 
     #[test]
@@ -435,7 +441,7 @@ mod tests {
         assert_eq!(err.object(), "DataTable");
 
         let errors = err.field("page_size");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(1)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -450,7 +456,10 @@ mod tests {
         assert_eq!(err.object(), "DataTable");
 
         let errors = err.field("page_size");
-        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue(100)));
+        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue {
+            max: 100,
+            actual: 101
+        }));
     }
 
     #[test]
@@ -465,7 +474,7 @@ mod tests {
         assert_eq!(err.object(), "DataTable");
 
         let errors = err.field("row_header_column_index");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(0)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 0, actual: -1 }));
     }
 
     fn row<T: Into<DataTableCell>>(cells: Vec<T>) -> DataTableRow {
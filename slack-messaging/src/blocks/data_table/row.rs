@@ -178,6 +178,9 @@ mod tests {
         assert_eq!(err.object(), "DataTableRow");
 
         let errors = err.field("cells");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 20,
+            actual: 21
+        }));
     }
 }
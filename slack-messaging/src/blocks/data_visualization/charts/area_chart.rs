@@ -229,7 +229,7 @@ mod tests {
         assert_eq!(err.object(), "AreaChart");
 
         let errors = err.field("series");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(6)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize { max: 6, actual: 7 }));
     }
 
     #[test]
@@ -141,7 +141,10 @@ mod tests {
         assert_eq!(err.object(), "AxisConfig");
 
         let errors = err.field("categories");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 20,
+            actual: 21
+        }));
     }
 
     #[test]
@@ -154,7 +157,10 @@ mod tests {
         assert_eq!(err.object(), "AxisConfig");
 
         let errors = err.field("x_label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(50)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 50,
+            actual: 51
+        }));
     }
 
     #[test]
@@ -167,6 +173,9 @@ mod tests {
         assert_eq!(err.object(), "AxisConfig");
 
         let errors = err.field("y_label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(50)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 50,
+            actual: 51
+        }));
     }
 }
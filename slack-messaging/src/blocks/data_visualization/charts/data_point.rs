@@ -180,7 +180,10 @@ mod tests {
         assert_eq!(err.object(), "DataPoint");
 
         let errors = err.field("label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 20,
+            actual: 21
+        }));
     }
 
     #[test]
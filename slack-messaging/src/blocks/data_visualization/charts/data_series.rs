@@ -157,7 +157,10 @@ mod tests {
         assert_eq!(err.object(), "DataSeries");
 
         let errors = err.field("name");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 20,
+            actual: 21
+        }));
     }
 
     #[test]
@@ -195,7 +198,10 @@ mod tests {
         assert_eq!(err.object(), "DataSeries");
 
         let errors = err.field("data");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 20,
+            actual: 21
+        }));
     }
 
     fn point(label: &str, value: i32) -> DataPoint {
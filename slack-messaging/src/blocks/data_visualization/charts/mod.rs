@@ -1,6 +1,6 @@
 use crate::errors::ValidationErrorKind;
-use crate::value::Value;
 use crate::validators::list;
+use crate::value::ValueRef;
 use std::collections::HashSet;
 
 /// Builders for creating charts and their components.
@@ -59,7 +59,7 @@ trait ValidateXYChart {
     }
 }
 
-fn unique_series_names(value: Value<Vec<DataSeries>>) -> Value<Vec<DataSeries>> {
+fn unique_series_names(value: ValueRef<Vec<DataSeries>>) -> ValueRef<Vec<DataSeries>> {
     list::inner_validator(value, ValidationErrorKind::UniqueSeriesName, |l| {
         let mut names = HashSet::new();
         l.iter().any(|series| {
@@ -153,23 +153,20 @@ mod tests {
         #[test]
         fn it_passes_if_all_series_names_are_unique() {
             let list = vec!["Series 1", "Series 2", "Series 3"];
-            let result = test(list);
-            assert!(result.errors.is_empty());
+            let errors = test(list);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_at_least_one_series_name_is_duplicated() {
             let list = vec!["Series 1", "Series 2", "Series 1"];
-            let result = test(list);
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::UniqueSeriesName]
-            );
+            let errors = test(list);
+            assert_eq!(errors, vec![ValidationErrorKind::UniqueSeriesName]);
         }
 
-        fn test(list: Vec<&str>) -> Value<Vec<DataSeries>> {
+        fn test(list: Vec<&str>) -> Vec<ValidationErrorKind> {
             let series: Vec<DataSeries> = list.into_iter().map(data_series).collect();
-            unique_series_names(Value::new(Some(series)))
+            unique_series_names(ValueRef::new(Some(&series))).errors
         }
 
         fn data_series(name: &str) -> DataSeries {
@@ -164,6 +164,6 @@ mod tests {
         assert_eq!(err.object(), "PieChart");
 
         let errors = err.field("segments");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(6)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize { max: 6, actual: 7 }));
     }
 }
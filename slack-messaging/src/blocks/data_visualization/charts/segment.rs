@@ -181,7 +181,10 @@ mod tests {
         assert_eq!(err.object(), "Segment");
 
         let errors = err.field("label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 20,
+            actual: 21
+        }));
     }
 
     #[test]
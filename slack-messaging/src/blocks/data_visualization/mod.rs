@@ -2,7 +2,7 @@ use crate::validators::*;
 
 use paste::paste;
 use serde::Serialize;
-use slack_messaging_derive::Builder;
+use slack_messaging_derive::{Builder, Validate};
 
 /// Charts and their related components.
 pub mod charts;
@@ -16,7 +16,7 @@ pub mod prelude {
 use charts::{AreaChart, BarChart, LineChart, PieChart};
 
 /// Each chart type supported by the chart field of the [DataVisualization]
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum Chart {
     /// [Pie chart](https://docs.slack.dev/reference/block-kit/blocks/data-visualization-block#pie)
@@ -48,6 +48,15 @@ macro_rules! impl_chart_from {
 
 impl_chart_from!(Pie, Bar, Area, Line,);
 
+deserialize_by_type! {
+    Chart {
+        "pie" => Pie,
+        "bar" => Bar,
+        "area" => Area,
+        "line" => Line,
+    }
+}
+
 /// [Data visualization
 /// block](https://docs.slack.dev/reference/block-kit/blocks/data-visualization-block/)
 /// representation.
@@ -441,7 +450,10 @@ mod tests {
         assert_eq!(err.object(), "DataVisualization");
 
         let errors = err.field("title");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(50)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 50,
+            actual: 51
+        }));
     }
 
     #[test]
@@ -467,7 +479,10 @@ mod tests {
         assert_eq!(err.object(), "DataVisualization");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     fn chart() -> Chart {
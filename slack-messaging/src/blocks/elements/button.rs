@@ -81,7 +81,11 @@ pub struct Button {
     #[builder(validate("text::max_2000"))]
     pub(crate) value: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::de::style"
+    )]
     #[builder(private_setter)]
     pub(crate) style: Option<&'static str>,
 
@@ -173,7 +177,10 @@ mod tests {
         assert_eq!(err.object(), "Button");
 
         let errors = err.field("text");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
     }
 
     #[test]
@@ -186,7 +193,10 @@ mod tests {
         assert_eq!(err.object(), "Button");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -199,7 +209,10 @@ mod tests {
         assert_eq!(err.object(), "Button");
 
         let errors = err.field("url");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(3000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
     }
 
     #[test]
@@ -212,7 +225,10 @@ mod tests {
         assert_eq!(err.object(), "Button");
 
         let errors = err.field("value");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 2000,
+            actual: 2001
+        }));
     }
 
     #[test]
@@ -225,6 +241,63 @@ mod tests {
         assert_eq!(err.object(), "Button");
 
         let errors = err.field("accessibility_label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
+    }
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let expected = Button {
+            text: Some(plain_text("Click Me")),
+            action_id: Some("button_0".into()),
+            url: None,
+            value: Some("click_me_123".into()),
+            style: Some("danger"),
+            confirm: Some(confirm()),
+            accessibility_label: None,
+        };
+
+        let json = serde_json::to_value(&expected).unwrap();
+        let val: Button = serde_json::from_value(json).unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_fails_to_deserialize_unknown_style() {
+        let json = serde_json::json!({
+            "type": "button",
+            "text": { "type": "plain_text", "text": "Click Me" },
+            "style": "secondary"
+        });
+
+        let err = serde_json::from_value::<Button>(json).unwrap_err();
+        assert!(err.to_string().contains("unknown variant `secondary`"));
+    }
+
+    #[test]
+    fn it_fails_to_deserialize_other_element_type() {
+        let json = serde_json::json!({
+            "type": "workflow_button",
+            "text": { "type": "plain_text", "text": "Click Me" }
+        });
+
+        assert!(serde_json::from_value::<Button>(json).is_err());
+    }
+
+    #[test]
+    fn it_runs_validations_on_deserializing() {
+        let json = serde_json::json!({
+            "type": "button",
+            "text": { "type": "plain_text", "text": "a".repeat(76) }
+        });
+
+        let err = serde_json::from_value::<Button>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("text: max text length is `75` characters but got `76`")
+        );
     }
 }
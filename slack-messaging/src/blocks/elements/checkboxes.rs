@@ -193,7 +193,10 @@ mod tests {
         assert_eq!(err.object(), "Checkboxes");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -212,6 +215,9 @@ mod tests {
         assert_eq!(err.object(), "Checkboxes");
 
         let errors = err.field("options");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(10)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 10,
+            actual: 11
+        }));
     }
 }
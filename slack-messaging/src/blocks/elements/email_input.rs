@@ -128,7 +128,10 @@ mod tests {
         assert_eq!(err.object(), "EmailInput");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -140,6 +143,9 @@ mod tests {
         assert_eq!(err.object(), "EmailInput");
 
         let errors = err.field("placeholder");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 }
@@ -128,7 +128,10 @@ mod tests {
         assert_eq!(err.object(), "FileInput");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -137,7 +140,7 @@ mod tests {
         assert_eq!(err.object(), "FileInput");
 
         let errors = err.field("max_files");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(1)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -146,6 +149,9 @@ mod tests {
         assert_eq!(err.object(), "FileInput");
 
         let errors = err.field("max_files");
-        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue(10)));
+        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue {
+            max: 10,
+            actual: 11
+        }));
     }
 }
@@ -193,7 +193,10 @@ mod tests {
         assert_eq!(err.object(), "IconButton");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -207,7 +210,10 @@ mod tests {
         assert_eq!(err.object(), "IconButton");
 
         let errors = err.field("value");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 2000,
+            actual: 2001
+        }));
     }
 
     #[test]
@@ -221,6 +227,9 @@ mod tests {
         assert_eq!(err.object(), "IconButton");
 
         let errors = err.field("accessibility_label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
     }
 }
@@ -166,7 +166,10 @@ mod tests {
         assert_eq!(err.object(), "Image");
 
         let errors = err.field("image_url");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(3000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
     }
 
     #[test]
@@ -100,6 +100,14 @@ pub mod test_helpers {
         }
     }
 
+    pub fn file_input() -> FileInput {
+        FileInput {
+            action_id: Some("file_input_0".into()),
+            filetypes: None,
+            max_files: None,
+        }
+    }
+
     pub fn fb_btn(text: impl Into<String>, value: impl Into<String>) -> FeedbackButton {
         FeedbackButton {
             text: Some(plain_text(text)),
@@ -174,7 +174,10 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuConversations");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -186,7 +189,7 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuConversations");
 
         let errors = err.field("max_selected_items");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(1)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -198,6 +201,9 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuConversations");
 
         let errors = err.field("placeholder");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 }
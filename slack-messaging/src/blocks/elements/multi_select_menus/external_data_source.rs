@@ -168,7 +168,10 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuExternalDataSource");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -180,7 +183,7 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuExternalDataSource");
 
         let errors = err.field("max_selected_items");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(1)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -192,6 +195,9 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuExternalDataSource");
 
         let errors = err.field("placeholder");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 }
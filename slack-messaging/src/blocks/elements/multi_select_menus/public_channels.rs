@@ -158,7 +158,10 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuPublicChannels");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -170,7 +173,7 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuPublicChannels");
 
         let errors = err.field("max_selected_items");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(1)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -182,6 +185,9 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuPublicChannels");
 
         let errors = err.field("placeholder");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 }
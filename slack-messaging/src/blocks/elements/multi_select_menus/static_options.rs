@@ -346,7 +346,10 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuStaticOptions");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -360,7 +363,10 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuStaticOptions");
 
         let errors = err.field("options");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(100)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
     }
 
     #[test]
@@ -376,7 +382,10 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuStaticOptions");
 
         let errors = err.field("option_groups");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(100)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
     }
 
     #[test]
@@ -389,7 +398,7 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuStaticOptions");
 
         let errors = err.field("max_selected_items");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(1)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -402,7 +411,10 @@ mod tests {
         assert_eq!(err.object(), "MultiSelectMenuStaticOptions");
 
         let errors = err.field("placeholder");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 
     #[test]
@@ -154,7 +154,10 @@ mod tests {
         assert_eq!(err.object(), "OverflowMenu");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -182,7 +185,7 @@ mod tests {
         assert_eq!(err.object(), "OverflowMenu");
 
         let errors = err.field("options");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(5)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize { max: 5, actual: 6 }));
     }
 
     fn option(text: impl Into<String>, value: impl Into<String>) -> Opt<Text<Plain>, UrlAvailable> {
@@ -154,7 +154,10 @@ mod tests {
         assert_eq!(err.object(), "PlainTextInput");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -166,7 +169,7 @@ mod tests {
         assert_eq!(err.object(), "PlainTextInput");
 
         let errors = err.field("min_length");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(0)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 0, actual: -1 }));
     }
 
     #[test]
@@ -178,7 +181,10 @@ mod tests {
         assert_eq!(err.object(), "PlainTextInput");
 
         let errors = err.field("min_length");
-        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue(3000)));
+        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue {
+            max: 3000,
+            actual: 3001
+        }));
     }
 
     #[test]
@@ -187,7 +193,7 @@ mod tests {
         assert_eq!(err.object(), "PlainTextInput");
 
         let errors = err.field("max_length");
-        assert!(errors.includes(ValidationErrorKind::MinIntegerValue(1)));
+        assert!(errors.includes(ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -199,7 +205,10 @@ mod tests {
         assert_eq!(err.object(), "PlainTextInput");
 
         let errors = err.field("max_length");
-        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue(3000)));
+        assert!(errors.includes(ValidationErrorKind::MaxIntegerValue {
+            max: 3000,
+            actual: 3001
+        }));
     }
 
     #[test]
@@ -211,6 +220,9 @@ mod tests {
         assert_eq!(err.object(), "PlainTextInput");
 
         let errors = err.field("placeholder");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 }
@@ -173,7 +173,10 @@ mod tests {
         assert_eq!(err.object(), "RadioButtonGroup");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -195,6 +198,9 @@ mod tests {
         assert_eq!(err.object(), "RadioButtonGroup");
 
         let errors = err.field("options");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(10)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 10,
+            actual: 11
+        }));
     }
 }
@@ -312,7 +312,10 @@ mod tests {
         assert_eq!(err.object(), "SelectMenuStaticOptions");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -326,7 +329,10 @@ mod tests {
         assert_eq!(err.object(), "SelectMenuStaticOptions");
 
         let errors = err.field("options");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(100)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
     }
 
     #[test]
@@ -342,7 +348,10 @@ mod tests {
         assert_eq!(err.object(), "SelectMenuStaticOptions");
 
         let errors = err.field("option_groups");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(100)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
     }
 
     #[test]
@@ -355,7 +364,10 @@ mod tests {
         assert_eq!(err.object(), "SelectMenuStaticOptions");
 
         let errors = err.field("placeholder");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 
     #[test]
@@ -1,11 +1,11 @@
 use crate::composition_objects::{Plain, Text};
 use crate::validators::*;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use slack_messaging_derive::Builder;
 
 /// Icons for [`IconButton`](crate::blocks::elements::IconButton).
-#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum Icon {
     Trash,
@@ -14,7 +14,7 @@ pub enum Icon {
 /// Possible filetype values for filetypes field of
 /// [`FileInput`](crate::blocks::elements::FileInput).
 /// See [this](https://docs.slack.dev/reference/objects/file-object#types).
-#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum FileType {
     /// Auto Detect Type
@@ -386,7 +386,10 @@ mod tests {
             assert_eq!(err.object(), "FeedbackButton");
 
             let errors = err.field("text");
-            assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+            assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+                max: 75,
+                actual: 76
+            }));
         }
 
         #[test]
@@ -411,7 +414,10 @@ mod tests {
             assert_eq!(err.object(), "FeedbackButton");
 
             let errors = err.field("value");
-            assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+            assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+                max: 2000,
+                actual: 2001
+            }));
         }
 
         #[test]
@@ -425,7 +431,10 @@ mod tests {
             assert_eq!(err.object(), "FeedbackButton");
 
             let errors = err.field("accessibility_label");
-            assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+            assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+                max: 75,
+                actual: 76
+            }));
         }
     }
 }
@@ -108,7 +108,11 @@ pub struct WorkflowButton {
     #[builder(validate("required"))]
     pub(crate) workflow: Option<Workflow>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::de::style"
+    )]
     #[builder(private_setter)]
     pub(crate) style: Option<&'static str>,
 
@@ -197,7 +201,10 @@ mod tests {
         assert_eq!(err.object(), "WorkflowButton");
 
         let errors = err.field("text");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
     }
 
     #[test]
@@ -224,7 +231,10 @@ mod tests {
         assert_eq!(err.object(), "WorkflowButton");
 
         let errors = err.field("action_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -252,6 +262,9 @@ mod tests {
         assert_eq!(err.object(), "WorkflowButton");
 
         let errors = err.field("accessibility_label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
     }
 }
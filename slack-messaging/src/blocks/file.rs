@@ -1,6 +1,6 @@
 use crate::validators::*;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use slack_messaging_derive::Builder;
 
 /// [File block](https://docs.slack.dev/reference/block-kit/blocks/file-block)
@@ -59,7 +59,7 @@ pub struct File {
 }
 
 /// Values that can be set to the source field of [File].
-#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum FileSource {
     Remote,
@@ -129,6 +129,9 @@ mod tests {
         assert_eq!(err.object(), "File");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
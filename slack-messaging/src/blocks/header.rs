@@ -107,7 +107,44 @@ mod tests {
         assert_eq!(err.object(), "Header");
 
         let errors = err.field("text");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
+    }
+
+    #[test]
+    fn it_counts_text_length_in_characters() {
+        let result = Header::builder()
+            .text(plain_text("予算".repeat(75)))
+            .build();
+        assert!(result.is_ok());
+
+        let err = Header::builder()
+            .text(plain_text("予算".repeat(75) + "a"))
+            .build()
+            .unwrap_err();
+
+        let errors = err.field("text");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
+    }
+
+    #[test]
+    fn it_returns_remaining_characters_of_text() {
+        let builder = Header::builder();
+        assert_eq!(builder.remaining_text(), 150);
+
+        let builder = builder.text(plain_text("予算の実績"));
+        assert_eq!(builder.remaining_text(), 145);
+
+        let builder = builder.text(plain_text("a".repeat(151)));
+        assert_eq!(builder.remaining_text(), 0);
+
+        let builder = builder.block_id("header_1");
+        assert_eq!(builder.remaining_block_id(), 247);
     }
 
     #[test]
@@ -120,6 +157,9 @@ mod tests {
         assert_eq!(err.object(), "Header");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
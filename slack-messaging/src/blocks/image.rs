@@ -189,7 +189,10 @@ mod tests {
         assert_eq!(err.object(), "Image");
 
         let errors = err.field("alt_text");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 2000,
+            actual: 2001
+        }));
     }
 
     #[test]
@@ -202,7 +205,10 @@ mod tests {
         assert_eq!(err.object(), "Image");
 
         let errors = err.field("image_url");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(3000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
     }
 
     #[test]
@@ -216,7 +222,10 @@ mod tests {
         assert_eq!(err.object(), "Image");
 
         let errors = err.field("title");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 2000,
+            actual: 2001
+        }));
     }
 
     #[test]
@@ -230,7 +239,10 @@ mod tests {
         assert_eq!(err.object(), "Image");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
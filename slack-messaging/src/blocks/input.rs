@@ -9,7 +9,7 @@ use crate::composition_objects::{Plain, Text};
 use crate::validators::*;
 
 use serde::Serialize;
-use slack_messaging_derive::Builder;
+use slack_messaging_derive::{Builder, Validate};
 
 /// [Input block](https://docs.slack.dev/reference/block-kit/blocks/input-block)
 /// representation.
@@ -103,7 +103,7 @@ pub struct Input {
 }
 
 /// Objects that can be an element of the [Input]'s element field.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum InputElement {
     /// [Checkbox group](https://docs.slack.dev/reference/block-kit/block-elements/checkboxes-element)
@@ -227,6 +227,32 @@ input_from! {
     UrlInput,
 }
 
+deserialize_by_type! {
+    InputElement {
+        "checkboxes" => Checkboxes,
+        "datepicker" => DatePicker,
+        "datetimepicker" => DatetimePicker,
+        "email_text_input" => EmailInput,
+        "file_input" => FileInput,
+        "multi_static_select" => MultiSelectMenuStaticOptions,
+        "multi_external_select" => MultiSelectMenuExternalDataSource,
+        "multi_users_select" => MultiSelectMenuUsers,
+        "multi_conversations_select" => MultiSelectMenuConversations,
+        "multi_channels_select" => MultiSelectMenuPublicChannels,
+        "number_input" => NumberInput,
+        "plain_text_input" => PlainTextInput,
+        "radio_buttons" => RadioButtonGroup,
+        "rich_text_input" => RichTextInput,
+        "static_select" => SelectMenuStaticOptions,
+        "external_select" => SelectMenuExternalDataSource,
+        "users_select" => SelectMenuUsers,
+        "conversations_select" => SelectMenuConversations,
+        "channels_select" => SelectMenuPublicChannels,
+        "timepicker" => TimePicker,
+        "url_text_input" => UrlInput,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,7 +315,10 @@ mod tests {
         assert_eq!(err.object(), "Input");
 
         let errors = err.field("label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 2000,
+            actual: 2001
+        }));
     }
 
     #[test]
@@ -315,7 +344,10 @@ mod tests {
         assert_eq!(err.object(), "Input");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -329,6 +361,9 @@ mod tests {
         assert_eq!(err.object(), "Input");
 
         let errors = err.field("hint");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 2000,
+            actual: 2001
+        }));
     }
 }
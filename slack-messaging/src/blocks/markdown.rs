@@ -101,7 +101,10 @@ mod tests {
         assert_eq!(err.object(), "Markdown");
 
         let errors = err.field("text");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(12000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 12000,
+            actual: 12001
+        }));
     }
 
     #[test]
@@ -114,6 +117,9 @@ mod tests {
         assert_eq!(err.object(), "Markdown");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
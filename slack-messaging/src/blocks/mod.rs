@@ -1,4 +1,5 @@
 use serde::Serialize;
+use slack_messaging_derive::Validate;
 
 /// Builders for blocks.
 pub mod builders;
@@ -52,7 +53,7 @@ pub use task_card::{TaskCard, TaskStatus};
 pub use video::Video;
 
 /// Objects that can be set to blocks in [Message](crate::message::Message).
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum Block {
     /// [Actions block](https://docs.slack.dev/reference/block-kit/blocks/actions-block) representation
@@ -119,6 +120,11 @@ pub enum Block {
 
     /// [Video block](https://docs.slack.dev/reference/block-kit/blocks/video-block) representation
     Video(Box<Video>),
+
+    /// Block in a payload Slack sends that is of a type this crate does not know or fails to
+    /// deserialize, kept as raw JSON. It is never produced when deserializing [`Block`] directly,
+    /// and [`Surface`](crate::Surface) rejects it, so that builders never send it to Slack.
+    Unknown(serde_json::Value),
 }
 
 macro_rules! block_from {
@@ -156,6 +162,61 @@ block_from! {
     Video,
 }
 
+impl Block {
+    /// Returns the `type` of the block, or `unknown` for [`Block::Unknown`].
+    pub fn r#type(&self) -> &'static str {
+        match self {
+            Self::Actions(_) => "actions",
+            Self::Alert(_) => "alert",
+            Self::Card(_) => "card",
+            Self::Carousel(_) => "carousel",
+            Self::Context(_) => "context",
+            Self::ContextActions(_) => "context_actions",
+            Self::DataTable(_) => "data_table",
+            Self::DataVisualization(_) => "data_visualization",
+            Self::Divider(_) => "divider",
+            Self::File(_) => "file",
+            Self::Header(_) => "header",
+            Self::Image(_) => "image",
+            Self::Input(_) => "input",
+            Self::Markdown(_) => "markdown",
+            Self::Plan(_) => "plan",
+            Self::RichText(_) => "rich_text",
+            Self::Section(_) => "section",
+            Self::Table(_) => "table",
+            Self::TaskCard(_) => "task_card",
+            Self::Video(_) => "video",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+}
+
+deserialize_by_type! {
+    Block {
+        "actions" => Actions,
+        "alert" => Alert,
+        "card" => Card,
+        "carousel" => Carousel,
+        "context" => Context,
+        "context_actions" => ContextActions,
+        "data_table" => DataTable,
+        "data_visualization" => DataVisualization,
+        "divider" => Divider,
+        "file" => File,
+        "header" => Header,
+        "image" => Image,
+        "input" => Input,
+        "markdown" => Markdown,
+        "plan" => Plan,
+        "rich_text" => RichText,
+        "section" => Section,
+        "table" => Table,
+        "task_card" => TaskCard,
+        "video" => Video,
+        _ if lenient => Unknown,
+    }
+}
+
 #[cfg(test)]
 pub mod test_helpers {
     use super::rich_text::test_helpers as rich_text_helper;
@@ -180,6 +241,17 @@ pub mod test_helpers {
         }
     }
 
+    pub fn input() -> Input {
+        Input {
+            label: Some(plain_text("Title")),
+            element: Some(elements::test_helpers::text_input().into()),
+            dispatch_action: None,
+            block_id: Some("input_0".into()),
+            hint: None,
+            optional: None,
+        }
+    }
+
     pub fn rich_text() -> RichText {
         RichText {
             block_id: Some("rich_text_0".into()),
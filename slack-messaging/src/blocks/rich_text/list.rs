@@ -1,7 +1,7 @@
 use super::RichTextSection;
 use crate::validators::*;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use slack_messaging_derive::Builder;
 
 /// [Rich text list element](https://docs.slack.dev/reference/block-kit/blocks/rich-text-block#rich_text_list)
@@ -111,7 +111,7 @@ pub struct RichTextList {
 }
 
 /// List style for [`RichTextList`].
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum ListStyle {
     /// Bullet list style.
@@ -2,7 +2,7 @@ use crate::validators::*;
 
 use paste::paste;
 use serde::Serialize;
-use slack_messaging_derive::Builder;
+use slack_messaging_derive::{Builder, Validate};
 
 /// Builder objects for [rich text sub-elements](https://docs.slack.dev/reference/block-kit/blocks/rich-text-block/#usage-info).
 pub mod builders;
@@ -36,7 +36,7 @@ pub use section::RichTextSection;
 
 /// [Rich text sub elements](https://api.slack.com/reference/block-kit/blocks#element-types)
 /// representation.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum RichTextSubElement {
     /// [Rich text section element](https://docs.slack.dev/reference/block-kit/blocks/rich-text-block#rich_text_section)
@@ -73,6 +73,15 @@ impl_sub_element! {
     Quote,
 }
 
+deserialize_by_type! {
+    RichTextSubElement {
+        "rich_text_section" => Section,
+        "rich_text_list" => List,
+        "rich_text_preformatted" => Preformatted,
+        "rich_text_quote" => Quote,
+    }
+}
+
 /// [Rich text block](https://docs.slack.dev/reference/block-kit/blocks/rich-text-block) representation.
 ///
 /// # Fields and Validations
@@ -233,7 +242,10 @@ mod tests {
         assert_eq!(err.object(), "RichText");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 }
 
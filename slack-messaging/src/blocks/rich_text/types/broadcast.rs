@@ -1,6 +1,6 @@
 use crate::validators::*;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use slack_messaging_derive::Builder;
 
 /// [broadcast element type](https://docs.slack.dev/reference/block-kit/blocks/rich-text-block/#broadcast-element-type)
@@ -89,7 +89,7 @@ mod tests {
 
 /// The range of broadcast for
 /// [`RichTextElementBroadcast`](crate::blocks::rich_text::types::RichTextElementBroadcast) element.
-#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum BroadcastRange {
     /// notifies only the active members of a channel.
@@ -1,5 +1,6 @@
 use paste::paste;
 use serde::Serialize;
+use slack_messaging_derive::Validate;
 
 /// Builders for rich text element types.
 pub mod builders;
@@ -28,7 +29,7 @@ pub use usergroup::RichTextElementUserGroup;
 
 /// [Rich text element types](https://api.slack.com/reference/block-kit/blocks#element-types)
 /// representation.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum RichTextElementType {
     /// [broadcast element type](https://docs.slack.dev/reference/block-kit/blocks/rich-text-block/#broadcast-element-type)
@@ -85,6 +86,20 @@ impl_element_type! {
     UserGroup,
 }
 
+deserialize_by_type! {
+    RichTextElementType {
+        "broadcast" => Broadcast,
+        "channel" => Channel,
+        "color" => Color,
+        "date" => Date,
+        "emoji" => Emoji,
+        "link" => Link,
+        "text" => Text,
+        "user" => User,
+        "usergroup" => UserGroup,
+    }
+}
+
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
@@ -10,7 +10,7 @@ use crate::errors::ValidationErrorKind;
 use crate::validators::*;
 
 use serde::Serialize;
-use slack_messaging_derive::Builder;
+use slack_messaging_derive::{Builder, Validate};
 
 /// [Section block](https://docs.slack.dev/reference/block-kit/blocks/section-block)
 /// representation.
@@ -124,7 +124,7 @@ fn validate(val: &Section) -> Vec<ValidationErrorKind> {
 }
 
 /// Objects that can be set to [Section] as an accessory.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum Accessory {
     /// [Button element](https://docs.slack.dev/reference/block-kit/block-elements/button-element)
@@ -233,6 +233,29 @@ accessory_from! {
     WorkflowButton,
 }
 
+deserialize_by_type! {
+    Accessory {
+        "button" => Button,
+        "checkboxes" => Checkboxes,
+        "datepicker" => DatePicker,
+        "image" => Image,
+        "multi_static_select" => MultiSelectMenuStaticOptions,
+        "multi_external_select" => MultiSelectMenuExternalDataSource,
+        "multi_users_select" => MultiSelectMenuUsers,
+        "multi_conversations_select" => MultiSelectMenuConversations,
+        "multi_channels_select" => MultiSelectMenuPublicChannels,
+        "overflow" => OverflowMenu,
+        "radio_buttons" => RadioButtonGroup,
+        "static_select" => SelectMenuStaticOptions,
+        "external_select" => SelectMenuExternalDataSource,
+        "users_select" => SelectMenuUsers,
+        "conversations_select" => SelectMenuConversations,
+        "channels_select" => SelectMenuPublicChannels,
+        "timepicker" => TimePicker,
+        "workflow_button" => WorkflowButton,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -306,7 +329,7 @@ mod tests {
         assert_eq!(err.object(), "Section");
 
         let errors = err.field("text");
-        assert!(errors.includes(ValidationErrorKind::MinTextLength(1)));
+        assert!(errors.includes(ValidationErrorKind::MinTextLength { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -318,7 +341,10 @@ mod tests {
         assert_eq!(err.object(), "Section");
 
         let errors = err.field("text");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(3000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
     }
 
     #[test]
@@ -331,7 +357,10 @@ mod tests {
         assert_eq!(err.object(), "Section");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -341,7 +370,10 @@ mod tests {
         assert_eq!(err.object(), "Section");
 
         let errors = err.field("fields");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(10)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 10,
+            actual: 11
+        }));
     }
 
     #[test]
@@ -353,7 +385,10 @@ mod tests {
         assert_eq!(err.object(), "Section");
 
         let errors = err.field("fields");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(2000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 2000,
+            actual: 2001
+        }));
     }
 
     #[test]
@@ -1,8 +1,9 @@
 use crate::blocks::RichText;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
+use slack_messaging_derive::Validate;
 
 /// A table cell value in table rows
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum TableCell {
     /// A plain text table cell
@@ -12,6 +13,13 @@ pub enum TableCell {
     RichText(RichText),
 }
 
+deserialize_by_type! {
+    TableCell {
+        "raw_text" => RawText,
+        "rich_text" => RichText,
+    }
+}
+
 impl<T: Into<String>> From<T> for TableCell {
     fn from(value: T) -> Self {
         Self::RawText(RawText::from(value))
@@ -61,6 +69,22 @@ impl Serialize for RawText {
     }
 }
 
+impl<'de> Deserialize<'de> for RawText {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum Raw {
+            RawText { text: String },
+        }
+
+        let Raw::RawText { text } = Raw::deserialize(deserializer)?;
+        Ok(Self(text))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,6 +127,20 @@ mod tests {
         assert_eq!(json, expected);
     }
 
+    #[test]
+    fn it_deserializes_each_cell_by_type() {
+        let json = serde_json::json!({
+            "type": "raw_text",
+            "text": "Data 1A",
+        });
+        let cell: TableCell = serde_json::from_value(json).unwrap();
+        assert_eq!(cell, TableCell::RawText("Data 1A".into()));
+
+        let json = serde_json::to_value(TableCell::RichText(rich_text())).unwrap();
+        let cell: TableCell = serde_json::from_value(json).unwrap();
+        assert_eq!(cell, TableCell::RichText(rich_text()));
+    }
+
     fn rich_text() -> RichText {
         RichText::builder()
             .element(
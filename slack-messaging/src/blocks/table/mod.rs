@@ -233,7 +233,10 @@ mod tests {
         assert_eq!(err.object(), "Table");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -252,7 +255,10 @@ mod tests {
         assert_eq!(err.object(), "Table");
 
         let errors = err.field("rows");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(100)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
     }
 
     #[test]
@@ -266,7 +272,10 @@ mod tests {
         assert_eq!(err.object(), "Table");
 
         let errors = err.field("column_settings");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 20,
+            actual: 21
+        }));
     }
 
     fn row<T: Into<TableCell>>(cells: Vec<T>) -> TableRow {
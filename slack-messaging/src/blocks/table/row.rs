@@ -159,6 +159,9 @@ mod tests {
         assert_eq!(err.object(), "TableRow");
 
         let errors = err.field("cells");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(20)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 20,
+            actual: 21
+        }));
     }
 }
@@ -2,7 +2,7 @@ use crate::blocks::RichText;
 use crate::blocks::elements::UrlSource;
 use crate::validators::*;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use slack_messaging_derive::Builder;
 
 /// [Task card](https://docs.slack.dev/reference/block-kit/blocks/task-card-block) representation.
@@ -136,7 +136,7 @@ pub struct TaskCard {
 }
 
 /// Values that can be set to the status field of [TaskCard].
-#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskStatus {
     Pending,
@@ -283,7 +283,10 @@ mod tests {
         assert_eq!(err.object(), "TaskCard");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     fn rich_text(texts: Vec<RichTextElementType>) -> RichText {
@@ -201,7 +201,10 @@ mod tests {
         assert_eq!(err.object(), "Video");
 
         let errors = err.field("author_name");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(50)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 50,
+            actual: 51
+        }));
     }
 
     #[test]
@@ -217,7 +220,10 @@ mod tests {
         assert_eq!(err.object(), "Video");
 
         let errors = err.field("block_id");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(255)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
     }
 
     #[test]
@@ -233,7 +239,10 @@ mod tests {
         assert_eq!(err.object(), "Video");
 
         let errors = err.field("description");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(200)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 200,
+            actual: 201
+        }));
     }
 
     #[test]
@@ -262,7 +271,10 @@ mod tests {
         assert_eq!(err.object(), "Video");
 
         let errors = err.field("title");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(200)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 200,
+            actual: 201
+        }));
     }
 
     #[test]
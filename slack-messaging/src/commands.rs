@@ -0,0 +1,177 @@
+use serde::Deserialize;
+
+/// [Slash command payload](https://docs.slack.dev/interactivity/implementing-slash-commands#app_command_handling)
+/// representation.
+///
+/// Slack sends this payload to your app as `application/x-www-form-urlencoded` body when a user
+/// invokes a slash command. Respond to it with a [`Message`](crate::Message) whose
+/// `response_type` is set by [`in_channel`](crate::MessageBuilder::in_channel) or
+/// [`ephemeral`](crate::MessageBuilder::ephemeral).
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::Message;
+/// use slack_messaging::commands::SlashCommand;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = "team_id=T0001&team_domain=example&channel_id=C2147483705\
+///     &channel_name=test&user_id=U2147483697&user_name=Steve&command=%2Fweather\
+///     &text=94070&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2F1234%2F5678\
+///     &trigger_id=13345224609.738474920.8088930838d88f008e0&api_app_id=A123456";
+///
+/// let command = SlashCommand::from_form(body.as_bytes())?;
+///
+/// assert_eq!(command.command, "/weather");
+/// assert_eq!(command.text, "94070");
+///
+/// let response = Message::builder()
+///     .text(format!("Weather for {}: sunny", command.text))
+///     .in_channel()
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "text": "Weather for 94070: sunny",
+///     "response_type": "in_channel"
+/// });
+///
+/// assert_eq!(serde_json::to_value(response)?, expected);
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SlashCommand {
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// ID of the workspace the command was invoked in.
+    pub team_id: String,
+
+    /// Domain of the workspace the command was invoked in.
+    pub team_domain: Option<String>,
+
+    /// ID of the enterprise organization the command was invoked in.
+    pub enterprise_id: Option<String>,
+
+    /// Name of the enterprise organization the command was invoked in.
+    pub enterprise_name: Option<String>,
+
+    /// ID of the channel the command was invoked in.
+    pub channel_id: String,
+
+    /// Name of the channel the command was invoked in.
+    pub channel_name: Option<String>,
+
+    /// ID of the user who invoked the command.
+    pub user_id: String,
+
+    /// Name of the user who invoked the command. This field is deprecated by Slack.
+    pub user_name: Option<String>,
+
+    /// The command that was invoked, e.g. `/weather`.
+    pub command: String,
+
+    /// Text the user typed after the command.
+    #[serde(default)]
+    pub text: String,
+
+    /// URL to respond to the command with [`Message`](crate::Message).
+    pub response_url: String,
+
+    /// Short-lived ID to open a modal.
+    pub trigger_id: String,
+
+    /// ID of your app.
+    pub api_app_id: Option<String>,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default, deserialize_with = "crate::de::bool_from_str")]
+    pub is_enterprise_install: bool,
+}
+
+impl SlashCommand {
+    /// Parses the `application/x-www-form-urlencoded` request body Slack sends.
+    pub fn from_form(body: &[u8]) -> Result<Self, serde_urlencoded::de::Error> {
+        serde_urlencoded::from_bytes(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // https://docs.slack.dev/authentication/verifying-requests-from-slack
+    const BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+
+    #[test]
+    fn it_parses_urlencoded_body() {
+        let command = SlashCommand::from_form(BODY.as_bytes()).unwrap();
+
+        let expected = SlashCommand {
+            token: Some("xyzz0WbapA4vBCDEFasx0q6G".into()),
+            team_id: "T1DC2JH3J".into(),
+            team_domain: Some("testteamnow".into()),
+            enterprise_id: None,
+            enterprise_name: None,
+            channel_id: "G8PSS9T3V".into(),
+            channel_name: Some("foobar".into()),
+            user_id: "U2CERLKJA".into(),
+            user_name: Some("roadrunner".into()),
+            command: "/webhook-collect".into(),
+            text: "".into(),
+            response_url:
+                "https://hooks.slack.com/commands/T1DC2JH3J/397700885554/96rGlfmibIGlgcZRskXaIFfN"
+                    .into(),
+            trigger_id: "398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c".into(),
+            api_app_id: None,
+            is_enterprise_install: false,
+        };
+
+        assert_eq!(command, expected);
+    }
+
+    #[test]
+    fn it_parses_enterprise_fields_and_decodes_text() {
+        let body = "team_id=T1&enterprise_id=E1&enterprise_name=Acme&channel_id=C1&user_id=U1\
+            &command=%2Fdeploy&text=app+%3Cv1.2%3E&response_url=https%3A%2F%2Fexample.com\
+            &trigger_id=1.2.3&api_app_id=A1&is_enterprise_install=true";
+
+        let command = SlashCommand::from_form(body.as_bytes()).unwrap();
+
+        assert_eq!(command.enterprise_id.as_deref(), Some("E1"));
+        assert_eq!(command.enterprise_name.as_deref(), Some("Acme"));
+        assert_eq!(command.text, "app <v1.2>");
+        assert_eq!(command.api_app_id.as_deref(), Some("A1"));
+        assert!(command.is_enterprise_install);
+    }
+
+    #[test]
+    fn it_parses_json_payload_of_socket_mode() {
+        let json = serde_json::json!({
+            "token": "bHKJ2n9AW6Ju3MjciOHfbA1b",
+            "team_id": "T1",
+            "channel_id": "C1",
+            "user_id": "U1",
+            "command": "/deploy",
+            "text": "app",
+            "response_url": "https://example.com",
+            "trigger_id": "1.2.3",
+            "is_enterprise_install": "false"
+        });
+
+        let command: SlashCommand = serde_json::from_value(json).unwrap();
+
+        assert_eq!(command.command, "/deploy");
+        assert!(!command.is_enterprise_install);
+    }
+
+    #[test]
+    fn it_fails_to_parse_body_without_required_fields() {
+        let err = SlashCommand::from_form(b"team_id=T1&text=foo").unwrap_err();
+        assert!(err.to_string().contains("missing field"));
+    }
+}
@@ -88,7 +88,11 @@ pub struct ConfirmationDialog {
     #[builder(validate("required", "text_object::max_30"))]
     pub(crate) deny: Option<Text<Plain>>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::de::style"
+    )]
     #[builder(private_setter)]
     pub(crate) style: Option<&'static str>,
 }
@@ -172,7 +176,10 @@ mod tests {
         assert_eq!(err.object(), "ConfirmationDialog");
 
         let title_err = err.field("title");
-        assert!(title_err.includes(ValidationErrorKind::MaxTextLength(100)));
+        assert!(title_err.includes(ValidationErrorKind::MaxTextLength {
+            max: 100,
+            actual: 101
+        }));
     }
 
     #[test]
@@ -201,7 +208,10 @@ mod tests {
         assert_eq!(err.object(), "ConfirmationDialog");
 
         let text_err = err.field("text");
-        assert!(text_err.includes(ValidationErrorKind::MaxTextLength(300)));
+        assert!(text_err.includes(ValidationErrorKind::MaxTextLength {
+            max: 300,
+            actual: 301
+        }));
     }
 
     #[test]
@@ -230,7 +240,10 @@ mod tests {
         assert_eq!(err.object(), "ConfirmationDialog");
 
         let confirm_err = err.field("confirm");
-        assert!(confirm_err.includes(ValidationErrorKind::MaxTextLength(30)));
+        assert!(confirm_err.includes(ValidationErrorKind::MaxTextLength {
+            max: 30,
+            actual: 31
+        }));
     }
 
     #[test]
@@ -259,6 +272,9 @@ mod tests {
         assert_eq!(err.object(), "ConfirmationDialog");
 
         let deny_err = err.field("deny");
-        assert!(deny_err.includes(ValidationErrorKind::MaxTextLength(30)));
+        assert!(deny_err.includes(ValidationErrorKind::MaxTextLength {
+            max: 30,
+            actual: 31
+        }));
     }
 }
@@ -114,6 +114,18 @@ where
     pub(crate) url: Option<String>,
 }
 
+impl<T: TextExt, P> Opt<T, P> {
+    /// get text field value.
+    pub fn text(&self) -> Option<&T> {
+        self.text.as_ref()
+    }
+
+    /// get value field value.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
 impl<T: TextExt> OptBuilder<T, UrlAvailable> {
     /// get url field value.
     pub fn get_url(&self) -> Option<&String> {
@@ -222,7 +234,10 @@ mod tests {
         assert_eq!(err.object(), "Opt");
 
         let errors = err.field("text");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
     }
 
     #[test]
@@ -247,7 +262,10 @@ mod tests {
         assert_eq!(err.object(), "Opt");
 
         let errors = err.field("value");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(150)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 150,
+            actual: 151
+        }));
     }
 
     #[test]
@@ -261,7 +279,10 @@ mod tests {
         assert_eq!(err.object(), "Opt");
 
         let errors = err.field("description");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
     }
 
     #[test]
@@ -276,6 +297,9 @@ mod tests {
         assert_eq!(err.object(), "Opt");
 
         let errors = err.field("url");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(3000)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
     }
 }
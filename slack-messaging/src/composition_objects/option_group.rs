@@ -188,7 +188,10 @@ mod tests {
         assert_eq!(err.object(), "OptGroup");
 
         let errors = err.field("label");
-        assert!(errors.includes(ValidationErrorKind::MaxTextLength(75)));
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 75,
+            actual: 76
+        }));
     }
 
     #[test]
@@ -214,6 +217,9 @@ mod tests {
         assert_eq!(err.object(), "OptGroup");
 
         let errors = err.field("options");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(100)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
     }
 }
@@ -1,7 +1,8 @@
+use crate::de::DeserializeLenient;
 use crate::validators::*;
 
-use serde::{Serialize, Serializer};
-use slack_messaging_derive::Builder;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use slack_messaging_derive::{Builder, Validate};
 
 /// [Text object](https://docs.slack.dev/reference/block-kit/composition-objects/text-object)
 /// representation.
@@ -78,6 +79,7 @@ use slack_messaging_derive::Builder;
 /// # }
 ///```
 #[derive(Debug, Clone, Builder)]
+#[builder(no_deserialize)]
 pub struct Text<T> {
     #[builder(phantom = "T")]
     pub(crate) r#type: std::marker::PhantomData<T>,
@@ -218,9 +220,77 @@ impl Serialize for Text<Mrkdwn> {
     }
 }
 
+impl<'de> Deserialize<'de> for TextBuilder<Plain> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum Raw {
+            PlainText {
+                text: Option<String>,
+                emoji: Option<bool>,
+            },
+        }
+
+        let Raw::PlainText { text, emoji } = Raw::deserialize(deserializer)?;
+
+        Ok(Text::<Plain>::builder().set_text(text).set_emoji(emoji))
+    }
+}
+
+impl<'de> Deserialize<'de> for TextBuilder<Mrkdwn> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum Raw {
+            Mrkdwn {
+                text: Option<String>,
+                verbatim: Option<bool>,
+            },
+        }
+
+        let Raw::Mrkdwn { text, verbatim } = Raw::deserialize(deserializer)?;
+
+        Ok(Text::<Mrkdwn>::builder()
+            .set_text(text)
+            .set_verbatim(verbatim))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Text<T>
+where
+    TextBuilder<T>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        TextBuilder::<T>::deserialize(deserializer)?
+            .build()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl<'de, T> DeserializeLenient<'de> for Text<T>
+where
+    TextBuilder<T>: Deserialize<'de>,
+{
+    fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        TextBuilder::<T>::deserialize(deserializer).map(TextBuilder::build_unchecked)
+    }
+}
+
 /// Enum representation of Text objects.
 /// Use this when you need to handle both [`Plain`] and [`Mrkdwn`] text objects.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
 #[serde(untagged)]
 pub enum TextContent {
     /// Plain text object.
@@ -229,6 +299,13 @@ pub enum TextContent {
     Mrkdwn(Text<Mrkdwn>),
 }
 
+deserialize_by_type! {
+    TextContent {
+        "plain_text" => Plain,
+        "mrkdwn" => Mrkdwn,
+    }
+}
+
 impl TextExt for TextContent {
     /// get text field value.
     fn text(&self) -> Option<&str> {
@@ -269,7 +346,7 @@ mod tests {
         let err = Text::<Plain>::builder().text("").build().unwrap_err();
         assert_eq!(err.object(), "Text");
         let text_err = err.field("text");
-        assert!(text_err.includes(ValidationErrorKind::MinTextLength(1)));
+        assert!(text_err.includes(ValidationErrorKind::MinTextLength { min: 1, actual: 0 }));
     }
 
     #[test]
@@ -280,7 +357,10 @@ mod tests {
             .unwrap_err();
         assert_eq!(err.object(), "Text");
         let text_err = err.field("text");
-        assert!(text_err.includes(ValidationErrorKind::MaxTextLength(3000)));
+        assert!(text_err.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
     }
 
     mod plain_text {
@@ -367,6 +447,46 @@ mod tests {
             assert_ne!(text1, text4);
             assert_eq!(text3, text5);
         }
+
+        #[test]
+        fn it_deserializes_from_json() {
+            let json = serde_json::json!({
+                "type": "plain_text",
+                "text": "Hello World :smile:",
+                "emoji": true
+            });
+
+            let expected = Text::<Plain> {
+                r#type: std::marker::PhantomData,
+                text: Some("Hello World :smile:".into()),
+                emoji: Some(true),
+                verbatim: None,
+            };
+            let text: Text<Plain> = serde_json::from_value(json).unwrap();
+            assert_eq!(text, expected);
+        }
+
+        #[test]
+        fn it_fails_to_deserialize_mrkdwn_text() {
+            let json = serde_json::json!({
+                "type": "mrkdwn",
+                "text": "*Hello*"
+            });
+            assert!(serde_json::from_value::<Text<Plain>>(json).is_err());
+        }
+
+        #[test]
+        fn it_fails_to_deserialize_empty_text() {
+            let json = serde_json::json!({
+                "type": "plain_text",
+                "text": ""
+            });
+            let err = serde_json::from_value::<Text<Plain>>(json).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("text: min text length is `1` characters but got `0`")
+            );
+        }
     }
 
     mod mrkdwn_text {
@@ -453,6 +573,33 @@ mod tests {
             assert_ne!(text1, text4);
             assert_eq!(text3, text5);
         }
+
+        #[test]
+        fn it_deserializes_from_json() {
+            let json = serde_json::json!({
+                "type": "mrkdwn",
+                "text": "*Hello* _World_ :smile:",
+                "verbatim": false
+            });
+
+            let expected = Text::<Mrkdwn> {
+                r#type: std::marker::PhantomData,
+                text: Some("*Hello* _World_ :smile:".into()),
+                emoji: None,
+                verbatim: Some(false),
+            };
+            let text: Text<Mrkdwn> = serde_json::from_value(json).unwrap();
+            assert_eq!(text, expected);
+        }
+
+        #[test]
+        fn it_fails_to_deserialize_plain_text() {
+            let json = serde_json::json!({
+                "type": "plain_text",
+                "text": "Hello"
+            });
+            assert!(serde_json::from_value::<Text<Mrkdwn>>(json).is_err());
+        }
     }
 
     mod text_content {
@@ -493,5 +640,32 @@ mod tests {
             let json = serde_json::to_value(&text).unwrap();
             assert_eq!(json, expected);
         }
+
+        #[test]
+        fn it_deserializes_each_variant_by_type() {
+            let json = serde_json::json!({
+                "type": "plain_text",
+                "text": "Hello"
+            });
+            let text: TextContent = serde_json::from_value(json).unwrap();
+            assert!(matches!(text, TextContent::Plain(_)));
+
+            let json = serde_json::json!({
+                "type": "mrkdwn",
+                "text": "*Hello*"
+            });
+            let text: TextContent = serde_json::from_value(json).unwrap();
+            assert!(matches!(text, TextContent::Mrkdwn(_)));
+        }
+
+        #[test]
+        fn it_fails_to_deserialize_unknown_type() {
+            let json = serde_json::json!({
+                "type": "rich_text",
+                "text": "Hello"
+            });
+            let err = serde_json::from_value::<TextContent>(json).unwrap_err();
+            assert!(err.to_string().contains("unknown variant `rich_text`"));
+        }
     }
 }
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Deserializer, de::Error};
+use serde_json::Number;
+
+/// Deserialization of payloads Slack sends, such as the blocks of message events. Objects are
+/// built without the builder validations, and blocks of unknown type or that fail to deserialize
+/// are kept as [`Block::Unknown`](crate::blocks::Block::Unknown), so that a message another app
+/// posted never fails the whole payload.
+///
+/// It is implemented by `#[derive(Builder)]` and `deserialize_by_type!`. The fields of the object
+/// are deserialized leniently as well, while [`Deserialize`] stays strict wherever it is called.
+pub(crate) trait DeserializeLenient<'de>: Sized {
+    fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Wrapper deserializing the value through [`DeserializeLenient`].
+pub(crate) struct Lenient<T>(pub(crate) T);
+
+impl<'de, T: DeserializeLenient<'de>> Deserialize<'de> for Lenient<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_lenient(deserializer).map(Lenient)
+    }
+}
+
+impl<'de, T: DeserializeLenient<'de>> DeserializeLenient<'de> for Option<T> {
+    fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Lenient<T>>::deserialize(deserializer).map(|value| value.map(|v| v.0))
+    }
+}
+
+impl<'de, T: DeserializeLenient<'de>> DeserializeLenient<'de> for Vec<T> {
+    fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Lenient<T>>::deserialize(deserializer)
+            .map(|values| values.into_iter().map(|v| v.0).collect())
+    }
+}
+
+impl<'de, T: DeserializeLenient<'de>> DeserializeLenient<'de> for Box<T> {
+    fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_lenient(deserializer).map(Box::new)
+    }
+}
+
+// Values without builder validations are deserialized leniently in the same way as strictly.
+macro_rules! impl_deserialize_lenient_as_strict {
+    ($($ty:ty),*) => {
+        $(
+            impl<'de> DeserializeLenient<'de> for $ty {
+                fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    <$ty as Deserialize<'de>>::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize_lenient_as_strict!(
+    String,
+    bool,
+    i64,
+    serde_json::Value,
+    Number,
+    crate::blocks::table::RawText,
+    crate::blocks::data_table::RawNumber,
+    crate::ResponseType,
+    crate::composition_objects::types::Conversation,
+    crate::composition_objects::types::TriggerAction,
+    crate::blocks::AlertLevel,
+    crate::blocks::FileSource,
+    crate::blocks::TaskStatus,
+    crate::blocks::elements::types::FileType,
+    crate::blocks::elements::types::Icon,
+    crate::blocks::rich_text::ListStyle,
+    crate::blocks::rich_text::types::BroadcastRange,
+    crate::blocks::table::ColumnAlignment,
+    chrono::NaiveDate,
+    chrono::NaiveTime
+);
+
+/// Deserializes a field of payloads Slack sends through [`DeserializeLenient`]. Use it as
+/// `#[serde(default, deserialize_with = "crate::de::lenient")]`.
+pub(crate) fn lenient<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeLenient<'de>,
+{
+    T::deserialize_lenient(deserializer)
+}
+
+const STYLES: &[&str] = &["primary", "danger"];
+
+/// Deserializes the `style` field of buttons and confirmation dialogs into one of the static
+/// strings their builders set.
+pub(crate) fn style<'de, D>(deserializer: D) -> Result<Option<&'static str>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None => Ok(None),
+        Some(style) => STYLES
+            .iter()
+            .find(|s| **s == style)
+            .copied()
+            .map(Some)
+            .ok_or_else(|| D::Error::unknown_variant(style, STYLES)),
+    }
+}
+
+/// Deserializes a number Slack sends as a string, such as the value of number input elements.
+pub(crate) fn number_from_str<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some(value) => value.parse().map(Some).map_err(D::Error::custom),
+    }
+}
+
+/// Deserializes a boolean Slack sends either as a boolean or as a string, such as the
+/// `is_enterprise_install` field of slash commands delivered over Socket Mode.
+pub(crate) fn bool_from_str<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrString {
+        Bool(bool),
+        String(String),
+    }
+
+    match BoolOrString::deserialize(deserializer)? {
+        BoolOrString::Bool(value) => Ok(value),
+        BoolOrString::String(value) => value.parse().map_err(D::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Message;
+    use crate::blocks::Block;
+    use serde_json::json;
+
+    #[test]
+    fn it_keeps_strict_deserialize_strict_inside_lenient_payload() {
+        // Deserializes Message strictly wherever it is called.
+        struct Strict(Result<Message, String>);
+
+        impl<'de> Deserialize<'de> for Strict {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = serde_json::Value::deserialize(deserializer)?;
+                Ok(Strict(
+                    Message::deserialize(value).map_err(|err| err.to_string()),
+                ))
+            }
+        }
+
+        impl<'de> DeserializeLenient<'de> for Strict {
+            fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Strict::deserialize(deserializer)
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct Payload {
+            #[serde(deserialize_with = "lenient")]
+            blocks: Vec<Block>,
+            #[serde(deserialize_with = "lenient")]
+            message: Strict,
+        }
+
+        let header = json!({
+            "type": "header",
+            "text": { "type": "plain_text", "text": "a".repeat(151) }
+        });
+        let payload: Payload = serde_json::from_value(json!({
+            "blocks": [header],
+            "message": { "blocks": [header] }
+        }))
+        .unwrap();
+
+        assert!(matches!(payload.blocks[0], Block::Header(_)));
+        assert!(
+            payload
+                .message
+                .0
+                .unwrap_err()
+                .contains("max text length is `150` characters but got `151`")
+        );
+    }
+
+    #[test]
+    fn it_deserializes_known_styles() {
+        assert_eq!(style(json!("primary")).unwrap(), Some("primary"));
+        assert_eq!(style(json!("danger")).unwrap(), Some("danger"));
+        assert_eq!(style(json!(null)).unwrap(), None);
+    }
+
+    #[test]
+    fn it_fails_to_deserialize_unknown_style() {
+        assert!(style(json!("secondary")).is_err());
+    }
+
+    #[test]
+    fn it_deserializes_numbers_from_strings() {
+        assert_eq!(number_from_str(json!("42")).unwrap(), Some(42.into()));
+        assert_eq!(
+            number_from_str(json!("2.5")).unwrap(),
+            Number::from_f64(2.5)
+        );
+        assert_eq!(number_from_str(json!(null)).unwrap(), None);
+        assert!(number_from_str(json!("foo")).is_err());
+    }
+
+    #[test]
+    fn it_deserializes_booleans_from_strings() {
+        assert!(bool_from_str(json!(true)).unwrap());
+        assert!(bool_from_str(json!("true")).unwrap());
+        assert!(!bool_from_str(json!("false")).unwrap());
+        assert!(bool_from_str(json!("yes")).is_err());
+    }
+}
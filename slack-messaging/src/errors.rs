@@ -1,4 +1,9 @@
+use crate::Surface;
+
+use serde::Serialize;
+use serde::ser::{SerializeMap, Serializer};
 use std::borrow::Cow;
+use std::fmt;
 use thiserror::Error;
 
 /// Validation error variants.
@@ -9,20 +14,40 @@ pub enum ValidationErrorKind {
     Required,
 
     /// Field exceeds maximum text length.
-    #[error("max text length `{0}` characters")]
-    MaxTextLength(usize),
+    #[error("max text length is `{max}` characters but got `{actual}`")]
+    MaxTextLength {
+        /// Maximum number of characters.
+        max: usize,
+        /// Number of characters of the value.
+        actual: usize,
+    },
 
     /// Field does not meet minimum text length.
-    #[error("min text length `{0}` characters")]
-    MinTextLength(usize),
+    #[error("min text length is `{min}` characters but got `{actual}`")]
+    MinTextLength {
+        /// Minimum number of characters.
+        min: usize,
+        /// Number of characters of the value.
+        actual: usize,
+    },
 
     /// Field exceeds maximum array length.
-    #[error("max array length `{0}` items")]
-    MaxArraySize(usize),
+    #[error("max array length is `{max}` items but got `{actual}`")]
+    MaxArraySize {
+        /// Maximum number of items.
+        max: usize,
+        /// Number of items of the value.
+        actual: usize,
+    },
 
     /// Field does not meet minimum array length.
-    #[error("min array length `{0}` items")]
-    MinArraySize(usize),
+    #[error("min array length is `{min}` items but got `{actual}`")]
+    MinArraySize {
+        /// Minimum number of items.
+        min: usize,
+        /// Number of items of the value.
+        actual: usize,
+    },
 
     /// Field does not meet non-empty condition.
     #[error("the array cannot be empty")]
@@ -33,12 +58,22 @@ pub enum ValidationErrorKind {
     InvalidFormat(&'static str),
 
     /// Field exceeds maximum integer value.
-    #[error("max value is `{0}")]
-    MaxIntegerValue(i64),
+    #[error("max value is `{max}` but got `{actual}`")]
+    MaxIntegerValue {
+        /// Maximum value.
+        max: i64,
+        /// The value.
+        actual: i64,
+    },
 
     /// Field does not meet minimum integer value.
-    #[error("min value is `{0}")]
-    MinIntegerValue(i64),
+    #[error("min value is `{min}` but got `{actual}`")]
+    MinIntegerValue {
+        /// Minimum value.
+        min: i64,
+        /// The value.
+        actual: i64,
+    },
 
     /// Field must be greater than zero.
     #[error("value must be greater than zero")]
@@ -52,6 +87,7 @@ pub enum ValidationErrorKind {
     #[error("required either {0} or {1}")]
     EitherRequired(&'static str, &'static str),
 
+    /// At least one of the four fields is required but none is provided.
     #[error("at least one of {0}, {1}, {2}, or {3} is required")]
     AtLeastOneOf4(&'static str, &'static str, &'static str, &'static str),
 
@@ -74,6 +110,127 @@ pub enum ValidationErrorKind {
     /// Every data point label in every series must match a value in axis config categories.
     #[error("every data point label in every series must match a value in axis config categories")]
     DataPointLabelMatching,
+
+    /// Modal containing input blocks must have submit button.
+    #[error("submit is required when the modal contains an input block")]
+    ModalSubmitRequired,
+
+    /// No input block with the block_id exists in the view.
+    #[error("no input block with the block_id exists in the view")]
+    InputBlockNotFound,
+
+    /// Total number of blocks across a message and its attachments exceeds the maximum.
+    #[error(
+        "max total blocks is `{max}` across the message and its attachments but got `{actual}`"
+    )]
+    MaxTotalBlocks {
+        /// Maximum number of blocks.
+        max: usize,
+        /// Number of blocks of the message and its attachments.
+        actual: usize,
+    },
+
+    /// Block at the index is not available in the surface.
+    #[error("block `{1}` at index {0} is not available in {2}")]
+    UnsupportedBlock(usize, &'static str, Surface),
+
+    /// Element of the block at the index is not available in the surface.
+    #[error("element `{2}` of block `{1}` at index {0} is not available in {3}")]
+    UnsupportedElement(usize, &'static str, &'static str, Surface),
+
+    /// Field of the message is only available when responding to a `response_url`, not in
+    /// Web API requests.
+    #[error("{0} is only available when responding to a response_url")]
+    ResponseUrlOnlyField(&'static str),
+}
+
+impl ValidationErrorKind {
+    /// Returns the error code, which is stable across releases unlike the message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Required => "required",
+            Self::MaxTextLength { .. } => "max_text_length",
+            Self::MinTextLength { .. } => "min_text_length",
+            Self::MaxArraySize { .. } => "max_array_size",
+            Self::MinArraySize { .. } => "min_array_size",
+            Self::EmptyArray => "empty_array",
+            Self::InvalidFormat(_) => "invalid_format",
+            Self::MaxIntegerValue { .. } => "max_integer_value",
+            Self::MinIntegerValue { .. } => "min_integer_value",
+            Self::MustBeGreaterThanZero => "must_be_greater_than_zero",
+            Self::ExclusiveField(..) => "exclusive_field",
+            Self::EitherRequired(..) => "either_required",
+            Self::AtLeastOneOf4(..) => "at_least_one_of",
+            Self::NoFieldProvided => "no_field_provided",
+            Self::RichTextSingleElement => "rich_text_single_element",
+            Self::RichTextTableHeader => "rich_text_table_header",
+            Self::UniqueSeriesName => "unique_series_name",
+            Self::DataPointLabelMatching => "data_point_label_matching",
+            Self::ModalSubmitRequired => "modal_submit_required",
+            Self::InputBlockNotFound => "input_block_not_found",
+            Self::MaxTotalBlocks { .. } => "max_total_blocks",
+            Self::UnsupportedBlock(..) => "unsupported_block",
+            Self::UnsupportedElement(..) => "unsupported_element",
+            Self::ResponseUrlOnlyField(_) => "response_url_only_field",
+        }
+    }
+}
+
+/// Serialized as an object with the error `code`, the `message` and the parameters of the
+/// variant, e.g. `{"code": "max_text_length", "message": "...", "max": 3000, "actual": 3012}`.
+impl Serialize for ValidationErrorKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", self.code())?;
+        map.serialize_entry("message", &self.to_string())?;
+
+        match *self {
+            Self::MaxTextLength { max, actual }
+            | Self::MaxArraySize { max, actual }
+            | Self::MaxTotalBlocks { max, actual } => {
+                map.serialize_entry("max", &max)?;
+                map.serialize_entry("actual", &actual)?;
+            }
+            Self::MinTextLength { min, actual } | Self::MinArraySize { min, actual } => {
+                map.serialize_entry("min", &min)?;
+                map.serialize_entry("actual", &actual)?;
+            }
+            Self::MaxIntegerValue { max, actual } => {
+                map.serialize_entry("max", &max)?;
+                map.serialize_entry("actual", &actual)?;
+            }
+            Self::MinIntegerValue { min, actual } => {
+                map.serialize_entry("min", &min)?;
+                map.serialize_entry("actual", &actual)?;
+            }
+            Self::InvalidFormat(format) => {
+                map.serialize_entry("format", format)?;
+            }
+            Self::ExclusiveField(a, b) | Self::EitherRequired(a, b) => {
+                map.serialize_entry("fields", &[a, b])?;
+            }
+            Self::AtLeastOneOf4(a, b, c, d) => {
+                map.serialize_entry("fields", &[a, b, c, d])?;
+            }
+            Self::UnsupportedBlock(index, block, surface) => {
+                map.serialize_entry("index", &index)?;
+                map.serialize_entry("block", block)?;
+                map.serialize_entry("surface", &surface.to_string())?;
+            }
+            Self::UnsupportedElement(index, block, element, surface) => {
+                map.serialize_entry("index", &index)?;
+                map.serialize_entry("block", block)?;
+                map.serialize_entry("element", element)?;
+                map.serialize_entry("surface", &surface.to_string())?;
+            }
+            Self::ResponseUrlOnlyField(field) => {
+                map.serialize_entry("field", field)?;
+            }
+            _ => {}
+        }
+
+        map.end()
+    }
 }
 
 /// Validation error from single field or across fields.
@@ -84,11 +241,9 @@ pub enum ValidationErrorKind {
 #[derive(Debug, Clone, PartialEq, Error)]
 pub enum ValidationError {
     /// Errors that involve multiple fields.
-    #[error("AcrossFieldsError {0:?}")]
     AcrossFields(Vec<ValidationErrorKind>),
 
     /// Errors that pertain to a specific field.
-    #[error("SingleField {{ field: {field:}, errors: {errors:?} }}")]
     SingleField {
         field: Cow<'static, str>,
         errors: Vec<ValidationErrorKind>,
@@ -136,10 +291,41 @@ impl ValidationError {
     }
 }
 
+/// Displayed as the field and its errors, e.g. `text: required`, or `(across fields): ...` for
+/// errors involving multiple fields.
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = self.field().unwrap_or("(across fields)");
+        write!(f, "{field}: ")?;
+
+        for (i, error) in self.errors().iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialized as `{"field": "text", "errors": [...]}`, where `field` is `null` for errors
+/// involving multiple fields.
+impl Serialize for ValidationError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("field", &self.field())?;
+        map.serialize_entry("errors", self.errors())?;
+        map.end()
+    }
+}
+
 /// Validation errors objects that every builder object can return as Result::Err
 /// when validation fails.
-#[derive(Debug, Clone, PartialEq, Error)]
-#[error("Validation Error {{ object: {object:}, errors: {errors:?} }}")]
+///
+/// The `Display` output is a single line meant for logs, e.g. `Header: text: required`. Use
+/// [`report`](Self::report) to show the errors to people, or serialize them to return them from
+/// an API.
+#[derive(Debug, Clone, PartialEq, Error, Serialize)]
 pub struct ValidationErrors {
     /// Name of the source object of the error.
     pub object: Cow<'static, str>,
@@ -157,6 +343,573 @@ impl ValidationErrors {
     pub fn errors(&self) -> &[ValidationError] {
         &self.errors
     }
+
+    /// Returns a human-readable report of the errors, one error per line.
+    ///
+    /// ```
+    /// use slack_messaging::blocks::Header;
+    /// use slack_messaging::plain_text;
+    /// # use std::error::Error;
+    ///
+    /// # fn try_main() -> Result<(), Box<dyn Error>> {
+    /// let err = Header::builder()
+    ///     .text(plain_text!("{}", "a".repeat(151))?)
+    ///     .block_id("a".repeat(256))
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.report(),
+    ///     "Header has 2 validation errors:\n\
+    ///      \x20 - text: max text length is `150` characters but got `151`\n\
+    ///      \x20 - block_id: max text length is `255` characters but got `256`"
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     try_main().unwrap()
+    /// # }
+    /// ```
+    pub fn report(&self) -> String {
+        let lines = self.errors.iter().flat_map(|error| {
+            let field = error.field().unwrap_or("(across fields)");
+            error
+                .errors()
+                .iter()
+                .map(move |kind| format!("{field}: {kind}"))
+        });
+        report(Some(&self.object), lines)
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.object)?;
+
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+// Lists the lines under a header with the number of errors, e.g. `Header has 2 validation errors:`.
+fn report(subject: Option<&str>, lines: impl Iterator<Item = String>) -> String {
+    let lines: Vec<String> = lines.collect();
+    let count = match lines.len() {
+        1 => "1 validation error".to_string(),
+        n => format!("{n} validation errors"),
+    };
+
+    let mut report = match subject {
+        Some(subject) => format!("{subject} has {count}:"),
+        None => format!("{count}:"),
+    };
+    for line in lines {
+        report.push_str("\n  - ");
+        report.push_str(&line);
+    }
+    report
+}
+
+/// Validation errors of a field, or of an object for errors involving multiple fields, found
+/// by [`Validate::validate`](crate::Validate::validate).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NestedValidationError {
+    /// Path to the field from the validated object, e.g. `blocks[12].elements[3].confirm.title`.
+    /// For errors involving multiple fields, it is the path to the object, which is empty for
+    /// the validated object itself.
+    pub path: String,
+    /// Name of the object the errors come from.
+    pub object: Cow<'static, str>,
+    /// Validation errors at the path.
+    pub errors: Vec<ValidationErrorKind>,
+}
+
+impl NestedValidationError {
+    /// Returns the path to the field or the object.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the name of the object the errors come from.
+    pub fn object(&self) -> &str {
+        &self.object
+    }
+
+    /// Returns the validation errors at the path.
+    pub fn errors(&self) -> &[ValidationErrorKind] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for NestedValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "(root)"
+        } else {
+            &self.path
+        };
+        write!(f, "{path} ({}): ", self.object)?;
+
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// All validation errors of an object and the objects nested in it, returned by
+/// [`Validate::validate`](crate::Validate::validate). It displays one error per line.
+///
+/// Use [`report`](Self::report) to show the errors to people, or serialize them to return them
+/// from an API.
+#[derive(Debug, Clone, PartialEq, Error, Serialize)]
+pub struct NestedValidationErrors {
+    /// Validation errors in the order of the fields.
+    pub errors: Vec<NestedValidationError>,
+}
+
+impl NestedValidationErrors {
+    /// Returns all validation errors.
+    pub fn errors(&self) -> &[NestedValidationError] {
+        &self.errors
+    }
+
+    /// Returns a human-readable report of the errors, one error per line with the path to the
+    /// field, e.g.
+    ///
+    /// ```text
+    /// 2 validation errors:
+    ///   - blocks[0].text (Header): required
+    ///   - blocks[1].accessory.confirm.title (ConfirmationDialog): max text length is `100` characters but got `101`
+    /// ```
+    pub fn report(&self) -> String {
+        let lines = self.errors.iter().flat_map(|error| {
+            let path = if error.path.is_empty() {
+                "(root)"
+            } else {
+                &error.path
+            };
+            error
+                .errors
+                .iter()
+                .map(move |kind| format!("{path} ({}): {kind}", error.object))
+        });
+        report(None, lines)
+    }
+}
+
+impl fmt::Display for NestedValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Errors returned when extracting a value from the state of a view.
+/// See [`ViewState::get`](crate::interactions::ViewState::get).
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum StateError {
+    /// No value exists for the pair of block_id and action_id.
+    #[error("no value found for block_id `{block_id}` and action_id `{action_id}`")]
+    NotFound {
+        /// block_id of the lookup.
+        block_id: String,
+        /// action_id of the lookup.
+        action_id: String,
+    },
+
+    /// The value exists but comes from another type of element.
+    #[error(
+        "value for block_id `{block_id}` and action_id `{action_id}` is `{actual}`, not `{expected}`"
+    )]
+    TypeMismatch {
+        /// block_id of the lookup.
+        block_id: String,
+        /// action_id of the lookup.
+        action_id: String,
+        /// Element type the caller expected.
+        expected: &'static str,
+        /// Element type of the value actually stored.
+        actual: String,
+    },
+}
+
+/// Errors returned when verifying a request from Slack.
+/// See [`Verifier::verify`](crate::verify::Verifier::verify).
+#[cfg(feature = "verify")]
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum VerificationError {
+    /// `X-Slack-Request-Timestamp` is not a unix timestamp.
+    #[error("invalid request timestamp `{0}`")]
+    InvalidTimestamp(String),
+
+    /// The request timestamp is outside of the replay window.
+    #[error("request timestamp `{timestamp}` is more than {tolerance} seconds away from `{now}`")]
+    Expired {
+        /// Timestamp of the request.
+        timestamp: i64,
+        /// Current unix timestamp used for the check.
+        now: i64,
+        /// Accepted difference in seconds.
+        tolerance: u64,
+    },
+
+    /// `X-Slack-Signature` is not in the format `v0=<hex digest>`.
+    #[error("invalid signature format `{0}`")]
+    InvalidSignatureFormat(String),
+
+    /// The signature does not match the request body.
+    #[error("signature mismatch")]
+    SignatureMismatch,
+}
+
+/// Rejections of the axum extractors for verified requests from Slack.
+/// See [`axum`](crate::axum) module.
+#[cfg(feature = "axum")]
+#[derive(Debug, Error)]
+pub enum ExtractError {
+    /// A header required to verify the request is missing or not valid UTF-8.
+    #[error("missing header `{0}`")]
+    MissingHeader(&'static str),
+
+    /// The request failed the signature verification.
+    #[error(transparent)]
+    Verification(#[from] VerificationError),
+
+    /// The request body could not be read.
+    #[error(transparent)]
+    Body(#[from] ::axum::extract::rejection::BytesRejection),
+
+    /// The request body is not a valid payload.
+    #[error("invalid payload: {0}")]
+    InvalidPayload(String),
+}
+
+/// Errors returned when enqueuing a message to a [`MessageQueue`](crate::queue::MessageQueue).
+#[cfg(feature = "queue")]
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum QueueError {
+    /// The queue of the channel is full and the overflow policy rejects new messages.
+    #[error("the queue of channel `{0}` is full")]
+    Full(String),
+}
+
+/// Errors returned when responding through a `response_url`.
+/// See [`ResponseUrl`](crate::response_url::ResponseUrl).
+#[derive(Debug, Error)]
+pub enum ResponseUrlError {
+    /// More than 30 minutes have passed since the `response_url` was issued.
+    #[error("response_url issued at `{issued_at}` expired at `{now}`")]
+    Expired {
+        /// Unix timestamp the `response_url` was issued at.
+        issued_at: i64,
+        /// Unix timestamp used for the check.
+        now: i64,
+    },
+
+    /// The `response_url` has already been used 5 times.
+    #[error("response_url has already been used {0} times")]
+    UsesExhausted(u8),
+
+    /// Slack rejected the response.
+    #[cfg(feature = "response-url")]
+    #[error("response rejected `{status}`: {error}")]
+    Rejected {
+        /// HTTP status code of the response.
+        status: u16,
+        /// Error code or body of the response.
+        error: String,
+    },
+
+    /// The request could not be sent or the response could not be read.
+    #[cfg(feature = "response-url")]
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+}
+
+/// Errors returned by the [`SocketModeClient`](crate::socket_mode::SocketModeClient).
+#[cfg(feature = "socket-mode")]
+#[derive(Debug, Error)]
+pub enum SocketModeError {
+    /// `apps.connections.open` failed.
+    #[error("failed to open connection: {0}")]
+    Api(#[from] WebApiError),
+
+    /// The transport failed to connect, send or receive.
+    #[error("transport error: {0}")]
+    Transport(#[source] crate::socket_mode::TransportError),
+
+    /// Slack sent a frame that is not in the format of Socket Mode.
+    #[error("invalid frame: {0}")]
+    InvalidFrame(#[from] serde_json::Error),
+
+    /// The response payload of the acknowledgement fails to serialize.
+    #[error("invalid acknowledgement: {0}")]
+    InvalidAck(#[source] serde_json::Error),
+
+    /// The acknowledgement is sent while no connection is open.
+    #[error("not connected")]
+    NotConnected,
+}
+
+/// Errors returned when posting a message to an incoming webhook.
+/// See [`WebhookClient::send`](crate::webhook::WebhookClient::send).
+///
+/// Slack responds to invalid webhook requests with a plain-text error code in the body. Known
+/// codes are mapped to dedicated variants.
+#[cfg(feature = "webhook")]
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    /// `invalid_payload`: the request body is not valid JSON or is missing required fields.
+    #[error("invalid_payload")]
+    InvalidPayload,
+
+    /// `invalid_blocks`: the blocks in the message are invalid.
+    #[error("invalid_blocks")]
+    InvalidBlocks,
+
+    /// `invalid_blocks_format`: the blocks field is not a valid array of blocks.
+    #[error("invalid_blocks_format")]
+    InvalidBlocksFormat,
+
+    /// `no_text`: the message has neither text nor blocks.
+    #[error("no_text")]
+    NoText,
+
+    /// `too_many_attachments`: the message includes more than 100 attachments.
+    #[error("too_many_attachments")]
+    TooManyAttachments,
+
+    /// `user_not_found`: the user of the webhook no longer exists.
+    #[error("user_not_found")]
+    UserNotFound,
+
+    /// `channel_not_found`: the channel of the webhook no longer exists.
+    #[error("channel_not_found")]
+    ChannelNotFound,
+
+    /// `channel_is_archived`: the channel of the webhook has been archived.
+    #[error("channel_is_archived")]
+    ChannelIsArchived,
+
+    /// `action_prohibited`: an admin has restricted posting to the channel.
+    #[error("action_prohibited")]
+    ActionProhibited,
+
+    /// `posting_to_general_channel_denied`: only admins can post to the general channel.
+    #[error("posting_to_general_channel_denied")]
+    PostingToGeneralChannelDenied,
+
+    /// `no_service`: the webhook is disabled, removed or invalid.
+    #[error("no_service")]
+    NoService,
+
+    /// `no_service_id`: the service id of the webhook URL is invalid.
+    #[error("no_service_id")]
+    NoServiceId,
+
+    /// `no_team`: the workspace id of the webhook URL is invalid.
+    #[error("no_team")]
+    NoTeam,
+
+    /// `team_disabled`: the workspace of the webhook has been disabled.
+    #[error("team_disabled")]
+    TeamDisabled,
+
+    /// `invalid_token`: the token of the webhook URL is invalid.
+    #[error("invalid_token")]
+    InvalidToken,
+
+    /// Slack rate limited the webhook and the retries are exhausted.
+    #[error("rate limited (retry after {retry_after:?})")]
+    RateLimited {
+        /// Value of the `Retry-After` header of the last response.
+        retry_after: Option<std::time::Duration>,
+    },
+
+    /// Slack responded with a server error and the retries are exhausted.
+    #[error("server error `{status}`: {body}")]
+    Server {
+        /// HTTP status code of the last response.
+        status: u16,
+        /// Body of the last response.
+        body: String,
+    },
+
+    /// Slack responded with an error this crate does not know.
+    #[error("unexpected response `{status}`: {body}")]
+    Unexpected {
+        /// HTTP status code of the response.
+        status: u16,
+        /// Body of the response.
+        body: String,
+    },
+
+    /// The request could not be sent or the response could not be read.
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+}
+
+/// Errors returned when calling a Slack Web API method.
+/// See [`WebApiClient`](crate::web_api::WebApiClient).
+#[cfg(feature = "web-api")]
+#[derive(Debug, Error)]
+pub enum WebApiError {
+    /// Slack responded with `"ok": false`.
+    #[error("{code}{}", detail(.messages))]
+    Api {
+        /// Value of the `error` field.
+        code: ApiErrorCode,
+        /// Value of the `response_metadata.messages` field, explaining the error in detail.
+        messages: Vec<String>,
+    },
+
+    /// Slack rate limited the method and the retries are exhausted.
+    #[error("rate limited (retry after {retry_after:?})")]
+    RateLimited {
+        /// Value of the `Retry-After` header of the last response.
+        retry_after: Option<std::time::Duration>,
+    },
+
+    /// Slack responded with a server error and the retries are exhausted.
+    #[error("server error `{status}`: {body}")]
+    Server {
+        /// HTTP status code of the last response.
+        status: u16,
+        /// Body of the last response.
+        body: String,
+    },
+
+    /// The response body is not in the format of the method.
+    #[error("invalid response: {0}")]
+    InvalidResponse(#[from] serde_json::Error),
+
+    /// The request could not be sent or the response could not be read.
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+}
+
+#[cfg(feature = "web-api")]
+fn detail(messages: &[String]) -> String {
+    if messages.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", messages.join(", "))
+    }
+}
+
+#[cfg(feature = "web-api")]
+macro_rules! api_error_codes {
+    ($($(#[$meta:meta])* $variant:ident => $code:literal,)*) => {
+        /// Value of the `error` field of the Web API responses.
+        ///
+        /// Errors commonly returned by the messaging and views methods are mapped to dedicated
+        /// variants and the others are kept as [`ApiErrorCode::Other`].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ApiErrorCode {
+            $(
+                $(#[$meta])*
+                $variant,
+            )*
+            /// Any other error code.
+            Other(String),
+        }
+
+        impl ApiErrorCode {
+            /// Returns the error code as Slack sends it.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Other(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for ApiErrorCode {
+            fn from(code: &str) -> Self {
+                match code {
+                    $($code => Self::$variant,)*
+                    _ => Self::Other(code.into()),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "web-api")]
+api_error_codes! {
+    /// `not_authed`: no token is provided.
+    NotAuthed => "not_authed",
+    /// `invalid_auth`: the token is invalid.
+    InvalidAuth => "invalid_auth",
+    /// `account_inactive`: the token is for a deleted user or workspace.
+    AccountInactive => "account_inactive",
+    /// `token_revoked`: the token has been revoked.
+    TokenRevoked => "token_revoked",
+    /// `missing_scope`: the token does not have the scope the method requires.
+    MissingScope => "missing_scope",
+    /// `invalid_arguments`: the method was called with invalid arguments.
+    InvalidArguments => "invalid_arguments",
+    /// `ratelimited`: the method was called too many times.
+    Ratelimited => "ratelimited",
+    /// `channel_not_found`: the channel does not exist or is not visible to the token.
+    ChannelNotFound => "channel_not_found",
+    /// `not_in_channel`: the bot is not a member of the channel.
+    NotInChannel => "not_in_channel",
+    /// `is_archived`: the channel has been archived.
+    IsArchived => "is_archived",
+    /// `user_not_in_channel`: the ephemeral message recipient is not a member of the channel.
+    UserNotInChannel => "user_not_in_channel",
+    /// `msg_too_long`: the message text is too long.
+    MsgTooLong => "msg_too_long",
+    /// `no_text`: the message has neither text nor blocks.
+    NoText => "no_text",
+    /// `invalid_blocks`: the blocks in the message are invalid.
+    InvalidBlocks => "invalid_blocks",
+    /// `invalid_blocks_format`: the blocks field is not a valid array of blocks.
+    InvalidBlocksFormat => "invalid_blocks_format",
+    /// `too_many_attachments`: the message includes more than 100 attachments.
+    TooManyAttachments => "too_many_attachments",
+    /// `message_not_found`: the message to update does not exist.
+    MessageNotFound => "message_not_found",
+    /// `cant_update_message`: the message cannot be updated by the token.
+    CantUpdateMessage => "cant_update_message",
+    /// `edit_window_closed`: the message can no longer be edited.
+    EditWindowClosed => "edit_window_closed",
+    /// `expired_trigger_id`: the trigger_id is older than 3 seconds.
+    ExpiredTriggerId => "expired_trigger_id",
+    /// `invalid_trigger_id`: the trigger_id is invalid or already used.
+    InvalidTriggerId => "invalid_trigger_id",
+    /// `not_found`: the view to update does not exist.
+    NotFound => "not_found",
+    /// `hash_conflict`: the view has been updated since the hash was issued.
+    HashConflict => "hash_conflict",
+    /// `duplicate_external_id`: another view already uses the external_id.
+    DuplicateExternalId => "duplicate_external_id",
+    /// `push_limit_reached`: three views are already on the stack.
+    PushLimitReached => "push_limit_reached",
+    /// `view_too_large`: the view exceeds 250kB.
+    ViewTooLarge => "view_too_large",
+}
+
+#[cfg(feature = "web-api")]
+impl std::fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[cfg(test)]
@@ -195,3 +948,203 @@ mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::Header;
+    use crate::composition_objects::test_helpers::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_error_kind_with_code_and_parameters() {
+        let kind = ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3012,
+        };
+        assert_eq!(kind.code(), "max_text_length");
+        assert_eq!(
+            serde_json::to_value(kind).unwrap(),
+            json!({
+                "code": "max_text_length",
+                "message": "max text length is `3000` characters but got `3012`",
+                "max": 3000,
+                "actual": 3012
+            })
+        );
+
+        let kind = ValidationErrorKind::UnsupportedBlock(1, "markdown", Surface::Modal);
+        assert_eq!(
+            serde_json::to_value(kind).unwrap(),
+            json!({
+                "code": "unsupported_block",
+                "message": "block `markdown` at index 1 is not available in modal",
+                "index": 1,
+                "block": "markdown",
+                "surface": "modal"
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(ValidationErrorKind::Required).unwrap(),
+            json!({ "code": "required", "message": "required" })
+        );
+    }
+
+    #[test]
+    fn it_serializes_validation_errors() {
+        let err = ValidationErrors {
+            object: "Section".into(),
+            errors: vec![
+                ValidationError::SingleField {
+                    field: "text".into(),
+                    errors: vec![ValidationErrorKind::MaxTextLength {
+                        max: 3000,
+                        actual: 3001,
+                    }],
+                },
+                ValidationError::AcrossFields(vec![ValidationErrorKind::EitherRequired(
+                    "text", "fields",
+                )]),
+            ],
+        };
+
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            json!({
+                "object": "Section",
+                "errors": [
+                    {
+                        "field": "text",
+                        "errors": [{
+                            "code": "max_text_length",
+                            "message": "max text length is `3000` characters but got `3001`",
+                            "max": 3000,
+                            "actual": 3001
+                        }]
+                    },
+                    {
+                        "field": null,
+                        "errors": [{
+                            "code": "either_required",
+                            "message": "required either text or fields",
+                            "fields": ["text", "fields"]
+                        }]
+                    }
+                ]
+            })
+        );
+        assert_eq!(
+            err.report(),
+            "Section has 2 validation errors:\n  \
+             - text: max text length is `3000` characters but got `3001`\n  \
+             - (across fields): required either text or fields"
+        );
+    }
+
+    #[test]
+    fn it_displays_validation_errors_in_one_line() {
+        let err = ValidationErrors {
+            object: "Section".into(),
+            errors: vec![
+                ValidationError::SingleField {
+                    field: "text".into(),
+                    errors: vec![
+                        ValidationErrorKind::Required,
+                        ValidationErrorKind::MaxTextLength {
+                            max: 3000,
+                            actual: 3001,
+                        },
+                    ],
+                },
+                ValidationError::AcrossFields(vec![ValidationErrorKind::EitherRequired(
+                    "text", "fields",
+                )]),
+            ],
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Section: text: required, max text length is `3000` characters but got `3001`; \
+             (across fields): required either text or fields"
+        );
+        assert_eq!(
+            err.errors()[1].to_string(),
+            "(across fields): required either text or fields"
+        );
+    }
+
+    #[test]
+    fn it_reports_single_error() {
+        let err = Header::builder().build().unwrap_err();
+        assert_eq!(
+            err.report(),
+            "Header has 1 validation error:\n  - text: required"
+        );
+    }
+
+    #[test]
+    fn it_reports_and_serializes_nested_errors() {
+        let err = NestedValidationErrors {
+            errors: vec![
+                NestedValidationError {
+                    path: "".into(),
+                    object: "Message".into(),
+                    errors: vec![ValidationErrorKind::MaxTotalBlocks {
+                        max: 50,
+                        actual: 52,
+                    }],
+                },
+                NestedValidationError {
+                    path: "blocks[0].text".into(),
+                    object: "Header".into(),
+                    errors: vec![
+                        ValidationErrorKind::Required,
+                        ValidationErrorKind::MinTextLength { min: 1, actual: 0 },
+                    ],
+                },
+            ],
+        };
+
+        assert_eq!(
+            err.report(),
+            "3 validation errors:\n  \
+             - (root) (Message): max total blocks is `50` across the message and its attachments but got `52`\n  \
+             - blocks[0].text (Header): required\n  \
+             - blocks[0].text (Header): min text length is `1` characters but got `0`"
+        );
+        assert_eq!(
+            serde_json::to_value(&err).unwrap()["errors"][1],
+            json!({
+                "path": "blocks[0].text",
+                "object": "Header",
+                "errors": [
+                    { "code": "required", "message": "required" },
+                    {
+                        "code": "min_text_length",
+                        "message": "min text length is `1` characters but got `0`",
+                        "min": 1,
+                        "actual": 0
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn it_reports_observed_values_of_built_objects() {
+        let err = Header::builder()
+            .text(plain_text("a".repeat(152)))
+            .build()
+            .unwrap_err();
+
+        assert!(
+            err.field("text")
+                .includes(ValidationErrorKind::MaxTextLength {
+                    max: 150,
+                    actual: 152
+                })
+        );
+    }
+}
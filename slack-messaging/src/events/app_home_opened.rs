@@ -0,0 +1,82 @@
+use crate::interactions::types::ViewPayload;
+
+use serde::Deserialize;
+
+/// [`app_home_opened` event](https://docs.slack.dev/reference/events/app_home_opened)
+/// representation.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::events::AppHomeOpened;
+///
+/// let json = serde_json::json!({
+///     "type": "app_home_opened",
+///     "user": "U123ABC456",
+///     "channel": "D123ABC456",
+///     "event_ts": "1515449522000016",
+///     "tab": "home"
+/// });
+///
+/// let event: AppHomeOpened = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(event.user, "U123ABC456");
+/// assert_eq!(event.tab, "home");
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AppHomeOpened {
+    /// ID of the user who opened the App Home.
+    pub user: String,
+
+    /// ID of the direct message channel with your app.
+    pub channel: String,
+
+    /// The tab the user opened, `home` or `messages`.
+    pub tab: String,
+
+    /// Timestamp of the event.
+    pub event_ts: String,
+
+    /// The Home tab view currently published to the user.
+    pub view: Option<ViewPayload>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "app_home_opened",
+            "user": "U123ABC456",
+            "channel": "D123ABC456",
+            "event_ts": "1515449522000016",
+            "tab": "home",
+            "view": {
+                "id": "V123ABC456",
+                "team_id": "T123ABC456",
+                "type": "home",
+                "blocks": [
+                    {
+                        "type": "section",
+                        "text": { "type": "mrkdwn", "text": "*Welcome home!*" }
+                    }
+                ],
+                "private_metadata": "",
+                "callback_id": "",
+                "state": { "values": {} },
+                "hash": "1231232323.12321312",
+                "app_id": "A123ABC456",
+                "bot_id": "B123ABC456"
+            }
+        });
+
+        let event: AppHomeOpened = serde_json::from_value(json).unwrap();
+
+        assert_eq!(event.channel, "D123ABC456");
+        let view = event.view.unwrap();
+        assert_eq!(view.r#type, "home");
+        assert_eq!(view.blocks.len(), 1);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::blocks::Block;
+
+use serde::Deserialize;
+
+/// [`app_mention` event](https://docs.slack.dev/reference/events/app_mention) representation.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::events::AppMention;
+///
+/// let json = serde_json::json!({
+///     "type": "app_mention",
+///     "user": "U061F7AUR",
+///     "text": "<@U0LAN0Z89> is it everything a river should be?",
+///     "ts": "1515449522.000016",
+///     "channel": "C123ABC456",
+///     "event_ts": "1515449522000016"
+/// });
+///
+/// let event: AppMention = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(event.user, "U061F7AUR");
+/// assert_eq!(event.channel, "C123ABC456");
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AppMention {
+    /// ID of the user who mentioned your app.
+    pub user: String,
+
+    /// Text of the message.
+    #[serde(default)]
+    pub text: String,
+
+    /// Blocks of the message. Blocks are not validated, and the ones this crate does not
+    /// support are kept as [`Block::Unknown`].
+    #[serde(default, deserialize_with = "crate::de::lenient")]
+    pub blocks: Vec<Block>,
+
+    /// Timestamp of the message.
+    pub ts: String,
+
+    /// Timestamp of the parent message if the message is a thread reply.
+    pub thread_ts: Option<String>,
+
+    /// ID of the channel the message was posted in.
+    pub channel: String,
+
+    /// ID of the workspace of the user.
+    pub team: Option<String>,
+
+    /// Timestamp of the event.
+    pub event_ts: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "app_mention",
+            "user": "U061F7AUR",
+            "text": "<@U0LAN0Z89> hi",
+            "ts": "1515449522.000016",
+            "team": "T123ABC456",
+            "channel": "C123ABC456",
+            "event_ts": "1515449522000016",
+            "blocks": [
+                {
+                    "type": "rich_text",
+                    "block_id": "Bh1xE",
+                    "elements": [
+                        {
+                            "type": "rich_text_section",
+                            "elements": [
+                                { "type": "user", "user_id": "U0LAN0Z89" },
+                                { "type": "text", "text": " hi" }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let event: AppMention = serde_json::from_value(json).unwrap();
+
+        assert_eq!(event.user, "U061F7AUR");
+        assert_eq!(event.text, "<@U0LAN0Z89> hi");
+        assert_eq!(event.team.as_deref(), Some("T123ABC456"));
+        assert!(matches!(event.blocks[0], Block::RichText(_)));
+    }
+}
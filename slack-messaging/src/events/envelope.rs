@@ -0,0 +1,314 @@
+use super::Event;
+
+use serde::Deserialize;
+
+/// Enum representation of [the envelopes](https://docs.slack.dev/apis/events-api#callback-field)
+/// the Events API posts to your app's request URL. Each variant is distinguished by the `type`
+/// field of the payload.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::events::{Event, EventEnvelope};
+///
+/// let json = serde_json::json!({
+///     "type": "event_callback",
+///     "token": "XXYYZZ",
+///     "team_id": "T123ABC456",
+///     "api_app_id": "A123ABC456",
+///     "event": {
+///         "type": "app_mention",
+///         "user": "U061F7AUR",
+///         "text": "<@U0LAN0Z89> hello",
+///         "ts": "1515449522.000016",
+///         "channel": "C123ABC456",
+///         "event_ts": "1515449522000016"
+///     },
+///     "event_id": "Ev123ABC456",
+///     "event_time": 1515449522
+/// });
+///
+/// let envelope: EventEnvelope = serde_json::from_value(json).unwrap();
+///
+/// let EventEnvelope::EventCallback(callback) = envelope else {
+///     panic!("expected event_callback");
+/// };
+/// let Event::AppMention(mention) = callback.event else {
+///     panic!("expected app_mention");
+/// };
+/// assert_eq!(mention.text, "<@U0LAN0Z89> hello");
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventEnvelope {
+    /// Envelope wrapping an event.
+    EventCallback(Box<EventCallback>),
+
+    /// Challenge to verify your request URL. Respond with the `challenge` value.
+    UrlVerification(UrlVerification),
+
+    /// Notification that your app's event deliveries are being rate limited.
+    AppRateLimited(AppRateLimited),
+}
+
+/// [`event_callback` envelope](https://docs.slack.dev/apis/events-api#callback-field)
+/// representation.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct EventCallback {
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// ID of the workspace the event occurred in.
+    pub team_id: String,
+
+    /// ID of your app.
+    pub api_app_id: String,
+
+    /// The event itself.
+    pub event: Event,
+
+    /// Unique identifier of the event.
+    pub event_id: String,
+
+    /// Unix timestamp when the event was dispatched.
+    pub event_time: i64,
+
+    /// Identifier of the event shared across installations.
+    pub event_context: Option<String>,
+
+    /// Installations the event is visible to.
+    #[serde(default)]
+    pub authorizations: Vec<Authorization>,
+
+    /// Whether the event occurred in an externally shared channel or not.
+    #[serde(default)]
+    pub is_ext_shared_channel: bool,
+
+    /// ID of the workspace the event is attributed to.
+    pub context_team_id: Option<String>,
+
+    /// ID of the enterprise organization the event is attributed to.
+    pub context_enterprise_id: Option<String>,
+}
+
+/// An installation of your app the event is visible to.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Authorization {
+    /// ID of the enterprise organization of the installation.
+    pub enterprise_id: Option<String>,
+
+    /// ID of the workspace of the installation.
+    pub team_id: Option<String>,
+
+    /// ID of the user who installed your app.
+    pub user_id: String,
+
+    /// Whether the installation is a bot or not.
+    #[serde(default)]
+    pub is_bot: bool,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+/// [`url_verification` envelope](https://docs.slack.dev/reference/events/url_verification)
+/// representation.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct UrlVerification {
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// Value your app should respond with.
+    pub challenge: String,
+}
+
+/// [`app_rate_limited` envelope](https://docs.slack.dev/reference/events/app_rate_limited)
+/// representation.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AppRateLimited {
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// ID of the workspace the events are rate limited in.
+    pub team_id: String,
+
+    /// Unix timestamp of the minute the rate limiting started.
+    pub minute_rate_limited: i64,
+
+    /// ID of your app.
+    pub api_app_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::Block;
+
+    #[test]
+    fn it_deserializes_event_callback() {
+        let json = serde_json::json!({
+            "token": "XXYYZZ",
+            "team_id": "T123ABC456",
+            "api_app_id": "A123ABC456",
+            "event": {
+                "type": "app_home_opened",
+                "user": "U123ABC456",
+                "channel": "D123ABC456",
+                "tab": "home",
+                "event_ts": "1515449522000016"
+            },
+            "type": "event_callback",
+            "authorizations": [
+                {
+                    "enterprise_id": null,
+                    "team_id": "T123ABC456",
+                    "user_id": "U123ABC456",
+                    "is_bot": true,
+                    "is_enterprise_install": false
+                }
+            ],
+            "event_id": "Ev123ABC456",
+            "event_context": "EC123ABC456",
+            "event_time": 1234567890,
+            "is_ext_shared_channel": false,
+            "context_team_id": "T123ABC456",
+            "context_enterprise_id": null
+        });
+
+        let envelope: EventEnvelope = serde_json::from_value(json).unwrap();
+        let EventEnvelope::EventCallback(callback) = envelope else {
+            panic!("expected event_callback");
+        };
+
+        assert_eq!(callback.team_id, "T123ABC456");
+        assert_eq!(callback.event_id, "Ev123ABC456");
+        assert_eq!(callback.event_time, 1234567890);
+        assert_eq!(callback.authorizations.len(), 1);
+        assert!(callback.authorizations[0].is_bot);
+        assert!(matches!(callback.event, Event::AppHomeOpened(_)));
+    }
+
+    #[test]
+    fn it_keeps_blocks_of_unknown_type_as_raw_json() {
+        let call = serde_json::json!({
+            "type": "call",
+            "block_id": "call0",
+            "call_id": "R123ABC456"
+        });
+        let json = event_callback(serde_json::json!({
+            "type": "message",
+            "channel": "C123ABC456",
+            "user": "U123ABC456",
+            "text": "A call was started",
+            "ts": "1355517523.000005",
+            "blocks": [call.clone()]
+        }));
+
+        let envelope: EventEnvelope = serde_json::from_value(json).unwrap();
+        let EventEnvelope::EventCallback(callback) = envelope else {
+            panic!("expected event_callback");
+        };
+        let Event::Message(message) = callback.event else {
+            panic!("expected message event");
+        };
+
+        assert_eq!(message.blocks, vec![Block::Unknown(call)]);
+    }
+
+    #[test]
+    fn it_deserializes_blocks_exceeding_limits_without_validations() {
+        let json = event_callback(serde_json::json!({
+            "type": "app_mention",
+            "user": "U123ABC456",
+            "text": "<@U0LAN0Z89> hello",
+            "ts": "1515449522.000016",
+            "channel": "C123ABC456",
+            "event_ts": "1515449522000016",
+            "blocks": [
+                {
+                    "type": "header",
+                    "text": { "type": "plain_text", "text": "a".repeat(151) }
+                }
+            ]
+        }));
+
+        let envelope: EventEnvelope = serde_json::from_value(json).unwrap();
+        let EventEnvelope::EventCallback(callback) = envelope else {
+            panic!("expected event_callback");
+        };
+        let Event::AppMention(mention) = callback.event else {
+            panic!("expected app_mention event");
+        };
+        let Block::Header(header) = &mention.blocks[0] else {
+            panic!("expected header block");
+        };
+
+        let text = header.text.as_ref().and_then(|t| t.text.clone());
+        assert_eq!(text, Some("a".repeat(151)));
+    }
+
+    #[test]
+    fn it_still_validates_blocks_deserialized_directly() {
+        let json = serde_json::json!({
+            "type": "header",
+            "text": { "type": "plain_text", "text": "a".repeat(151) }
+        });
+        assert!(serde_json::from_value::<Block>(json).is_err());
+
+        let json = serde_json::json!({ "type": "call" });
+        assert!(serde_json::from_value::<Block>(json).is_err());
+    }
+
+    fn event_callback(event: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "token": "XXYYZZ",
+            "team_id": "T123ABC456",
+            "api_app_id": "A123ABC456",
+            "event": event,
+            "type": "event_callback",
+            "event_id": "Ev123ABC456",
+            "event_time": 1234567890
+        })
+    }
+
+    #[test]
+    fn it_deserializes_url_verification() {
+        let json = serde_json::json!({
+            "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+            "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+            "type": "url_verification"
+        });
+
+        let envelope: EventEnvelope = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            envelope,
+            EventEnvelope::UrlVerification(UrlVerification {
+                token: Some("Jhj5dZrVaK7ZwHHjRyZWjbDl".into()),
+                challenge: "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_deserializes_app_rate_limited() {
+        let json = serde_json::json!({
+            "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+            "type": "app_rate_limited",
+            "team_id": "T123456",
+            "minute_rate_limited": 1518467820,
+            "api_app_id": "A123456"
+        });
+
+        let envelope: EventEnvelope = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            envelope,
+            EventEnvelope::AppRateLimited(AppRateLimited {
+                token: Some("Jhj5dZrVaK7ZwHHjRyZWjbDl".into()),
+                team_id: "T123456".into(),
+                minute_rate_limited: 1518467820,
+                api_app_id: "A123456".into(),
+            })
+        );
+    }
+}
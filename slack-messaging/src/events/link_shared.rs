@@ -0,0 +1,124 @@
+use serde::Deserialize;
+
+/// [`link_shared` event](https://docs.slack.dev/reference/events/link_shared) representation.
+///
+/// Respond to this event with [`ChatUnfurl`](crate::requests::ChatUnfurl) whose
+/// [`Unfurls`](crate::requests::Unfurls) are keyed by the URLs in `links`.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::events::LinkShared;
+///
+/// let json = serde_json::json!({
+///     "type": "link_shared",
+///     "channel": "C123ABC456",
+///     "is_bot_user_member": true,
+///     "user": "U123ABC456",
+///     "message_ts": "123456789.9875",
+///     "unfurl_id": "C123456.123456789.987501.1b90fa1278528ce6e2f6c5c2bfa1abc9a41d57d02b29d173f40399c9ffdecf4b",
+///     "thread_ts": "123456621.1855",
+///     "source": "conversations_history",
+///     "links": [
+///         {
+///             "domain": "example.com",
+///             "url": "https://example.com/12345"
+///         }
+///     ],
+///     "event_ts": "123456789.9875"
+/// });
+///
+/// let event: LinkShared = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(event.channel, "C123ABC456");
+/// assert_eq!(event.links[0].url, "https://example.com/12345");
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct LinkShared {
+    /// ID of the channel the links were shared in. It is `COMPOSER` when the links are in the
+    /// message composer.
+    pub channel: String,
+
+    /// Whether your app is a member of the channel or not.
+    #[serde(default)]
+    pub is_bot_user_member: bool,
+
+    /// ID of the user who shared the links.
+    pub user: String,
+
+    /// Timestamp of the message containing the links.
+    pub message_ts: String,
+
+    /// Timestamp of the parent message if the message is a thread reply.
+    pub thread_ts: Option<String>,
+
+    /// ID to unfurl the links in the message composer.
+    pub unfurl_id: Option<String>,
+
+    /// Where the links were shared, `composer` or `conversations_history`.
+    pub source: Option<String>,
+
+    /// Links matching your app's registered domains.
+    #[serde(default)]
+    pub links: Vec<SharedLink>,
+
+    /// Timestamp of the event.
+    pub event_ts: String,
+}
+
+/// Link in [`LinkShared`] event.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SharedLink {
+    /// Domain of the link.
+    pub domain: String,
+
+    /// URL of the link.
+    pub url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "link_shared",
+            "channel": "COMPOSER",
+            "user": "U123ABC456",
+            "message_ts": "Uxxxxxxx-909b5454-75f8-4ac4-b325-1b40e230bbd8",
+            "unfurl_id": "Uxxxxxxx-909b5454-75f8-4ac4-b325-1b40e230bbd8",
+            "source": "composer",
+            "links": [
+                { "domain": "example.com", "url": "https://example.com/12345" },
+                { "domain": "example.com", "url": "https://example.com/67890" }
+            ],
+            "event_ts": "123456789.9875"
+        });
+
+        let event: LinkShared = serde_json::from_value(json).unwrap();
+
+        let expected = LinkShared {
+            channel: "COMPOSER".into(),
+            is_bot_user_member: false,
+            user: "U123ABC456".into(),
+            message_ts: "Uxxxxxxx-909b5454-75f8-4ac4-b325-1b40e230bbd8".into(),
+            thread_ts: None,
+            unfurl_id: Some("Uxxxxxxx-909b5454-75f8-4ac4-b325-1b40e230bbd8".into()),
+            source: Some("composer".into()),
+            links: vec![
+                SharedLink {
+                    domain: "example.com".into(),
+                    url: "https://example.com/12345".into(),
+                },
+                SharedLink {
+                    domain: "example.com".into(),
+                    url: "https://example.com/67890".into(),
+                },
+            ],
+            event_ts: "123456789.9875".into(),
+        };
+
+        assert_eq!(event, expected);
+    }
+}
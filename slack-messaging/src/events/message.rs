@@ -0,0 +1,238 @@
+use crate::MessageMetadata;
+use crate::blocks::Block;
+
+use serde::Deserialize;
+
+/// [`message` event](https://docs.slack.dev/reference/events/message) representation.
+///
+/// The `blocks` field is deserialized into [`Block`], so rich text users typed can be handled
+/// as [`RichText`](crate::blocks::RichText) values.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::blocks::Block;
+/// use slack_messaging::events::MessageEvent;
+///
+/// let json = serde_json::json!({
+///     "type": "message",
+///     "channel": "C123ABC456",
+///     "user": "U123ABC456",
+///     "text": "Hello world",
+///     "ts": "1355517523.000005",
+///     "channel_type": "channel",
+///     "blocks": [
+///         {
+///             "type": "rich_text",
+///             "block_id": "Bh1xE",
+///             "elements": [
+///                 {
+///                     "type": "rich_text_section",
+///                     "elements": [
+///                         { "type": "text", "text": "Hello world" }
+///                     ]
+///                 }
+///             ]
+///         }
+///     ]
+/// });
+///
+/// let event: MessageEvent = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(event.text, "Hello world");
+/// assert!(matches!(event.blocks[0], Block::RichText(_)));
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MessageEvent {
+    /// [Subtype](https://docs.slack.dev/reference/events/message#subtypes) of the message.
+    pub subtype: Option<String>,
+
+    /// ID of the channel the message was posted in. Messages nested in `message` and
+    /// `previous_message` fields do not have this field.
+    pub channel: Option<String>,
+
+    /// Type of the channel, e.g. `channel`, `group`, `im` or `mpim`.
+    pub channel_type: Option<String>,
+
+    /// ID of the user who posted the message.
+    pub user: Option<String>,
+
+    /// ID of the bot which posted the message.
+    pub bot_id: Option<String>,
+
+    /// ID of the app which posted the message.
+    pub app_id: Option<String>,
+
+    /// ID of the workspace of the user.
+    pub team: Option<String>,
+
+    /// Text of the message.
+    #[serde(default)]
+    pub text: String,
+
+    /// Blocks of the message. Blocks are not validated, and the ones this crate does not
+    /// support are kept as [`Block::Unknown`].
+    #[serde(default, deserialize_with = "crate::de::lenient")]
+    pub blocks: Vec<Block>,
+
+    /// Timestamp of the message.
+    pub ts: String,
+
+    /// Timestamp of the parent message if the message is a thread reply.
+    pub thread_ts: Option<String>,
+
+    /// Timestamp of the event.
+    pub event_ts: Option<String>,
+
+    /// Metadata of the message. Metadata is not validated. Use [`MessageMetadata::payload`] to
+    /// parse its payload.
+    #[serde(default, deserialize_with = "crate::de::lenient")]
+    pub metadata: Option<MessageMetadata>,
+
+    /// Whether the message is hidden from the channel or not.
+    #[serde(default)]
+    pub hidden: bool,
+
+    /// The message after the change, for `message_changed` subtype.
+    pub message: Option<Box<MessageEvent>>,
+
+    /// The message before the change, for `message_changed` and `message_deleted` subtypes.
+    pub previous_message: Option<Box<MessageEvent>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "message",
+            "channel": "C123ABC456",
+            "user": "U123ABC456",
+            "text": "Are we there yet?",
+            "ts": "1355517523.000005",
+            "thread_ts": "1355517500.000001",
+            "event_ts": "1355517523.000005",
+            "channel_type": "channel",
+            "team": "T123ABC456",
+            "blocks": [
+                {
+                    "type": "rich_text",
+                    "block_id": "Bh1xE",
+                    "elements": [
+                        {
+                            "type": "rich_text_section",
+                            "elements": [
+                                { "type": "text", "text": "Are we there yet?" }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let event: MessageEvent = serde_json::from_value(json).unwrap();
+
+        assert_eq!(event.subtype, None);
+        assert_eq!(event.channel.as_deref(), Some("C123ABC456"));
+        assert_eq!(event.user.as_deref(), Some("U123ABC456"));
+        assert_eq!(event.text, "Are we there yet?");
+        assert_eq!(event.thread_ts.as_deref(), Some("1355517500.000001"));
+        assert_eq!(event.blocks.len(), 1);
+        assert!(matches!(event.blocks[0], Block::RichText(_)));
+    }
+
+    #[test]
+    fn it_deserializes_message_changed_subtype() {
+        let json = serde_json::json!({
+            "type": "message",
+            "subtype": "message_changed",
+            "hidden": true,
+            "channel": "C123ABC456",
+            "ts": "1358878755.000001",
+            "message": {
+                "type": "message",
+                "user": "U123ABC456",
+                "text": "Hello, world!",
+                "ts": "1355517523.000005"
+            },
+            "previous_message": {
+                "type": "message",
+                "user": "U123ABC456",
+                "text": "Hello world",
+                "ts": "1355517523.000005"
+            }
+        });
+
+        let event: MessageEvent = serde_json::from_value(json).unwrap();
+
+        assert_eq!(event.subtype.as_deref(), Some("message_changed"));
+        assert!(event.hidden);
+        assert_eq!(event.message.unwrap().text, "Hello, world!");
+        assert_eq!(event.previous_message.unwrap().text, "Hello world");
+    }
+
+    #[test]
+    fn it_parses_metadata_payload_into_typed_value() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct TaskCreated {
+            id: String,
+        }
+
+        let json = serde_json::json!({
+            "type": "message",
+            "subtype": "bot_message",
+            "channel": "C123ABC456",
+            "bot_id": "B123ABC456",
+            "text": "New task created",
+            "ts": "1358878755.000001",
+            "metadata": {
+                "event_type": "task_created",
+                "event_payload": { "id": "TK-2132" }
+            }
+        });
+
+        let event: MessageEvent = serde_json::from_value(json).unwrap();
+        let metadata = event.metadata.unwrap();
+
+        assert_eq!(metadata.event_type(), "task_created");
+        assert_eq!(
+            metadata.payload::<TaskCreated>().unwrap(),
+            TaskCreated {
+                id: "TK-2132".into()
+            }
+        );
+    }
+
+    #[test]
+    fn it_deserializes_metadata_without_validations() {
+        let json = serde_json::json!({
+            "type": "message",
+            "channel": "C123ABC456",
+            "user": "U123ABC456",
+            "text": "New task created",
+            "ts": "1358878755.000001",
+            "metadata": {
+                "event_type": "Task Created!",
+                "event_payload": ["TK-2132"]
+            }
+        });
+
+        let event: MessageEvent = serde_json::from_value(json).unwrap();
+        let metadata = event.metadata.unwrap();
+
+        assert_eq!(metadata.event_type(), "Task Created!");
+        assert_eq!(
+            metadata.payload::<Vec<String>>().unwrap(),
+            vec!["TK-2132".to_string()]
+        );
+        assert!(
+            serde_json::from_value::<MessageMetadata>(serde_json::json!({
+                "event_type": "Task Created!",
+                "event_payload": {}
+            }))
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,113 @@
+mod app_home_opened;
+mod app_mention;
+mod envelope;
+mod link_shared;
+mod message;
+
+pub use app_home_opened::AppHomeOpened;
+pub use app_mention::AppMention;
+pub use envelope::{AppRateLimited, Authorization, EventCallback, EventEnvelope, UrlVerification};
+pub use link_shared::{LinkShared, SharedLink};
+pub use message::MessageEvent;
+
+/// Enum representation of [events](https://docs.slack.dev/reference/events) included in the
+/// [`EventCallback`] envelope. Each variant is distinguished by the `type` field of the event.
+/// Events of types this crate does not know are kept as raw JSON value.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::events::Event;
+///
+/// let json = serde_json::json!({
+///     "type": "reaction_added",
+///     "user": "U024BE7LH",
+///     "reaction": "thumbsup",
+///     "event_ts": "1360782804.083113"
+/// });
+///
+/// let event: Event = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(event.r#type(), "reaction_added");
+/// assert!(matches!(event, Event::Unknown(_)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// [`app_home_opened` event](https://docs.slack.dev/reference/events/app_home_opened)
+    AppHomeOpened(Box<AppHomeOpened>),
+
+    /// [`app_mention` event](https://docs.slack.dev/reference/events/app_mention)
+    AppMention(Box<AppMention>),
+
+    /// [`link_shared` event](https://docs.slack.dev/reference/events/link_shared)
+    LinkShared(Box<LinkShared>),
+
+    /// [`message` event](https://docs.slack.dev/reference/events/message)
+    Message(Box<MessageEvent>),
+
+    /// Any other event.
+    Unknown(serde_json::Value),
+}
+
+impl Event {
+    /// Returns the `type` of the event.
+    pub fn r#type(&self) -> &str {
+        match self {
+            Self::AppHomeOpened(_) => "app_home_opened",
+            Self::AppMention(_) => "app_mention",
+            Self::LinkShared(_) => "link_shared",
+            Self::Message(_) => "message",
+            Self::Unknown(value) => value
+                .get("type")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+deserialize_by_type! {
+    Event {
+        "app_home_opened" => AppHomeOpened,
+        "app_mention" => AppMention,
+        "link_shared" => LinkShared,
+        "message" => Message,
+        _ => Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_known_events() {
+        let json = serde_json::json!({
+            "type": "app_mention",
+            "user": "U061F7AUR",
+            "text": "<@U0LAN0Z89> is it everything a river should be?",
+            "ts": "1515449522.000016",
+            "channel": "C123ABC456",
+            "event_ts": "1515449522000016"
+        });
+
+        let event: Event = serde_json::from_value(json).unwrap();
+        assert_eq!(event.r#type(), "app_mention");
+        assert!(matches!(event, Event::AppMention(_)));
+    }
+
+    #[test]
+    fn it_keeps_unknown_events_as_raw_json() {
+        let json = serde_json::json!({
+            "type": "channel_created",
+            "channel": {
+                "id": "C024BE91L",
+                "name": "fun",
+                "created": 1360782804,
+                "creator": "U024BE7LH"
+            }
+        });
+
+        let event: Event = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(event, Event::Unknown(json));
+    }
+}
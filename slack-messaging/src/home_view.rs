@@ -0,0 +1,237 @@
+use crate::blocks::Block;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// [Home tab view](https://docs.slack.dev/surfaces/app-home) representation.
+///
+/// Use this with [`ViewsPublish`](crate::requests::ViewsPublish) to publish the App Home tab
+/// of a user.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/views/home-tab-views).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | blocks | Vec<[Block]> | Yes | Maximum 100 items, available in [home](crate::Surface) |
+/// | private_metadata | String | No | Max length 3000 characters |
+/// | callback_id | String | No | Max length 255 characters |
+/// | external_id | String | No | Max length 255 characters |
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{mrkdwn, HomeView};
+/// use slack_messaging::blocks::Section;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let home = HomeView::builder()
+///     .callback_id("home_0")
+///     .block(
+///         Section::builder()
+///             .text(mrkdwn!("*Welcome home!*")?)
+///             .build()?
+///     )
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "type": "home",
+///     "blocks": [
+///         {
+///             "type": "section",
+///             "text": {
+///                 "type": "mrkdwn",
+///                 "text": "*Welcome home!*"
+///             }
+///         }
+///     ],
+///     "callback_id": "home_0"
+/// });
+///
+/// let json = serde_json::to_value(home).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let home = HomeView::builder().build();
+///
+/// assert!(home.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[serde(tag = "type", rename = "home")]
+pub struct HomeView {
+    #[builder(
+        push_item = "block",
+        validate("required", "list::max_item_100", "surface::home")
+    )]
+    pub(crate) blocks: Option<Vec<Block>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::max_3000"))]
+    pub(crate) private_metadata: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::max_255"))]
+    pub(crate) callback_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::max_255"))]
+    pub(crate) external_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Surface;
+    use crate::blocks::test_helpers::*;
+    use crate::errors::ValidationErrorKind;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = HomeView {
+            blocks: Some(vec![section("this is a section block").into()]),
+            private_metadata: Some("shhh-its-secret".into()),
+            callback_id: Some("home_0".into()),
+            external_id: Some("external_0".into()),
+        };
+
+        let val = HomeView::builder()
+            .set_blocks(Some(
+                vec![section("this is a section block").into()] as Vec<Block>
+            ))
+            .set_private_metadata(Some("shhh-its-secret"))
+            .set_callback_id(Some("home_0"))
+            .set_external_id(Some("external_0"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = HomeView::builder()
+            .blocks(vec![section("this is a section block").into()] as Vec<Block>)
+            .private_metadata("shhh-its-secret")
+            .callback_id("home_0")
+            .external_id("external_0")
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_implements_push_item_method() {
+        let expected = HomeView {
+            blocks: Some(vec![
+                header("this is a header block").into(),
+                section("this is a section block").into(),
+            ]),
+            private_metadata: None,
+            callback_id: None,
+            external_id: None,
+        };
+
+        let val = HomeView::builder()
+            .block(header("this is a header block"))
+            .block(section("this is a section block"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_blocks_field() {
+        let err = HomeView::builder().build().unwrap_err();
+        assert_eq!(err.object(), "HomeView");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_blocks_list_size_less_than_100() {
+        let blocks: Vec<Block> = (0..101).map(|_| section("foo").into()).collect();
+        let err = HomeView::builder().blocks(blocks).build().unwrap_err();
+        assert_eq!(err.object(), "HomeView");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
+    }
+
+    #[test]
+    fn it_requires_blocks_available_in_home_tabs() {
+        let err = HomeView::builder()
+            .block(section("foo"))
+            .block(task_card())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "HomeView");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::UnsupportedBlock(
+            1,
+            "task_card",
+            Surface::Home
+        )));
+    }
+
+    #[test]
+    fn it_requires_private_metadata_less_than_3000_characters_long() {
+        let err = HomeView::builder()
+            .block(section("foo"))
+            .private_metadata("a".repeat(3001))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "HomeView");
+
+        let errors = err.field("private_metadata");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
+    }
+
+    #[test]
+    fn it_requires_callback_id_less_than_255_characters_long() {
+        let err = HomeView::builder()
+            .block(section("foo"))
+            .callback_id("a".repeat(256))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "HomeView");
+
+        let errors = err.field("callback_id");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
+    }
+
+    #[test]
+    fn it_requires_external_id_less_than_255_characters_long() {
+        let err = HomeView::builder()
+            .block(section("foo"))
+            .external_id("a".repeat(256))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "HomeView");
+
+        let errors = err.field("external_id");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::composition_objects::{Plain, Text};
+
+use serde::Deserialize;
+
+/// Action of [`Button`](crate::blocks::elements::Button) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ButtonAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// Text of the clicked button.
+    pub text: Option<Text<Plain>>,
+    /// Value of the clicked button.
+    pub value: Option<String>,
+}
+
+/// Action of [`FeedbackButtons`](crate::blocks::elements::FeedbackButtons) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FeedbackButtonsAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// Text of the clicked button.
+    pub text: Option<Text<Plain>>,
+    /// Value of the clicked button, either of the positive or negative one.
+    pub value: Option<String>,
+}
+
+/// Action of [`IconButton`](crate::blocks::elements::IconButton) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct IconButtonAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// Text of the clicked button.
+    pub text: Option<Text<Plain>>,
+    /// Value of the clicked button.
+    pub value: Option<String>,
+}
+
+/// Action of [`WorkflowButton`](crate::blocks::elements::WorkflowButton) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct WorkflowButtonAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// Text of the clicked button.
+    pub text: Option<Text<Plain>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_deserializes_button_action() {
+        let json = serde_json::json!({
+            "type": "button",
+            "action_id": "WaXA",
+            "block_id": "=qXel",
+            "text": {
+                "type": "plain_text",
+                "text": "View",
+                "emoji": true
+            },
+            "value": "click_me_123",
+            "action_ts": "1548426417.840180"
+        });
+
+        let expected = ButtonAction {
+            action_id: "WaXA".into(),
+            block_id: "=qXel".into(),
+            action_ts: Some("1548426417.840180".into()),
+            text: Some(Text {
+                emoji: Some(true),
+                ..plain_text("View")
+            }),
+            value: Some("click_me_123".into()),
+        };
+
+        let action: ButtonAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+
+    #[test]
+    fn it_deserializes_feedback_buttons_action() {
+        let json = serde_json::json!({
+            "type": "feedback_buttons",
+            "action_id": "feedback_buttons_1",
+            "block_id": "context_actions_0",
+            "text": {
+                "type": "plain_text",
+                "text": "Good"
+            },
+            "value": "positive_feedback",
+            "action_ts": "1548426417.840180"
+        });
+
+        let expected = FeedbackButtonsAction {
+            action_id: "feedback_buttons_1".into(),
+            block_id: "context_actions_0".into(),
+            action_ts: Some("1548426417.840180".into()),
+            text: Some(plain_text("Good")),
+            value: Some("positive_feedback".into()),
+        };
+
+        let action: FeedbackButtonsAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::composition_objects::{Opt, Plain, Text, TextContent, types::UrlAvailable};
+
+use serde::Deserialize;
+
+/// Action of [`Checkboxes`](crate::blocks::elements::Checkboxes) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct CheckboxesAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// Options checked after the action.
+    #[serde(default)]
+    pub selected_options: Vec<Opt<TextContent>>,
+}
+
+/// Action of [`OverflowMenu`](crate::blocks::elements::OverflowMenu) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct OverflowMenuAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected option.
+    pub selected_option: Opt<Text<Plain>, UrlAvailable>,
+}
+
+/// Action of [`RadioButtonGroup`](crate::blocks::elements::RadioButtonGroup) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct RadioButtonGroupAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected option.
+    pub selected_option: Option<Opt<TextContent>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_deserializes_checkboxes_action() {
+        let json = serde_json::json!({
+            "type": "checkboxes",
+            "action_id": "checkboxes_0",
+            "block_id": "actions_0",
+            "selected_options": [
+                {
+                    "text": {
+                        "type": "mrkdwn",
+                        "text": "*a*"
+                    },
+                    "value": "a"
+                }
+            ],
+            "action_ts": "1548426417.840180"
+        });
+
+        let expected = CheckboxesAction {
+            action_id: "checkboxes_0".into(),
+            block_id: "actions_0".into(),
+            action_ts: Some("1548426417.840180".into()),
+            selected_options: vec![option_t("*a*", "a")],
+        };
+
+        let action: CheckboxesAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+
+    #[test]
+    fn it_deserializes_overflow_menu_action() {
+        let json = serde_json::json!({
+            "type": "overflow",
+            "action_id": "overflow_0",
+            "block_id": "actions_0",
+            "selected_option": {
+                "text": {
+                    "type": "plain_text",
+                    "text": "Edit"
+                },
+                "value": "edit"
+            },
+            "action_ts": "1548426417.840180"
+        });
+
+        let action: OverflowMenuAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action.selected_option.value(), Some("edit"));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::blocks::RichText;
+
+use serde::Deserialize;
+use serde_json::Number;
+
+/// Action of [`EmailInput`](crate::blocks::elements::EmailInput) element, dispatched when its
+/// `dispatch_action_config` is set.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct EmailInputAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The entered email address.
+    pub value: Option<String>,
+}
+
+/// Action of [`NumberInput`](crate::blocks::elements::NumberInput) element, dispatched when its
+/// `dispatch_action_config` is set.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct NumberInputAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The entered number.
+    #[serde(default, deserialize_with = "crate::de::number_from_str")]
+    pub value: Option<Number>,
+}
+
+/// Action of [`PlainTextInput`](crate::blocks::elements::PlainTextInput) element, dispatched when
+/// its `dispatch_action_config` is set.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PlainTextInputAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The entered text.
+    pub value: Option<String>,
+}
+
+/// Action of [`RichTextInput`](crate::blocks::elements::RichTextInput) element, dispatched when
+/// its `dispatch_action_config` is set.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct RichTextInputAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The entered rich text.
+    pub rich_text_value: Option<RichText>,
+}
+
+/// Action of [`UrlInput`](crate::blocks::elements::UrlInput) element, dispatched when its
+/// `dispatch_action_config` is set.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct UrlInputAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The entered URL.
+    pub value: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_number_input_action() {
+        let json = serde_json::json!({
+            "type": "number_input",
+            "action_id": "number_0",
+            "block_id": "input_0",
+            "value": "42",
+            "action_ts": "1548426417.840180"
+        });
+
+        let expected = NumberInputAction {
+            action_id: "number_0".into(),
+            block_id: "input_0".into(),
+            action_ts: Some("1548426417.840180".into()),
+            value: Some(42.into()),
+        };
+
+        let action: NumberInputAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+
+    #[test]
+    fn it_deserializes_rich_text_input_action() {
+        let json = serde_json::json!({
+            "type": "rich_text_input",
+            "action_id": "rich_text_0",
+            "block_id": "input_0",
+            "rich_text_value": {
+                "type": "rich_text",
+                "elements": [
+                    {
+                        "type": "rich_text_section",
+                        "elements": [
+                            { "type": "text", "text": "Hello" }
+                        ]
+                    }
+                ]
+            }
+        });
+
+        let action: RichTextInputAction = serde_json::from_value(json).unwrap();
+        assert!(action.rich_text_value.is_some());
+    }
+}
@@ -0,0 +1,279 @@
+mod buttons;
+mod choices;
+mod inputs;
+mod pickers;
+mod select_menus;
+
+pub use buttons::{ButtonAction, FeedbackButtonsAction, IconButtonAction, WorkflowButtonAction};
+pub use choices::{CheckboxesAction, OverflowMenuAction, RadioButtonGroupAction};
+pub use inputs::{
+    EmailInputAction, NumberInputAction, PlainTextInputAction, RichTextInputAction, UrlInputAction,
+};
+pub use pickers::{DatePickerAction, DatetimePickerAction, TimePickerAction};
+pub use select_menus::{
+    MultiSelectMenuConversationsAction, MultiSelectMenuExternalDataSourceAction,
+    MultiSelectMenuPublicChannelsAction, MultiSelectMenuStaticOptionsAction,
+    MultiSelectMenuUsersAction, SelectMenuConversationsAction, SelectMenuExternalDataSourceAction,
+    SelectMenuPublicChannelsAction, SelectMenuStaticOptionsAction, SelectMenuUsersAction,
+};
+
+/// An action the user took on a block element, included in the `actions` field of
+/// [`BlockActions`](crate::interactions::BlockActions) payload.
+///
+/// Each variant corresponds to the [block element](crate::blocks::elements) of the same name.
+/// The action of an element type this crate doesn't know is kept as raw JSON value in the
+/// [`Action::Unknown`] variant.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::interactions::Action;
+///
+/// let json = serde_json::json!({
+///     "type": "static_select",
+///     "action_id": "select_witch",
+///     "block_id": "actions_0",
+///     "selected_option": {
+///         "text": {
+///             "type": "plain_text",
+///             "text": "Glinda"
+///         },
+///         "value": "glinda"
+///     },
+///     "action_ts": "1548426417.840180"
+/// });
+///
+/// let action: Action = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(action.action_id(), Some("select_witch"));
+///
+/// if let Action::SelectMenuStaticOptions(action) = action {
+///     let value = action.selected_option.as_ref().and_then(|opt| opt.value());
+///     assert_eq!(value, Some("glinda"));
+/// } else {
+///     panic!("expected static select action");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Action of [`Button`](crate::blocks::elements::Button) element.
+    Button(Box<ButtonAction>),
+
+    /// Action of [`Checkboxes`](crate::blocks::elements::Checkboxes) element.
+    Checkboxes(Box<CheckboxesAction>),
+
+    /// Action of [`DatePicker`](crate::blocks::elements::DatePicker) element.
+    DatePicker(Box<DatePickerAction>),
+
+    /// Action of [`DatetimePicker`](crate::blocks::elements::DatetimePicker) element.
+    DatetimePicker(Box<DatetimePickerAction>),
+
+    /// Action of [`EmailInput`](crate::blocks::elements::EmailInput) element.
+    EmailInput(Box<EmailInputAction>),
+
+    /// Action of [`FeedbackButtons`](crate::blocks::elements::FeedbackButtons) element.
+    FeedbackButtons(Box<FeedbackButtonsAction>),
+
+    /// Action of [`IconButton`](crate::blocks::elements::IconButton) element.
+    IconButton(Box<IconButtonAction>),
+
+    /// Action of [`MultiSelectMenuStaticOptions`](crate::blocks::elements::MultiSelectMenuStaticOptions)
+    /// element.
+    MultiSelectMenuStaticOptions(Box<MultiSelectMenuStaticOptionsAction>),
+
+    /// Action of [`MultiSelectMenuExternalDataSource`](crate::blocks::elements::MultiSelectMenuExternalDataSource)
+    /// element.
+    MultiSelectMenuExternalDataSource(Box<MultiSelectMenuExternalDataSourceAction>),
+
+    /// Action of [`MultiSelectMenuUsers`](crate::blocks::elements::MultiSelectMenuUsers) element.
+    MultiSelectMenuUsers(Box<MultiSelectMenuUsersAction>),
+
+    /// Action of [`MultiSelectMenuConversations`](crate::blocks::elements::MultiSelectMenuConversations)
+    /// element.
+    MultiSelectMenuConversations(Box<MultiSelectMenuConversationsAction>),
+
+    /// Action of [`MultiSelectMenuPublicChannels`](crate::blocks::elements::MultiSelectMenuPublicChannels)
+    /// element.
+    MultiSelectMenuPublicChannels(Box<MultiSelectMenuPublicChannelsAction>),
+
+    /// Action of [`NumberInput`](crate::blocks::elements::NumberInput) element.
+    NumberInput(Box<NumberInputAction>),
+
+    /// Action of [`OverflowMenu`](crate::blocks::elements::OverflowMenu) element.
+    OverflowMenu(Box<OverflowMenuAction>),
+
+    /// Action of [`PlainTextInput`](crate::blocks::elements::PlainTextInput) element.
+    PlainTextInput(Box<PlainTextInputAction>),
+
+    /// Action of [`RadioButtonGroup`](crate::blocks::elements::RadioButtonGroup) element.
+    RadioButtonGroup(Box<RadioButtonGroupAction>),
+
+    /// Action of [`RichTextInput`](crate::blocks::elements::RichTextInput) element.
+    RichTextInput(Box<RichTextInputAction>),
+
+    /// Action of [`SelectMenuStaticOptions`](crate::blocks::elements::SelectMenuStaticOptions)
+    /// element.
+    SelectMenuStaticOptions(Box<SelectMenuStaticOptionsAction>),
+
+    /// Action of [`SelectMenuExternalDataSource`](crate::blocks::elements::SelectMenuExternalDataSource)
+    /// element.
+    SelectMenuExternalDataSource(Box<SelectMenuExternalDataSourceAction>),
+
+    /// Action of [`SelectMenuUsers`](crate::blocks::elements::SelectMenuUsers) element.
+    SelectMenuUsers(Box<SelectMenuUsersAction>),
+
+    /// Action of [`SelectMenuConversations`](crate::blocks::elements::SelectMenuConversations)
+    /// element.
+    SelectMenuConversations(Box<SelectMenuConversationsAction>),
+
+    /// Action of [`SelectMenuPublicChannels`](crate::blocks::elements::SelectMenuPublicChannels)
+    /// element.
+    SelectMenuPublicChannels(Box<SelectMenuPublicChannelsAction>),
+
+    /// Action of [`TimePicker`](crate::blocks::elements::TimePicker) element.
+    TimePicker(Box<TimePickerAction>),
+
+    /// Action of [`UrlInput`](crate::blocks::elements::UrlInput) element.
+    UrlInput(Box<UrlInputAction>),
+
+    /// Action of [`WorkflowButton`](crate::blocks::elements::WorkflowButton) element.
+    WorkflowButton(Box<WorkflowButtonAction>),
+
+    /// Action of any other element not supported by this crate.
+    Unknown(serde_json::Value),
+}
+
+deserialize_by_type! {
+    Action {
+        "button" => Button,
+        "checkboxes" => Checkboxes,
+        "datepicker" => DatePicker,
+        "datetimepicker" => DatetimePicker,
+        "email_text_input" => EmailInput,
+        "feedback_buttons" => FeedbackButtons,
+        "icon_button" => IconButton,
+        "multi_static_select" => MultiSelectMenuStaticOptions,
+        "multi_external_select" => MultiSelectMenuExternalDataSource,
+        "multi_users_select" => MultiSelectMenuUsers,
+        "multi_conversations_select" => MultiSelectMenuConversations,
+        "multi_channels_select" => MultiSelectMenuPublicChannels,
+        "number_input" => NumberInput,
+        "overflow" => OverflowMenu,
+        "plain_text_input" => PlainTextInput,
+        "radio_buttons" => RadioButtonGroup,
+        "rich_text_input" => RichTextInput,
+        "static_select" => SelectMenuStaticOptions,
+        "external_select" => SelectMenuExternalDataSource,
+        "users_select" => SelectMenuUsers,
+        "conversations_select" => SelectMenuConversations,
+        "channels_select" => SelectMenuPublicChannels,
+        "timepicker" => TimePicker,
+        "url_text_input" => UrlInput,
+        "workflow_button" => WorkflowButton,
+        _ => Unknown,
+    }
+}
+
+macro_rules! action_field {
+    ($action:expr, $field:ident) => {
+        match $action {
+            Action::Button(a) => Some(a.$field.as_str()),
+            Action::Checkboxes(a) => Some(a.$field.as_str()),
+            Action::DatePicker(a) => Some(a.$field.as_str()),
+            Action::DatetimePicker(a) => Some(a.$field.as_str()),
+            Action::EmailInput(a) => Some(a.$field.as_str()),
+            Action::FeedbackButtons(a) => Some(a.$field.as_str()),
+            Action::IconButton(a) => Some(a.$field.as_str()),
+            Action::MultiSelectMenuStaticOptions(a) => Some(a.$field.as_str()),
+            Action::MultiSelectMenuExternalDataSource(a) => Some(a.$field.as_str()),
+            Action::MultiSelectMenuUsers(a) => Some(a.$field.as_str()),
+            Action::MultiSelectMenuConversations(a) => Some(a.$field.as_str()),
+            Action::MultiSelectMenuPublicChannels(a) => Some(a.$field.as_str()),
+            Action::NumberInput(a) => Some(a.$field.as_str()),
+            Action::OverflowMenu(a) => Some(a.$field.as_str()),
+            Action::PlainTextInput(a) => Some(a.$field.as_str()),
+            Action::RadioButtonGroup(a) => Some(a.$field.as_str()),
+            Action::RichTextInput(a) => Some(a.$field.as_str()),
+            Action::SelectMenuStaticOptions(a) => Some(a.$field.as_str()),
+            Action::SelectMenuExternalDataSource(a) => Some(a.$field.as_str()),
+            Action::SelectMenuUsers(a) => Some(a.$field.as_str()),
+            Action::SelectMenuConversations(a) => Some(a.$field.as_str()),
+            Action::SelectMenuPublicChannels(a) => Some(a.$field.as_str()),
+            Action::TimePicker(a) => Some(a.$field.as_str()),
+            Action::UrlInput(a) => Some(a.$field.as_str()),
+            Action::WorkflowButton(a) => Some(a.$field.as_str()),
+            Action::Unknown(v) => v.get(stringify!($field)).and_then(|v| v.as_str()),
+        }
+    };
+}
+
+impl Action {
+    /// Returns action_id of the element the user interacted with.
+    pub fn action_id(&self) -> Option<&str> {
+        action_field!(self, action_id)
+    }
+
+    /// Returns block_id of the block containing the element the user interacted with.
+    pub fn block_id(&self) -> Option<&str> {
+        action_field!(self, block_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_each_action_by_type() {
+        let json = serde_json::json!({
+            "type": "button",
+            "action_id": "button_0",
+            "block_id": "actions_0",
+            "value": "click_me_123"
+        });
+        let action: Action = serde_json::from_value(json).unwrap();
+        assert!(matches!(action, Action::Button(_)));
+
+        let json = serde_json::json!({
+            "type": "datetimepicker",
+            "action_id": "datetimepicker_0",
+            "block_id": "actions_0",
+            "selected_date_time": 1628633820
+        });
+        let action: Action = serde_json::from_value(json).unwrap();
+        assert!(matches!(action, Action::DatetimePicker(_)));
+
+        let json = serde_json::json!({
+            "type": "icon_button",
+            "action_id": "delete_button_1",
+            "block_id": "context_actions_0",
+            "value": "delete_item"
+        });
+        let action: Action = serde_json::from_value(json).unwrap();
+        assert!(matches!(action, Action::IconButton(_)));
+    }
+
+    #[test]
+    fn it_keeps_unknown_action_as_json_value() {
+        let json = serde_json::json!({
+            "type": "some_future_element",
+            "action_id": "future_0",
+            "block_id": "actions_0"
+        });
+        let action: Action = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(action, Action::Unknown(json));
+        assert_eq!(action.action_id(), Some("future_0"));
+        assert_eq!(action.block_id(), Some("actions_0"));
+    }
+
+    #[test]
+    fn it_fails_to_deserialize_invalid_action_of_known_type() {
+        let json = serde_json::json!({
+            "type": "datepicker",
+            "action_id": "datepicker_0",
+            "block_id": "actions_0",
+            "selected_date": "not a date"
+        });
+        assert!(serde_json::from_value::<Action>(json).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::Deserialize;
+
+/// Action of [`DatePicker`](crate::blocks::elements::DatePicker) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DatePickerAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected date. None if the date is cleared.
+    pub selected_date: Option<NaiveDate>,
+}
+
+/// Action of [`DatetimePicker`](crate::blocks::elements::DatetimePicker) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DatetimePickerAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected date and time in UNIX timestamp. None if it is cleared.
+    pub selected_date_time: Option<i64>,
+}
+
+/// Action of [`TimePicker`](crate::blocks::elements::TimePicker) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TimePickerAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected time. None if the time is cleared.
+    pub selected_time: Option<NaiveTime>,
+    /// IANA format timezone the time picker is set to, if any.
+    pub timezone: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_date_picker_action() {
+        let json = serde_json::json!({
+            "type": "datepicker",
+            "action_id": "datepicker_0",
+            "block_id": "actions_0",
+            "selected_date": "1990-04-28",
+            "initial_date": "1990-04-01",
+            "action_ts": "1548426417.840180"
+        });
+
+        let expected = DatePickerAction {
+            action_id: "datepicker_0".into(),
+            block_id: "actions_0".into(),
+            action_ts: Some("1548426417.840180".into()),
+            selected_date: NaiveDate::from_ymd_opt(1990, 4, 28),
+        };
+
+        let action: DatePickerAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+
+    #[test]
+    fn it_deserializes_cleared_date_picker_action() {
+        let json = serde_json::json!({
+            "type": "datepicker",
+            "action_id": "datepicker_0",
+            "block_id": "actions_0",
+            "selected_date": null
+        });
+
+        let action: DatePickerAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action.selected_date, None);
+    }
+
+    #[test]
+    fn it_deserializes_time_picker_action() {
+        let json = serde_json::json!({
+            "type": "timepicker",
+            "action_id": "timepicker_0",
+            "block_id": "actions_0",
+            "selected_time": "14:30",
+            "timezone": "Asia/Tokyo"
+        });
+
+        let expected = TimePickerAction {
+            action_id: "timepicker_0".into(),
+            block_id: "actions_0".into(),
+            action_ts: None,
+            selected_time: NaiveTime::from_hms_opt(14, 30, 0),
+            timezone: Some("Asia/Tokyo".into()),
+        };
+
+        let action: TimePickerAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::composition_objects::Opt;
+
+use serde::Deserialize;
+
+/// Action of [`SelectMenuConversations`](crate::blocks::elements::SelectMenuConversations)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SelectMenuConversationsAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// ID of the selected conversation.
+    pub selected_conversation: Option<String>,
+}
+
+/// Action of [`SelectMenuExternalDataSource`](crate::blocks::elements::SelectMenuExternalDataSource)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SelectMenuExternalDataSourceAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected option.
+    pub selected_option: Option<Opt>,
+}
+
+/// Action of [`SelectMenuPublicChannels`](crate::blocks::elements::SelectMenuPublicChannels)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SelectMenuPublicChannelsAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// ID of the selected channel.
+    pub selected_channel: Option<String>,
+}
+
+/// Action of [`SelectMenuStaticOptions`](crate::blocks::elements::SelectMenuStaticOptions)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SelectMenuStaticOptionsAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected option.
+    pub selected_option: Option<Opt>,
+}
+
+/// Action of [`SelectMenuUsers`](crate::blocks::elements::SelectMenuUsers) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SelectMenuUsersAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// ID of the selected user.
+    pub selected_user: Option<String>,
+}
+
+/// Action of [`MultiSelectMenuConversations`](crate::blocks::elements::MultiSelectMenuConversations)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MultiSelectMenuConversationsAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// IDs of the selected conversations.
+    #[serde(default)]
+    pub selected_conversations: Vec<String>,
+}
+
+/// Action of [`MultiSelectMenuExternalDataSource`](crate::blocks::elements::MultiSelectMenuExternalDataSource)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MultiSelectMenuExternalDataSourceAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected options.
+    #[serde(default)]
+    pub selected_options: Vec<Opt>,
+}
+
+/// Action of [`MultiSelectMenuPublicChannels`](crate::blocks::elements::MultiSelectMenuPublicChannels)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MultiSelectMenuPublicChannelsAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// IDs of the selected channels.
+    #[serde(default)]
+    pub selected_channels: Vec<String>,
+}
+
+/// Action of [`MultiSelectMenuStaticOptions`](crate::blocks::elements::MultiSelectMenuStaticOptions)
+/// element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MultiSelectMenuStaticOptionsAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// The selected options.
+    #[serde(default)]
+    pub selected_options: Vec<Opt>,
+}
+
+/// Action of [`MultiSelectMenuUsers`](crate::blocks::elements::MultiSelectMenuUsers) element.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MultiSelectMenuUsersAction {
+    /// action_id of the element.
+    pub action_id: String,
+    /// block_id of the block containing the element.
+    pub block_id: String,
+    /// Timestamp of the action.
+    pub action_ts: Option<String>,
+    /// IDs of the selected users.
+    #[serde(default)]
+    pub selected_users: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_deserializes_static_select_action() {
+        let json = serde_json::json!({
+            "type": "static_select",
+            "action_id": "select_0",
+            "block_id": "actions_0",
+            "selected_option": {
+                "text": {
+                    "type": "plain_text",
+                    "text": "Matilda"
+                },
+                "value": "matilda"
+            },
+            "placeholder": {
+                "type": "plain_text",
+                "text": "Which witch is the witchiest witch?"
+            },
+            "action_ts": "1548426417.840180"
+        });
+
+        let expected = SelectMenuStaticOptionsAction {
+            action_id: "select_0".into(),
+            block_id: "actions_0".into(),
+            action_ts: Some("1548426417.840180".into()),
+            selected_option: Some(option("Matilda", "matilda")),
+        };
+
+        let action: SelectMenuStaticOptionsAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+
+    #[test]
+    fn it_deserializes_multi_users_select_action() {
+        let json = serde_json::json!({
+            "type": "multi_users_select",
+            "action_id": "select_0",
+            "block_id": "actions_0",
+            "selected_users": ["U0123", "U4567"]
+        });
+
+        let expected = MultiSelectMenuUsersAction {
+            action_id: "select_0".into(),
+            block_id: "actions_0".into(),
+            action_ts: None,
+            selected_users: vec!["U0123".into(), "U4567".into()],
+        };
+
+        let action: MultiSelectMenuUsersAction = serde_json::from_value(json).unwrap();
+        assert_eq!(action, expected);
+    }
+}
@@ -0,0 +1,272 @@
+use super::types::{Channel, Container, Enterprise, OriginalMessage, Team, User, ViewPayload};
+use super::{Action, ViewState};
+
+use serde::Deserialize;
+
+/// [`block_actions` payload](https://docs.slack.dev/reference/interaction-payloads/block_actions-payload)
+/// representation.
+///
+/// Slack sends this payload to your app when a user interacts with a block element of a message
+/// or a view.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::interactions::{Action, BlockActions};
+///
+/// let json = serde_json::json!({
+///     "type": "block_actions",
+///     "team": {
+///         "id": "T9TK3CUKW",
+///         "domain": "example"
+///     },
+///     "user": {
+///         "id": "UA8RXUSPL",
+///         "username": "jtorrance",
+///         "team_id": "T9TK3CUKW"
+///     },
+///     "api_app_id": "AABA1ABCD",
+///     "container": {
+///         "type": "message",
+///         "message_ts": "1548261231.000200",
+///         "channel_id": "CBR2V3XEX",
+///         "is_ephemeral": false
+///     },
+///     "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+///     "channel": {
+///         "id": "CBR2V3XEX",
+///         "name": "review-updates"
+///     },
+///     "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+///     "actions": [
+///         {
+///             "type": "button",
+///             "action_id": "approve",
+///             "block_id": "actions_0",
+///             "text": {
+///                 "type": "plain_text",
+///                 "text": "Approve"
+///             },
+///             "value": "approve",
+///             "action_ts": "1548426417.840180"
+///         }
+///     ]
+/// });
+///
+/// let payload: BlockActions = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(payload.user.id, "UA8RXUSPL");
+///
+/// match &payload.actions[0] {
+///     Action::Button(button) => assert_eq!(button.value.as_deref(), Some("approve")),
+///     _ => panic!("expected button action"),
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct BlockActions {
+    /// The workspace the interaction happened in.
+    pub team: Option<Team>,
+
+    /// The user who interacted with the element.
+    pub user: User,
+
+    /// ID of your app.
+    pub api_app_id: String,
+
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// The source of the interaction.
+    pub container: Container,
+
+    /// Short-lived ID to open a modal.
+    pub trigger_id: String,
+
+    /// The enterprise organization the interaction happened in.
+    pub enterprise: Option<Enterprise>,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+
+    /// The channel the interaction happened in.
+    pub channel: Option<Channel>,
+
+    /// The message containing the block the user interacted with.
+    pub message: Option<OriginalMessage>,
+
+    /// The view containing the block the user interacted with.
+    pub view: Option<ViewPayload>,
+
+    /// Values of the input elements in the message. For the view, see the `state` field of
+    /// `view` instead.
+    pub state: Option<ViewState>,
+
+    /// URL to respond to the interaction. Only included when the interaction happened in a
+    /// message.
+    pub response_url: Option<String>,
+
+    /// Actions the user took.
+    #[serde(default)]
+    pub actions: Vec<Action>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::Block;
+    use crate::interactions::Interaction;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "block_actions",
+            "team": {
+                "id": "T9TK3CUKW",
+                "domain": "example"
+            },
+            "user": {
+                "id": "UA8RXUSPL",
+                "username": "jtorrance",
+                "name": "jtorrance",
+                "team_id": "T9TK3CUKW"
+            },
+            "api_app_id": "AABA1ABCD",
+            "token": "9s8d9as89d8as9d8as989",
+            "container": {
+                "type": "message_attachment",
+                "message_ts": "1548261231.000200",
+                "attachment_id": 1,
+                "channel_id": "CBR2V3XEX",
+                "is_ephemeral": false,
+                "is_app_unfurl": false
+            },
+            "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+            "enterprise": null,
+            "is_enterprise_install": false,
+            "channel": {
+                "id": "CBR2V3XEX",
+                "name": "review-updates"
+            },
+            "message": {
+                "type": "message",
+                "bot_id": "BAH5CA16Z",
+                "text": "This content can't be displayed.",
+                "user": "UAJ2RU415",
+                "ts": "1548261231.000200",
+                "team": "T9TK3CUKW",
+                "blocks": [
+                    {
+                        "type": "section",
+                        "block_id": "=qXel",
+                        "text": {
+                            "type": "mrkdwn",
+                            "text": "Would you like to play a game?",
+                            "verbatim": false
+                        }
+                    },
+                    {
+                        "type": "actions",
+                        "block_id": "actions_0",
+                        "elements": [
+                            {
+                                "type": "button",
+                                "action_id": "WaXA",
+                                "text": {
+                                    "type": "plain_text",
+                                    "text": "View",
+                                    "emoji": true
+                                },
+                                "value": "click_me_123"
+                            }
+                        ]
+                    }
+                ]
+            },
+            "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+            "actions": [
+                {
+                    "action_id": "WaXA",
+                    "block_id": "actions_0",
+                    "text": {
+                        "type": "plain_text",
+                        "text": "View",
+                        "emoji": true
+                    },
+                    "value": "click_me_123",
+                    "type": "button",
+                    "action_ts": "1548426417.840180"
+                },
+                {
+                    "type": "some_future_element",
+                    "action_id": "future_0",
+                    "block_id": "actions_0"
+                }
+            ]
+        });
+
+        let payload: BlockActions = serde_json::from_value(json).unwrap();
+
+        assert_eq!(payload.user.id, "UA8RXUSPL");
+        assert_eq!(payload.team.map(|t| t.id), Some("T9TK3CUKW".into()));
+        assert_eq!(
+            payload.container,
+            Container::MessageAttachment {
+                message_ts: "1548261231.000200".into(),
+                attachment_id: 1,
+                channel_id: Some("CBR2V3XEX".into()),
+                is_ephemeral: false,
+                is_app_unfurl: false,
+            }
+        );
+        assert_eq!(
+            payload.channel,
+            Some(Channel {
+                id: "CBR2V3XEX".into(),
+                name: Some("review-updates".into()),
+            })
+        );
+        assert_eq!(payload.message.map(|m| m.blocks.len()), Some(2));
+        assert_eq!(payload.actions.len(), 2);
+        assert!(matches!(payload.actions[0], Action::Button(_)));
+        assert!(matches!(payload.actions[1], Action::Unknown(_)));
+    }
+
+    #[test]
+    fn it_keeps_blocks_of_original_message_slack_sends_as_is() {
+        let call = serde_json::json!({ "type": "call", "call_id": "R123ABC456" });
+        let json = serde_json::json!({
+            "type": "block_actions",
+            "user": { "id": "UA8RXUSPL" },
+            "api_app_id": "AABA1ABCD",
+            "container": {
+                "type": "message",
+                "message_ts": "1548261231.000200",
+                "channel_id": "CBR2V3XEX",
+                "is_ephemeral": false
+            },
+            "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+            "message": {
+                "type": "message",
+                "text": "Join the call",
+                "ts": "1548261231.000200",
+                "blocks": [
+                    call.clone(),
+                    {
+                        "type": "section",
+                        "text": { "type": "mrkdwn", "text": "a".repeat(3001) }
+                    }
+                ]
+            },
+            "actions": []
+        });
+
+        let Interaction::BlockActions(payload) = serde_json::from_value(json).unwrap() else {
+            panic!("expected block_actions payload");
+        };
+        let blocks = payload.message.unwrap().blocks;
+
+        assert_eq!(blocks[0], Block::Unknown(call));
+        assert!(matches!(blocks[1], Block::Section(_)));
+    }
+}
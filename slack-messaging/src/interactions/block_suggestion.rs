@@ -0,0 +1,120 @@
+use super::types::{Channel, Container, Enterprise, OriginalMessage, Team, User, ViewPayload};
+
+use serde::Deserialize;
+
+/// [`block_suggestion` payload](https://docs.slack.dev/reference/interaction-payloads/block_suggestion-payload)
+/// representation.
+///
+/// Slack sends this payload to the options load URL of your app when a user types into
+/// [`SelectMenuExternalDataSource`](crate::blocks::elements::SelectMenuExternalDataSource) or
+/// [`MultiSelectMenuExternalDataSource`](crate::blocks::elements::MultiSelectMenuExternalDataSource)
+/// element. Respond to it with [`OptionsResponse`](super::OptionsResponse).
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::interactions::BlockSuggestion;
+///
+/// let json = serde_json::json!({
+///     "type": "block_suggestion",
+///     "user": { "id": "U0D15K92L", "name": "dr_maomao" },
+///     "team": { "id": "T0CAG", "domain": "acme-creamery" },
+///     "api_app_id": "A0CA5",
+///     "container": { "type": "view", "view_id": "V0PKB1ZFV" },
+///     "action_id": "witch_select",
+///     "block_id": "witch_block",
+///     "value": "gl"
+/// });
+///
+/// let payload: BlockSuggestion = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(payload.action_id, "witch_select");
+/// assert_eq!(payload.value, "gl");
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct BlockSuggestion {
+    /// The workspace the interaction happened in.
+    pub team: Option<Team>,
+
+    /// The user who is typing into the select menu.
+    pub user: User,
+
+    /// ID of your app.
+    pub api_app_id: String,
+
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// The source of the interaction.
+    pub container: Option<Container>,
+
+    /// The enterprise organization the interaction happened in.
+    pub enterprise: Option<Enterprise>,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+
+    /// The channel the interaction happened in.
+    pub channel: Option<Channel>,
+
+    /// The message containing the select menu.
+    pub message: Option<OriginalMessage>,
+
+    /// The view containing the select menu.
+    pub view: Option<ViewPayload>,
+
+    /// action_id of the select menu.
+    pub action_id: String,
+
+    /// block_id of the block containing the select menu.
+    pub block_id: String,
+
+    /// Text the user has typed so far.
+    #[serde(default)]
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "block_suggestion",
+            "user": {
+                "id": "U0D15K92L",
+                "username": "dr_maomao",
+                "team_id": "T0CAG"
+            },
+            "container": {
+                "type": "message",
+                "message_ts": "1548261231.000200",
+                "channel_id": "CBR2V3XEX",
+                "is_ephemeral": false
+            },
+            "api_app_id": "A0CA5",
+            "token": "Nj2rfC2hU8mAfgaJLemZgO7H",
+            "action_id": "chooseOption",
+            "block_id": "sectionBlockWithExternalSelect",
+            "value": "Blue",
+            "team": {
+                "id": "T0CAG",
+                "domain": "acme-creamery"
+            },
+            "channel": {
+                "id": "CBR2V3XEX",
+                "name": "review-updates"
+            }
+        });
+
+        let payload: BlockSuggestion = serde_json::from_value(json).unwrap();
+
+        assert_eq!(payload.user.id, "U0D15K92L");
+        assert_eq!(payload.action_id, "chooseOption");
+        assert_eq!(payload.block_id, "sectionBlockWithExternalSelect");
+        assert_eq!(payload.value, "Blue");
+        assert!(matches!(payload.container, Some(Container::Message { .. })));
+    }
+}
@@ -0,0 +1,143 @@
+use super::types::{Channel, Enterprise, OriginalMessage, Team, User};
+
+use serde::Deserialize;
+
+/// [`message_action` payload](https://docs.slack.dev/reference/interaction-payloads/shortcuts-interaction-payload#message_actions)
+/// representation.
+///
+/// Slack sends this payload to your app when a user invokes a message shortcut. The `blocks` of
+/// the source message are deserialized into [`Block`](crate::blocks::Block), so a message built
+/// with [`Message::builder()`](crate::Message::builder) can be inspected without reparsing JSON.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::blocks::Block;
+/// use slack_messaging::interactions::MessageAction;
+///
+/// let json = serde_json::json!({
+///     "type": "message_action",
+///     "callback_id": "create_ticket",
+///     "trigger_id": "13345224609.738474920.8088930838d88f008e0",
+///     "response_url": "https://hooks.slack.com/app-actions/T0MJR11A4/21974584944/yk1S9ndf35Q1flupVG5JbpM6",
+///     "team": { "id": "T0MJRM1A7", "domain": "pandamonium" },
+///     "channel": { "id": "D0LFFBKLZ", "name": "cats" },
+///     "user": { "id": "U0D15K92L", "name": "dr_maomao" },
+///     "message": {
+///         "type": "message",
+///         "user": "U0MJRG1AL",
+///         "ts": "1516229207.000133",
+///         "text": "World's smallest big cat!",
+///         "blocks": [
+///             {
+///                 "type": "section",
+///                 "text": { "type": "mrkdwn", "text": "World's smallest big cat!" }
+///             }
+///         ]
+///     }
+/// });
+///
+/// let payload: MessageAction = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(payload.callback_id, "create_ticket");
+/// assert!(matches!(payload.message.blocks[0], Block::Section(_)));
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MessageAction {
+    /// The workspace the shortcut was invoked in.
+    pub team: Option<Team>,
+
+    /// The user who invoked the shortcut.
+    pub user: User,
+
+    /// ID of your app.
+    pub api_app_id: Option<String>,
+
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// callback_id of the shortcut configured in your app settings.
+    pub callback_id: String,
+
+    /// Short-lived ID to open a modal.
+    pub trigger_id: String,
+
+    /// URL to post messages to the channel the shortcut was invoked in.
+    pub response_url: String,
+
+    /// The channel the shortcut was invoked in.
+    pub channel: Option<Channel>,
+
+    /// The message the shortcut was invoked on.
+    pub message: OriginalMessage,
+
+    /// Timestamp of the message the shortcut was invoked on.
+    pub message_ts: Option<String>,
+
+    /// Timestamp when the shortcut was invoked.
+    pub action_ts: Option<String>,
+
+    /// The enterprise organization the shortcut was invoked in.
+    pub enterprise: Option<Enterprise>,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::Block;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "message_action",
+            "token": "Nj2rfC2hU8mAfgaJLemZgO7H",
+            "action_ts": "1516229219.163037",
+            "team": {
+                "id": "T0MJRM1A7",
+                "domain": "pandamonium"
+            },
+            "user": {
+                "id": "U0D15K92L",
+                "name": "dr_maomao"
+            },
+            "channel": {
+                "id": "D0LFFBKLZ",
+                "name": "cats"
+            },
+            "callback_id": "create_ticket",
+            "trigger_id": "13345224609.738474920.8088930838d88f008e0",
+            "message_ts": "1516229207.000133",
+            "message": {
+                "type": "message",
+                "user": "U0MJRG1AL",
+                "ts": "1516229207.000133",
+                "text": "World's smallest big cat! <https://youtube.com/watch?v=W86cTIoMv2U>",
+                "blocks": [
+                    {
+                        "type": "header",
+                        "text": { "type": "plain_text", "text": "Big cats" }
+                    },
+                    {
+                        "type": "section",
+                        "text": { "type": "mrkdwn", "text": "World's smallest big cat!" }
+                    }
+                ]
+            },
+            "response_url": "https://hooks.slack.com/app-actions/T0MJR11A4/21974584944/yk1S9ndf35Q1flupVG5JbpM6"
+        });
+
+        let payload: MessageAction = serde_json::from_value(json).unwrap();
+
+        assert_eq!(payload.user.id, "U0D15K92L");
+        assert_eq!(payload.channel.unwrap().id, "D0LFFBKLZ");
+        assert_eq!(payload.message_ts.as_deref(), Some("1516229207.000133"));
+        assert_eq!(payload.message.ts, "1516229207.000133");
+        assert_eq!(payload.message.blocks.len(), 2);
+        assert!(matches!(payload.message.blocks[0], Block::Header(_)));
+        assert!(matches!(payload.message.blocks[1], Block::Section(_)));
+    }
+}
@@ -0,0 +1,73 @@
+/// Actions included in the block_actions payload.
+pub mod actions;
+/// Objects commonly included in interaction payloads.
+pub mod types;
+
+mod block_actions;
+mod block_suggestion;
+mod message_action;
+mod options_response;
+mod shortcut;
+mod state;
+mod view_closed;
+mod view_submission;
+mod view_submission_response;
+
+pub use actions::Action;
+pub use block_actions::BlockActions;
+pub use block_suggestion::BlockSuggestion;
+pub use message_action::MessageAction;
+pub use options_response::{OptionsResponse, OptionsResponseBuilder};
+pub use shortcut::Shortcut;
+pub use state::{StateElement, StateValue, ViewState};
+pub use view_closed::ViewClosed;
+pub use view_submission::ViewSubmission;
+pub use view_submission_response::ViewSubmissionResponse;
+
+use serde::Deserialize;
+
+/// Enum representation of [interaction payloads](https://docs.slack.dev/reference/interaction-payloads)
+/// Slack sends to your app. Each variant is distinguished by the `type` field of the payload.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::interactions::Interaction;
+///
+/// let json = serde_json::json!({
+///     "type": "block_actions",
+///     "user": { "id": "UA8RXUSPL" },
+///     "api_app_id": "AABA1ABCD",
+///     "container": {
+///         "type": "view",
+///         "view_id": "V0PKB1ZFV"
+///     },
+///     "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+///     "actions": []
+/// });
+///
+/// let interaction: Interaction = serde_json::from_value(json).unwrap();
+///
+/// assert!(matches!(interaction, Interaction::BlockActions(_)));
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Interaction {
+    /// [`block_actions` payload](https://docs.slack.dev/reference/interaction-payloads/block_actions-payload)
+    BlockActions(Box<BlockActions>),
+
+    /// [`block_suggestion` payload](https://docs.slack.dev/reference/interaction-payloads/block_suggestion-payload)
+    BlockSuggestion(Box<BlockSuggestion>),
+
+    /// [`shortcut` payload](https://docs.slack.dev/reference/interaction-payloads/shortcuts-interaction-payload)
+    Shortcut(Box<Shortcut>),
+
+    /// [`message_action` payload](https://docs.slack.dev/reference/interaction-payloads/shortcuts-interaction-payload#message_actions)
+    MessageAction(Box<MessageAction>),
+
+    /// [`view_submission` payload](https://docs.slack.dev/reference/interaction-payloads/view-interactions-payload#view_submission)
+    ViewSubmission(Box<ViewSubmission>),
+
+    /// [`view_closed` payload](https://docs.slack.dev/reference/interaction-payloads/view-interactions-payload#view_closed)
+    ViewClosed(Box<ViewClosed>),
+}
@@ -0,0 +1,262 @@
+use crate::composition_objects::{Opt, OptGroup};
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// [Response](https://docs.slack.dev/reference/block-kit/block-elements/select-menu-element#external_select)
+/// to [`BlockSuggestion`](super::BlockSuggestion) payload, listing the options of external data
+/// source select menus.
+///
+/// # Fields and Validations
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | options | Vec<[Opt]> | Conditionally* | Max items 100 |
+/// | option_groups | Vec<[OptGroup]> | Conditionally* | Max items 100 |
+///
+/// # Validation Across Fields
+///
+/// * Either `options` or `option_groups` is required. Both fields cannot be set simultaneously.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::plain_text;
+/// use slack_messaging::composition_objects::Opt;
+/// use slack_messaging::interactions::OptionsResponse;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let response = OptionsResponse::builder()
+///     .option(
+///         Opt::builder()
+///             .text(plain_text!("Glinda")?)
+///             .value("glinda")
+///             .build()?
+///     )
+///     .option(
+///         Opt::builder()
+///             .text(plain_text!("Granny Weatherwax")?)
+///             .value("grannyWeatherwax")
+///             .build()?
+///     )
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "options": [
+///         {
+///             "text": {
+///                 "type": "plain_text",
+///                 "text": "Glinda"
+///             },
+///             "value": "glinda"
+///         },
+///         {
+///             "text": {
+///                 "type": "plain_text",
+///                 "text": "Granny Weatherwax"
+///             },
+///             "value": "grannyWeatherwax"
+///         }
+///     ]
+/// });
+///
+/// let json = serde_json::to_value(response).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let response = OptionsResponse::builder().build();
+///
+/// assert!(response.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate")]
+pub struct OptionsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(push_item = "option", validate("list::max_item_100"))]
+    pub(crate) options: Option<Vec<Opt>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(push_item = "option_group", validate("list::max_item_100"))]
+    pub(crate) option_groups: Option<Vec<OptGroup>>,
+}
+
+fn validate(val: &OptionsResponse) -> Vec<ValidationErrorKind> {
+    match (val.options.as_ref(), val.option_groups.as_ref()) {
+        (Some(_), Some(_)) => {
+            vec![ValidationErrorKind::ExclusiveField(
+                "options",
+                "option_groups",
+            )]
+        }
+        (None, None) => {
+            vec![ValidationErrorKind::EitherRequired(
+                "options",
+                "option_groups",
+            )]
+        }
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_implements_builder() {
+        // using options field
+        let expected = OptionsResponse {
+            options: Some(vec![option("opt0", "val0"), option("opt1", "val1")]),
+            option_groups: None,
+        };
+
+        let val = OptionsResponse::builder()
+            .set_options(Some(vec![option("opt0", "val0"), option("opt1", "val1")]))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = OptionsResponse::builder()
+            .options(vec![option("opt0", "val0"), option("opt1", "val1")])
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        // using option_groups field
+        let expected = OptionsResponse {
+            options: None,
+            option_groups: Some(vec![option_group(
+                "group0",
+                vec![option("opt00", "val00"), option("opt01", "val01")],
+            )]),
+        };
+
+        let val = OptionsResponse::builder()
+            .set_option_groups(Some(vec![option_group(
+                "group0",
+                vec![option("opt00", "val00"), option("opt01", "val01")],
+            )]))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = OptionsResponse::builder()
+            .option_groups(vec![option_group(
+                "group0",
+                vec![option("opt00", "val00"), option("opt01", "val01")],
+            )])
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_implements_push_item_method() {
+        let expected = OptionsResponse {
+            options: Some(vec![option("opt0", "val0"), option("opt1", "val1")]),
+            option_groups: None,
+        };
+
+        let val = OptionsResponse::builder()
+            .option(option("opt0", "val0"))
+            .option(option("opt1", "val1"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let expected = OptionsResponse {
+            options: None,
+            option_groups: Some(vec![
+                option_group("group0", vec![option("opt00", "val00")]),
+                option_group("group1", vec![option("opt10", "val10")]),
+            ]),
+        };
+
+        let val = OptionsResponse::builder()
+            .option_group(option_group("group0", vec![option("opt00", "val00")]))
+            .option_group(option_group("group1", vec![option("opt10", "val10")]))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_options_list_size_less_than_100() {
+        let options: Vec<Opt> = (0..101).map(|_| option("opt", "val")).collect();
+
+        let err = OptionsResponse::builder()
+            .options(options)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "OptionsResponse");
+
+        let errors = err.field("options");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
+    }
+
+    #[test]
+    fn it_requires_option_groups_list_size_less_than_100() {
+        let option_groups: Vec<OptGroup> = (0..101)
+            .map(|_| option_group("group", vec![option("opt", "val")]))
+            .collect();
+
+        let err = OptionsResponse::builder()
+            .option_groups(option_groups)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "OptionsResponse");
+
+        let errors = err.field("option_groups");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
+    }
+
+    #[test]
+    fn it_requires_either_options_or_option_groups_is_set() {
+        let err = OptionsResponse::builder().build().unwrap_err();
+        assert_eq!(err.object(), "OptionsResponse");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::EitherRequired(
+            "options",
+            "option_groups"
+        )));
+    }
+
+    #[test]
+    fn it_prevents_from_both_options_or_option_groups_are_set() {
+        let err = OptionsResponse::builder()
+            .option(option("opt", "val"))
+            .option_group(option_group("group", vec![option("opt", "val")]))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "OptionsResponse");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::ExclusiveField(
+            "options",
+            "option_groups"
+        )));
+    }
+}
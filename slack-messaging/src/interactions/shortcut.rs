@@ -0,0 +1,100 @@
+use super::types::{Enterprise, Team, User};
+
+use serde::Deserialize;
+
+/// [`shortcut` payload](https://docs.slack.dev/reference/interaction-payloads/shortcuts-interaction-payload)
+/// representation.
+///
+/// Slack sends this payload to your app when a user invokes a global shortcut. Use `trigger_id`
+/// to open a [`Modal`](crate::Modal).
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::interactions::Shortcut;
+///
+/// let json = serde_json::json!({
+///     "type": "shortcut",
+///     "token": "XXXXXXXXXXXXX",
+///     "action_ts": "1581106241.371594",
+///     "team": { "id": "TXXXXXXXX", "domain": "shortcuts-test" },
+///     "user": { "id": "UXXXXXXXXX", "username": "aman", "team_id": "TXXXXXXXX" },
+///     "callback_id": "shortcut_create_task",
+///     "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"
+/// });
+///
+/// let payload: Shortcut = serde_json::from_value(json).unwrap();
+///
+/// assert_eq!(payload.callback_id, "shortcut_create_task");
+/// assert_eq!(payload.user.id, "UXXXXXXXXX");
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Shortcut {
+    /// The workspace the shortcut was invoked in.
+    pub team: Option<Team>,
+
+    /// The user who invoked the shortcut.
+    pub user: User,
+
+    /// ID of your app.
+    pub api_app_id: Option<String>,
+
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// callback_id of the shortcut configured in your app settings.
+    pub callback_id: String,
+
+    /// Short-lived ID to open a modal.
+    pub trigger_id: String,
+
+    /// Timestamp when the shortcut was invoked.
+    pub action_ts: Option<String>,
+
+    /// The enterprise organization the shortcut was invoked in.
+    pub enterprise: Option<Enterprise>,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "shortcut",
+            "token": "XXXXXXXXXXXXX",
+            "action_ts": "1581106241.371594",
+            "team": {
+                "id": "TXXXXXXXX",
+                "domain": "shortcuts-test"
+            },
+            "user": {
+                "id": "UXXXXXXXXX",
+                "username": "aman",
+                "team_id": "TXXXXXXXX"
+            },
+            "api_app_id": "AXXXXXXXX",
+            "callback_id": "shortcut_create_task",
+            "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638",
+            "is_enterprise_install": false,
+            "enterprise": null
+        });
+
+        let payload: Shortcut = serde_json::from_value(json).unwrap();
+
+        assert_eq!(payload.team.unwrap().id, "TXXXXXXXX");
+        assert_eq!(payload.user.username.as_deref(), Some("aman"));
+        assert_eq!(payload.api_app_id.as_deref(), Some("AXXXXXXXX"));
+        assert_eq!(payload.callback_id, "shortcut_create_task");
+        assert_eq!(
+            payload.trigger_id,
+            "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"
+        );
+        assert_eq!(payload.enterprise, None);
+    }
+}
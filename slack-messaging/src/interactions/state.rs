@@ -0,0 +1,528 @@
+use crate::blocks::RichText;
+use crate::blocks::elements::{
+    Checkboxes, DatePicker, DatetimePicker, EmailInput, FileInput, MultiSelectMenuConversations,
+    MultiSelectMenuExternalDataSource, MultiSelectMenuPublicChannels, MultiSelectMenuStaticOptions,
+    MultiSelectMenuUsers, NumberInput, PlainTextInput, RadioButtonGroup, RichTextInput,
+    SelectMenuConversations, SelectMenuExternalDataSource, SelectMenuPublicChannels,
+    SelectMenuStaticOptions, SelectMenuUsers, TimePicker, UrlInput,
+};
+use crate::composition_objects::{Opt, TextContent};
+use crate::de::DeserializeLenient;
+use crate::errors::StateError;
+
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Deserializer, de::Error};
+use serde_json::{Number, Value};
+use std::collections::HashMap;
+
+/// [State of a view](https://docs.slack.dev/reference/interaction-payloads/view-interactions-payload#view_submission),
+/// holding the values of the input elements keyed by block_id and action_id.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::blocks::elements::{DatePicker, PlainTextInput};
+/// use slack_messaging::errors::StateError;
+/// use slack_messaging::interactions::ViewState;
+/// use chrono::NaiveDate;
+///
+/// let json = serde_json::json!({
+///     "values": {
+///         "date_block": {
+///             "date_action": {
+///                 "type": "datepicker",
+///                 "selected_date": "2024-05-01"
+///             }
+///         },
+///         "title_block": {
+///             "title_action": {
+///                 "type": "plain_text_input",
+///                 "value": "Spring offsite"
+///             }
+///         }
+///     }
+/// });
+///
+/// let state: ViewState = serde_json::from_value(json).unwrap();
+///
+/// let date = state.get::<DatePicker>("date_block", "date_action").unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 5, 1));
+///
+/// let title = state.get::<PlainTextInput>("title_block", "title_action").unwrap();
+/// assert_eq!(title.as_deref(), Some("Spring offsite"));
+///
+/// // Asking for another type of element returns an error.
+/// let err = state.get::<DatePicker>("title_block", "title_action").unwrap_err();
+/// assert!(matches!(err, StateError::TypeMismatch { .. }));
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct ViewState {
+    /// Values of the input elements, keyed by block_id and then action_id.
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, StateValue>>,
+}
+
+impl ViewState {
+    /// Returns the raw value for the pair of block_id and action_id.
+    pub fn value(&self, block_id: &str, action_id: &str) -> Option<&StateValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+
+    /// Returns the typed value of the element `E` for the pair of block_id and action_id.
+    pub fn get<E: StateElement>(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<E::Value, StateError> {
+        let value = self
+            .value(block_id, action_id)
+            .ok_or_else(|| StateError::NotFound {
+                block_id: block_id.into(),
+                action_id: action_id.into(),
+            })?;
+
+        E::extract(value).ok_or_else(|| StateError::TypeMismatch {
+            block_id: block_id.into(),
+            action_id: action_id.into(),
+            expected: E::TYPE,
+            actual: value.r#type().into(),
+        })
+    }
+}
+
+/// Value of an input element in [`ViewState`]. Each variant corresponds to the
+/// [`InputElement`](crate::blocks::InputElement) variant of the same name. The value of an
+/// element type this crate doesn't know, or a value which fails to decode, is kept as raw JSON
+/// value in the [`StateValue::Unknown`] variant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateValue {
+    /// Options checked in [`Checkboxes`] element.
+    Checkboxes(Vec<Opt<TextContent>>),
+
+    /// Date selected in [`DatePicker`] element.
+    DatePicker(Option<NaiveDate>),
+
+    /// UNIX timestamp selected in [`DatetimePicker`] element.
+    DatetimePicker(Option<i64>),
+
+    /// Email address entered in [`EmailInput`] element.
+    EmailInput(Option<String>),
+
+    /// IDs of the files uploaded with [`FileInput`] element.
+    FileInput(Vec<String>),
+
+    /// Options selected in [`MultiSelectMenuStaticOptions`] element.
+    MultiSelectMenuStaticOptions(Vec<Opt>),
+
+    /// Options selected in [`MultiSelectMenuExternalDataSource`] element.
+    MultiSelectMenuExternalDataSource(Vec<Opt>),
+
+    /// IDs of the users selected in [`MultiSelectMenuUsers`] element.
+    MultiSelectMenuUsers(Vec<String>),
+
+    /// IDs of the conversations selected in [`MultiSelectMenuConversations`] element.
+    MultiSelectMenuConversations(Vec<String>),
+
+    /// IDs of the channels selected in [`MultiSelectMenuPublicChannels`] element.
+    MultiSelectMenuPublicChannels(Vec<String>),
+
+    /// Number entered in [`NumberInput`] element.
+    NumberInput(Option<Number>),
+
+    /// Text entered in [`PlainTextInput`] element.
+    PlainTextInput(Option<String>),
+
+    /// Option selected in [`RadioButtonGroup`] element.
+    RadioButtonGroup(Option<Opt<TextContent>>),
+
+    /// Rich text entered in [`RichTextInput`] element.
+    RichTextInput(Option<RichText>),
+
+    /// Option selected in [`SelectMenuStaticOptions`] element.
+    SelectMenuStaticOptions(Option<Opt>),
+
+    /// Option selected in [`SelectMenuExternalDataSource`] element.
+    SelectMenuExternalDataSource(Option<Opt>),
+
+    /// ID of the user selected in [`SelectMenuUsers`] element.
+    SelectMenuUsers(Option<String>),
+
+    /// ID of the conversation selected in [`SelectMenuConversations`] element.
+    SelectMenuConversations(Option<String>),
+
+    /// ID of the channel selected in [`SelectMenuPublicChannels`] element.
+    SelectMenuPublicChannels(Option<String>),
+
+    /// Time selected in [`TimePicker`] element.
+    TimePicker(Option<NaiveTime>),
+
+    /// URL entered in [`UrlInput`] element.
+    UrlInput(Option<String>),
+
+    /// Value of any other element not supported by this crate, or a value which fails to decode.
+    Unknown(Value),
+}
+
+impl StateValue {
+    /// Returns the `type` of the element the value comes from.
+    pub fn r#type(&self) -> &str {
+        match self {
+            Self::Checkboxes(_) => Checkboxes::TYPE,
+            Self::DatePicker(_) => DatePicker::TYPE,
+            Self::DatetimePicker(_) => DatetimePicker::TYPE,
+            Self::EmailInput(_) => EmailInput::TYPE,
+            Self::FileInput(_) => FileInput::TYPE,
+            Self::MultiSelectMenuStaticOptions(_) => MultiSelectMenuStaticOptions::TYPE,
+            Self::MultiSelectMenuExternalDataSource(_) => MultiSelectMenuExternalDataSource::TYPE,
+            Self::MultiSelectMenuUsers(_) => MultiSelectMenuUsers::TYPE,
+            Self::MultiSelectMenuConversations(_) => MultiSelectMenuConversations::TYPE,
+            Self::MultiSelectMenuPublicChannels(_) => MultiSelectMenuPublicChannels::TYPE,
+            Self::NumberInput(_) => NumberInput::TYPE,
+            Self::PlainTextInput(_) => PlainTextInput::TYPE,
+            Self::RadioButtonGroup(_) => RadioButtonGroup::TYPE,
+            Self::RichTextInput(_) => RichTextInput::TYPE,
+            Self::SelectMenuStaticOptions(_) => SelectMenuStaticOptions::TYPE,
+            Self::SelectMenuExternalDataSource(_) => SelectMenuExternalDataSource::TYPE,
+            Self::SelectMenuUsers(_) => SelectMenuUsers::TYPE,
+            Self::SelectMenuConversations(_) => SelectMenuConversations::TYPE,
+            Self::SelectMenuPublicChannels(_) => SelectMenuPublicChannels::TYPE,
+            Self::TimePicker(_) => TimePicker::TYPE,
+            Self::UrlInput(_) => UrlInput::TYPE,
+            Self::Unknown(value) => value
+                .get("type")
+                .and_then(Value::as_str)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StateValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct File {
+            id: String,
+        }
+
+        impl<'de> DeserializeLenient<'de> for File {
+            fn deserialize_lenient<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Self::deserialize(deserializer)
+            }
+        }
+
+        let value = Value::deserialize(deserializer)?;
+        let r#type = value
+            .get("type")
+            .and_then(Value::as_str)
+            .map(String::from)
+            .ok_or_else(|| D::Error::missing_field("type"))?;
+
+        // Values come from Slack, so they are decoded without validations, and a value which
+        // still fails to decode is kept as unknown rather than rejecting the whole payload.
+        let state = match r#type.as_str() {
+            "checkboxes" => list(&value, "selected_options").map(Self::Checkboxes),
+            "datepicker" => field(&value, "selected_date").map(Self::DatePicker),
+            "datetimepicker" => field(&value, "selected_date_time").map(Self::DatetimePicker),
+            "email_text_input" => field(&value, "value").map(Self::EmailInput),
+            "file_input" => list::<File>(&value, "files")
+                .map(|files| Self::FileInput(files.into_iter().map(|file| file.id).collect())),
+            "multi_static_select" => {
+                list(&value, "selected_options").map(Self::MultiSelectMenuStaticOptions)
+            }
+            "multi_external_select" => {
+                list(&value, "selected_options").map(Self::MultiSelectMenuExternalDataSource)
+            }
+            "multi_users_select" => list(&value, "selected_users").map(Self::MultiSelectMenuUsers),
+            "multi_conversations_select" => {
+                list(&value, "selected_conversations").map(Self::MultiSelectMenuConversations)
+            }
+            "multi_channels_select" => {
+                list(&value, "selected_channels").map(Self::MultiSelectMenuPublicChannels)
+            }
+            "number_input" => {
+                crate::de::number_from_str(take(&value, "value")).map(Self::NumberInput)
+            }
+            "plain_text_input" => field(&value, "value").map(Self::PlainTextInput),
+            "radio_buttons" => field(&value, "selected_option").map(Self::RadioButtonGroup),
+            "rich_text_input" => field(&value, "rich_text_value").map(Self::RichTextInput),
+            "static_select" => field(&value, "selected_option").map(Self::SelectMenuStaticOptions),
+            "external_select" => {
+                field(&value, "selected_option").map(Self::SelectMenuExternalDataSource)
+            }
+            "users_select" => field(&value, "selected_user").map(Self::SelectMenuUsers),
+            "conversations_select" => {
+                field(&value, "selected_conversation").map(Self::SelectMenuConversations)
+            }
+            "channels_select" => {
+                field(&value, "selected_channel").map(Self::SelectMenuPublicChannels)
+            }
+            "timepicker" => field(&value, "selected_time").map(Self::TimePicker),
+            "url_text_input" => field(&value, "value").map(Self::UrlInput),
+            _ => return Ok(Self::Unknown(value)),
+        };
+
+        Ok(state.unwrap_or(Self::Unknown(value)))
+    }
+}
+
+fn take(value: &Value, name: &str) -> Value {
+    value.get(name).cloned().unwrap_or_default()
+}
+
+fn field<T>(value: &Value, name: &str) -> Result<T, serde_json::Error>
+where
+    T: for<'de> DeserializeLenient<'de>,
+{
+    T::deserialize_lenient(take(value, name))
+}
+
+fn list<T>(value: &Value, name: &str) -> Result<Vec<T>, serde_json::Error>
+where
+    T: for<'de> DeserializeLenient<'de>,
+{
+    field::<Option<Vec<T>>>(value, name).map(Option::unwrap_or_default)
+}
+
+/// Block elements whose value can be extracted from [`ViewState`] by
+/// [`ViewState::get`] method.
+pub trait StateElement {
+    /// The `type` of the element.
+    const TYPE: &'static str;
+
+    /// Type of the value the element holds.
+    type Value;
+
+    /// Extracts the value from [`StateValue`]. Returns None if the value comes from another type
+    /// of element.
+    fn extract(value: &StateValue) -> Option<Self::Value>;
+}
+
+macro_rules! impl_state_element {
+    ($($element:ident => $ty:literal, $value:ty;)*) => {
+        $(
+            impl StateElement for $element {
+                const TYPE: &'static str = $ty;
+
+                type Value = $value;
+
+                fn extract(value: &StateValue) -> Option<Self::Value> {
+                    if let StateValue::$element(v) = value {
+                        Some(v.clone())
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_state_element! {
+    Checkboxes => "checkboxes", Vec<Opt<TextContent>>;
+    DatePicker => "datepicker", Option<NaiveDate>;
+    DatetimePicker => "datetimepicker", Option<i64>;
+    EmailInput => "email_text_input", Option<String>;
+    FileInput => "file_input", Vec<String>;
+    MultiSelectMenuStaticOptions => "multi_static_select", Vec<Opt>;
+    MultiSelectMenuExternalDataSource => "multi_external_select", Vec<Opt>;
+    MultiSelectMenuUsers => "multi_users_select", Vec<String>;
+    MultiSelectMenuConversations => "multi_conversations_select", Vec<String>;
+    MultiSelectMenuPublicChannels => "multi_channels_select", Vec<String>;
+    NumberInput => "number_input", Option<Number>;
+    PlainTextInput => "plain_text_input", Option<String>;
+    RadioButtonGroup => "radio_buttons", Option<Opt<TextContent>>;
+    RichTextInput => "rich_text_input", Option<RichText>;
+    SelectMenuStaticOptions => "static_select", Option<Opt>;
+    SelectMenuExternalDataSource => "external_select", Option<Opt>;
+    SelectMenuUsers => "users_select", Option<String>;
+    SelectMenuConversations => "conversations_select", Option<String>;
+    SelectMenuPublicChannels => "channels_select", Option<String>;
+    TimePicker => "timepicker", Option<NaiveTime>;
+    UrlInput => "url_text_input", Option<String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition_objects::test_helpers::*;
+
+    fn state() -> ViewState {
+        let json = serde_json::json!({
+            "values": {
+                "block_0": {
+                    "text": { "type": "plain_text_input", "value": "Hello" },
+                    "number": { "type": "number_input", "value": "3.5" },
+                    "date": { "type": "datepicker", "selected_date": null },
+                    "time": { "type": "timepicker", "selected_time": "09:15" }
+                },
+                "block_1": {
+                    "select": {
+                        "type": "static_select",
+                        "selected_option": {
+                            "text": { "type": "plain_text", "text": "Glinda" },
+                            "value": "glinda"
+                        }
+                    },
+                    "users": { "type": "multi_users_select", "selected_users": ["U01", "U02"] },
+                    "checkboxes": { "type": "checkboxes", "selected_options": [] },
+                    "files": {
+                        "type": "file_input",
+                        "files": [
+                            { "id": "F0123", "name": "foo.png" },
+                            { "id": "F4567", "name": "bar.png" }
+                        ]
+                    },
+                    "rich_text": {
+                        "type": "rich_text_input",
+                        "rich_text_value": {
+                            "type": "rich_text",
+                            "elements": [
+                                {
+                                    "type": "rich_text_section",
+                                    "elements": [{ "type": "text", "text": "Hi" }]
+                                }
+                            ]
+                        }
+                    },
+                    "future": { "type": "some_future_input", "value": "foo" }
+                }
+            }
+        });
+
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn it_gets_typed_values() {
+        let state = state();
+
+        assert_eq!(
+            state.get::<PlainTextInput>("block_0", "text"),
+            Ok(Some("Hello".into()))
+        );
+        assert_eq!(
+            state.get::<NumberInput>("block_0", "number"),
+            Ok(Number::from_f64(3.5))
+        );
+        assert_eq!(state.get::<DatePicker>("block_0", "date"), Ok(None));
+        assert_eq!(
+            state.get::<TimePicker>("block_0", "time"),
+            Ok(NaiveTime::from_hms_opt(9, 15, 0))
+        );
+        assert_eq!(
+            state.get::<SelectMenuStaticOptions>("block_1", "select"),
+            Ok(Some(option("Glinda", "glinda")))
+        );
+        assert_eq!(
+            state.get::<MultiSelectMenuUsers>("block_1", "users"),
+            Ok(vec!["U01".into(), "U02".into()])
+        );
+        assert_eq!(state.get::<Checkboxes>("block_1", "checkboxes"), Ok(vec![]));
+        assert_eq!(
+            state.get::<FileInput>("block_1", "files"),
+            Ok(vec!["F0123".into(), "F4567".into()])
+        );
+        assert!(
+            state
+                .get::<RichTextInput>("block_1", "rich_text")
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn it_keeps_unknown_values_as_json_value() {
+        let state = state();
+        let value = state.value("block_1", "future").unwrap();
+
+        assert_eq!(value.r#type(), "some_future_input");
+        assert!(matches!(value, StateValue::Unknown(_)));
+    }
+
+    #[test]
+    fn it_decodes_values_without_validations() {
+        let text = "a".repeat(76);
+        let json = serde_json::json!({
+            "values": {
+                "block_0": {
+                    "select": {
+                        "type": "static_select",
+                        "selected_option": {
+                            "text": { "type": "plain_text", "text": text },
+                            "value": "long"
+                        }
+                    }
+                }
+            }
+        });
+        let state: ViewState = serde_json::from_value(json).unwrap();
+
+        let option = state
+            .get::<SelectMenuStaticOptions>("block_0", "select")
+            .unwrap()
+            .unwrap();
+        assert_eq!(option.text.unwrap().text, Some(text));
+    }
+
+    #[test]
+    fn it_keeps_undecodable_values_of_known_elements_as_json_value() {
+        let json = serde_json::json!({
+            "values": {
+                "block_0": {
+                    "rich_text": {
+                        "type": "rich_text_input",
+                        "rich_text_value": {
+                            "type": "rich_text",
+                            "elements": [
+                                {
+                                    "type": "rich_text_section",
+                                    "elements": [{ "type": "team", "team_id": "T0123" }]
+                                }
+                            ]
+                        }
+                    }
+                }
+            }
+        });
+        let state: ViewState = serde_json::from_value(json).unwrap();
+        let value = state.value("block_0", "rich_text").unwrap();
+
+        assert_eq!(value.r#type(), "rich_text_input");
+        assert!(matches!(value, StateValue::Unknown(_)));
+    }
+
+    #[test]
+    fn it_returns_error_if_value_is_not_found() {
+        let err = state()
+            .get::<PlainTextInput>("block_0", "missing")
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            StateError::NotFound {
+                block_id: "block_0".into(),
+                action_id: "missing".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_returns_error_if_element_type_does_not_match() {
+        let err = state().get::<DatePicker>("block_0", "text").unwrap_err();
+
+        assert_eq!(
+            err,
+            StateError::TypeMismatch {
+                block_id: "block_0".into(),
+                action_id: "text".into(),
+                expected: "datepicker",
+                actual: "plain_text_input".into(),
+            }
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use super::ViewState;
+use crate::blocks::Block;
+use crate::composition_objects::{Plain, Text};
+
+use serde::Deserialize;
+
+/// The user who triggered the interaction.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct User {
+    /// ID of the user.
+    pub id: String,
+    /// Username of the user.
+    pub username: Option<String>,
+    /// Name of the user.
+    pub name: Option<String>,
+    /// ID of the workspace the user belongs to.
+    pub team_id: Option<String>,
+}
+
+/// The workspace the interaction happened in.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Team {
+    /// ID of the workspace.
+    pub id: String,
+    /// Domain of the workspace.
+    pub domain: Option<String>,
+    /// ID of the enterprise organization the workspace belongs to.
+    pub enterprise_id: Option<String>,
+    /// Name of the enterprise organization the workspace belongs to.
+    pub enterprise_name: Option<String>,
+}
+
+/// The enterprise organization the interaction happened in.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Enterprise {
+    /// ID of the enterprise organization.
+    pub id: String,
+    /// Name of the enterprise organization.
+    pub name: Option<String>,
+}
+
+/// The channel the interaction happened in.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Channel {
+    /// ID of the channel.
+    pub id: String,
+    /// Name of the channel.
+    pub name: Option<String>,
+}
+
+/// The source of the interaction, either a message, a view or a message attachment.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Container {
+    /// The interaction happened in a message.
+    Message {
+        /// Timestamp of the message.
+        message_ts: String,
+        /// ID of the channel the message was posted in.
+        channel_id: Option<String>,
+        /// Whether the message is ephemeral or not.
+        #[serde(default)]
+        is_ephemeral: bool,
+        /// Timestamp of the parent message if the message is in a thread.
+        thread_ts: Option<String>,
+    },
+
+    /// The interaction happened in a view such as a modal or the Home tab.
+    View {
+        /// ID of the view.
+        view_id: String,
+    },
+
+    /// The interaction happened in a message attachment.
+    MessageAttachment {
+        /// Timestamp of the message.
+        message_ts: String,
+        /// ID of the attachment.
+        attachment_id: u32,
+        /// ID of the channel the message was posted in.
+        channel_id: Option<String>,
+        /// Whether the message is ephemeral or not.
+        #[serde(default)]
+        is_ephemeral: bool,
+        /// Whether the message is an app unfurl or not.
+        #[serde(default)]
+        is_app_unfurl: bool,
+    },
+
+    /// Any other container not supported by this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The message containing the block the user interacted with.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct OriginalMessage {
+    /// Timestamp of the message.
+    pub ts: String,
+    /// Text of the message.
+    pub text: Option<String>,
+    /// ID of the user who posted the message.
+    pub user: Option<String>,
+    /// ID of the bot which posted the message.
+    pub bot_id: Option<String>,
+    /// ID of the app which posted the message.
+    pub app_id: Option<String>,
+    /// ID of the workspace the message was posted in.
+    pub team: Option<String>,
+    /// Timestamp of the parent message if the message is in a thread.
+    pub thread_ts: Option<String>,
+    /// Blocks of the message. Blocks are not validated, and the ones this crate does not
+    /// support are kept as [`Block::Unknown`].
+    #[serde(default, deserialize_with = "crate::de::lenient")]
+    pub blocks: Vec<Block>,
+}
+
+/// The view the interaction happened in, such as a modal or the Home tab.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ViewPayload {
+    /// ID of the view.
+    pub id: String,
+    /// ID of the workspace the view belongs to.
+    pub team_id: Option<String>,
+    /// Type of the view, `modal` or `home`.
+    pub r#type: String,
+    /// Identifier of the view set by your app.
+    pub callback_id: Option<String>,
+    /// Unique identifier of the view set by your app.
+    pub external_id: Option<String>,
+    /// String your app set to the view to pass data along.
+    pub private_metadata: Option<String>,
+    /// Hash of the view used to prevent race conditions on updating it.
+    pub hash: Option<String>,
+    /// ID of the root view of the view stack.
+    pub root_view_id: Option<String>,
+    /// ID of the previous view in the view stack.
+    pub previous_view_id: Option<String>,
+    /// ID of the app which opened the view.
+    pub app_id: Option<String>,
+    /// ID of the bot user of the app which opened the view.
+    pub bot_id: Option<String>,
+    /// Title of the view.
+    #[serde(default, deserialize_with = "crate::de::lenient")]
+    pub title: Option<Text<Plain>>,
+    /// Blocks of the view. Blocks are not validated, and the ones this crate does not support
+    /// are kept as [`Block::Unknown`].
+    #[serde(default, deserialize_with = "crate::de::lenient")]
+    pub blocks: Vec<Block>,
+    /// Values of the input elements in the view.
+    #[serde(default)]
+    pub state: ViewState,
+}
+
+/// URL to post a message to the channel the user selected in the view, included in
+/// [`ViewSubmission`](super::ViewSubmission) payload.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ResponseUrlInfo {
+    /// block_id of the block containing the conversation select menu.
+    pub block_id: String,
+    /// action_id of the conversation select menu.
+    pub action_id: String,
+    /// ID of the channel the user selected.
+    pub channel_id: String,
+    /// URL to post a message to the channel.
+    pub response_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_message_container() {
+        let json = serde_json::json!({
+            "type": "message",
+            "message_ts": "1548261231.000200",
+            "channel_id": "CBR2V3XEX",
+            "is_ephemeral": false
+        });
+
+        let expected = Container::Message {
+            message_ts: "1548261231.000200".into(),
+            channel_id: Some("CBR2V3XEX".into()),
+            is_ephemeral: false,
+            thread_ts: None,
+        };
+
+        let container: Container = serde_json::from_value(json).unwrap();
+        assert_eq!(container, expected);
+    }
+
+    #[test]
+    fn it_deserializes_view_container() {
+        let json = serde_json::json!({
+            "type": "view",
+            "view_id": "V0PKB1ZFV"
+        });
+
+        let expected = Container::View {
+            view_id: "V0PKB1ZFV".into(),
+        };
+
+        let container: Container = serde_json::from_value(json).unwrap();
+        assert_eq!(container, expected);
+    }
+
+    #[test]
+    fn it_deserializes_unknown_container() {
+        let json = serde_json::json!({
+            "type": "something_new",
+            "id": "foo"
+        });
+
+        let container: Container = serde_json::from_value(json).unwrap();
+        assert_eq!(container, Container::Unknown);
+    }
+}
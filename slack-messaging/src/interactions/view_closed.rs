@@ -0,0 +1,65 @@
+use super::types::{Enterprise, Team, User, ViewPayload};
+
+use serde::Deserialize;
+
+/// [`view_closed` payload](https://docs.slack.dev/reference/interaction-payloads/view-interactions-payload#view_closed)
+/// representation.
+///
+/// Slack sends this payload to your app when a user closes a modal whose `notify_on_close` is
+/// set to true.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ViewClosed {
+    /// The workspace the interaction happened in.
+    pub team: Option<Team>,
+
+    /// The user who closed the view.
+    pub user: User,
+
+    /// ID of your app.
+    pub api_app_id: String,
+
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// The enterprise organization the interaction happened in.
+    pub enterprise: Option<Enterprise>,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+
+    /// The closed view.
+    pub view: ViewPayload,
+
+    /// Whether the whole view stack is cleared or not.
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "view_closed",
+            "team": { "id": "TXXXXXX", "domain": "coverbands" },
+            "user": { "id": "UXXXXXX", "name": "dreamweaver" },
+            "api_app_id": "AXXXXXX",
+            "view": {
+                "id": "VNHU13V36",
+                "type": "modal",
+                "callback_id": "modal-identifier",
+                "state": { "values": {} }
+            },
+            "is_cleared": false
+        });
+
+        let payload: ViewClosed = serde_json::from_value(json).unwrap();
+
+        assert_eq!(payload.user.id, "UXXXXXX");
+        assert_eq!(payload.view.id, "VNHU13V36");
+        assert!(!payload.is_cleared);
+    }
+}
@@ -0,0 +1,216 @@
+use super::types::{Enterprise, ResponseUrlInfo, Team, User, ViewPayload};
+
+use serde::Deserialize;
+
+/// [`view_submission` payload](https://docs.slack.dev/reference/interaction-payloads/view-interactions-payload#view_submission)
+/// representation.
+///
+/// Slack sends this payload to your app when a user submits a modal. The values of the input
+/// elements are available from the `state` field of the view.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::blocks::elements::{PlainTextInput, SelectMenuUsers};
+/// use slack_messaging::interactions::ViewSubmission;
+///
+/// let json = serde_json::json!({
+///     "type": "view_submission",
+///     "team": { "id": "T0MJRM1A7", "domain": "pandamonium" },
+///     "user": { "id": "U0MJRG1AL", "name": "aaron" },
+///     "api_app_id": "A0MJRG1AM",
+///     "trigger_id": "12466734323.1395872398",
+///     "view": {
+///         "id": "VNHU13V36",
+///         "type": "modal",
+///         "callback_id": "ticket_modal",
+///         "private_metadata": "shhh-its-secret",
+///         "state": {
+///             "values": {
+///                 "title_block": {
+///                     "title_input": {
+///                         "type": "plain_text_input",
+///                         "value": "Printer is on fire"
+///                     }
+///                 },
+///                 "assignee_block": {
+///                     "assignee_select": {
+///                         "type": "users_select",
+///                         "selected_user": "U0MJRG1AL"
+///                     }
+///                 }
+///             }
+///         },
+///         "hash": "156663117.cd33ad1f"
+///     },
+///     "response_urls": []
+/// });
+///
+/// let payload: ViewSubmission = serde_json::from_value(json).unwrap();
+/// let state = &payload.view.state;
+///
+/// let title = state.get::<PlainTextInput>("title_block", "title_input").unwrap();
+/// assert_eq!(title.as_deref(), Some("Printer is on fire"));
+///
+/// let assignee = state.get::<SelectMenuUsers>("assignee_block", "assignee_select").unwrap();
+/// assert_eq!(assignee.as_deref(), Some("U0MJRG1AL"));
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ViewSubmission {
+    /// The workspace the interaction happened in.
+    pub team: Option<Team>,
+
+    /// The user who submitted the view.
+    pub user: User,
+
+    /// ID of your app.
+    pub api_app_id: String,
+
+    /// Deprecated verification token.
+    pub token: Option<String>,
+
+    /// Short-lived ID to open a modal.
+    pub trigger_id: Option<String>,
+
+    /// The enterprise organization the interaction happened in.
+    pub enterprise: Option<Enterprise>,
+
+    /// Whether your app is installed to the enterprise organization or not.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+
+    /// The submitted view.
+    pub view: ViewPayload,
+
+    /// URLs to post messages to the channels the user selected in the view.
+    #[serde(default)]
+    pub response_urls: Vec<ResponseUrlInfo>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::Block;
+    use crate::blocks::elements::{DatePicker, SelectMenuConversations};
+    use crate::interactions::Interaction;
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "type": "view_submission",
+            "team": { "id": "T0MJRM1A7", "domain": "pandamonium" },
+            "user": { "id": "U0MJRG1AL", "name": "aaron" },
+            "api_app_id": "A0MJRG1AM",
+            "token": "deprecated",
+            "trigger_id": "12466734323.1395872398",
+            "view": {
+                "id": "VNHU13V36",
+                "team_id": "T0MJRM1A7",
+                "type": "modal",
+                "title": { "type": "plain_text", "text": "Schedule" },
+                "blocks": [
+                    {
+                        "type": "input",
+                        "block_id": "date_block",
+                        "label": { "type": "plain_text", "text": "Date" },
+                        "element": { "type": "datepicker", "action_id": "date_action" }
+                    },
+                    {
+                        "type": "input",
+                        "block_id": "channel_block",
+                        "label": { "type": "plain_text", "text": "Channel" },
+                        "element": {
+                            "type": "conversations_select",
+                            "action_id": "channel_action",
+                            "response_url_enabled": true
+                        }
+                    }
+                ],
+                "private_metadata": "",
+                "callback_id": "schedule_modal",
+                "state": {
+                    "values": {
+                        "date_block": {
+                            "date_action": { "type": "datepicker", "selected_date": "2024-05-01" }
+                        },
+                        "channel_block": {
+                            "channel_action": {
+                                "type": "conversations_select",
+                                "selected_conversation": "C0123"
+                            }
+                        }
+                    }
+                },
+                "hash": "156663117.cd33ad1f",
+                "root_view_id": "VNHU13V36",
+                "previous_view_id": null,
+                "app_id": "A0MJRG1AM",
+                "bot_id": "B0MJRG1AN"
+            },
+            "response_urls": [
+                {
+                    "block_id": "channel_block",
+                    "action_id": "channel_action",
+                    "channel_id": "C0123",
+                    "response_url": "https://hooks.slack.com/app/T0MJRM1A7/1234/abcd"
+                }
+            ]
+        });
+
+        let payload: ViewSubmission = serde_json::from_value(json).unwrap();
+
+        assert_eq!(payload.user.id, "U0MJRG1AL");
+        assert_eq!(payload.view.callback_id.as_deref(), Some("schedule_modal"));
+        assert_eq!(payload.view.blocks.len(), 2);
+        assert_eq!(
+            payload
+                .view
+                .state
+                .get::<DatePicker>("date_block", "date_action"),
+            Ok(chrono::NaiveDate::from_ymd_opt(2024, 5, 1))
+        );
+        assert_eq!(
+            payload
+                .view
+                .state
+                .get::<SelectMenuConversations>("channel_block", "channel_action"),
+            Ok(Some("C0123".into()))
+        );
+        assert_eq!(payload.response_urls.len(), 1);
+        assert_eq!(payload.response_urls[0].channel_id, "C0123");
+    }
+
+    #[test]
+    fn it_keeps_blocks_of_the_view_slack_sends_as_is() {
+        let unknown = serde_json::json!({ "type": "future_block", "block_id": "future" });
+        let json = serde_json::json!({
+            "type": "view_submission",
+            "user": { "id": "U0MJRG1AL" },
+            "api_app_id": "A0MJRG1AM",
+            "trigger_id": "12466734323.1395872398",
+            "view": {
+                "id": "VNHU13V36",
+                "type": "modal",
+                "title": { "type": "plain_text", "text": "a".repeat(25) },
+                "blocks": [
+                    unknown.clone(),
+                    {
+                        "type": "input",
+                        "block_id": "note_block",
+                        "label": { "type": "plain_text", "text": "a".repeat(2001) },
+                        "element": { "type": "plain_text_input", "action_id": "note_action" }
+                    }
+                ],
+                "state": { "values": {} }
+            }
+        });
+
+        let Interaction::ViewSubmission(payload) = serde_json::from_value(json).unwrap() else {
+            panic!("expected view_submission payload");
+        };
+
+        assert_eq!(payload.view.blocks[0], Block::Unknown(unknown));
+        assert!(matches!(payload.view.blocks[1], Block::Input(_)));
+        assert!(payload.view.title.is_some());
+    }
+}
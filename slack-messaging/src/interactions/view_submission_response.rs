@@ -0,0 +1,293 @@
+use super::types::ViewPayload;
+use crate::Modal;
+use crate::blocks::Block;
+use crate::errors::{ValidationError, ValidationErrorKind, ValidationErrors};
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// [Response](https://docs.slack.dev/surfaces/modals#updating_response)
+/// to [`ViewSubmission`](super::ViewSubmission) payload.
+///
+/// Each variant is serialized with the `response_action` field Slack expects.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::interactions::{ViewSubmission, ViewSubmissionResponse};
+///
+/// let json = serde_json::json!({
+///     "type": "view_submission",
+///     "user": { "id": "U0MJRG1AL" },
+///     "api_app_id": "A0MJRG1AM",
+///     "view": {
+///         "id": "VNHU13V36",
+///         "type": "modal",
+///         "blocks": [
+///             {
+///                 "type": "input",
+///                 "block_id": "ticket_title",
+///                 "label": { "type": "plain_text", "text": "Title" },
+///                 "element": { "type": "plain_text_input", "action_id": "title" }
+///             }
+///         ]
+///     }
+/// });
+/// let payload: ViewSubmission = serde_json::from_value(json).unwrap();
+///
+/// let response = ViewSubmissionResponse::errors(
+///     &payload.view,
+///     [("ticket_title", "Title must be shorter than 20 characters")],
+/// )
+/// .unwrap();
+///
+/// let expected = serde_json::json!({
+///     "response_action": "errors",
+///     "errors": {
+///         "ticket_title": "Title must be shorter than 20 characters"
+///     }
+/// });
+///
+/// assert_eq!(serde_json::to_value(response).unwrap(), expected);
+///
+/// // block_id which is not an input block of the view is rejected.
+/// let response = ViewSubmissionResponse::errors(&payload.view, [("unknown", "oops")]);
+///
+/// assert!(response.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewSubmissionResponse {
+    /// Displays error messages next to the input blocks, keyed by their block_id.
+    Errors(HashMap<String, String>),
+
+    /// Updates the submitted view with the modal.
+    Update(Box<Modal>),
+
+    /// Pushes the modal on top of the view stack.
+    Push(Box<Modal>),
+
+    /// Closes all views in the view stack.
+    Clear,
+}
+
+impl ViewSubmissionResponse {
+    /// Constructs [`ViewSubmissionResponse::Errors`] after checking every key is the block_id of
+    /// an [Input](crate::blocks::Input) block in the submitted view, including input blocks kept
+    /// as [`Block::Unknown`].
+    pub fn errors<K, V>(
+        view: &ViewPayload,
+        errors: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, ValidationErrors>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let errors: HashMap<String, String> = errors
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+
+        let input_block_ids: Vec<&str> = view
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::Input(input) => input.block_id.as_deref(),
+                Block::Unknown(value) if value.get("type")? == "input" => {
+                    value.get("block_id")?.as_str()
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut unknown: Vec<&String> = errors
+            .keys()
+            .filter(|block_id| !input_block_ids.contains(&block_id.as_str()))
+            .collect();
+        unknown.sort();
+
+        if unknown.is_empty() {
+            Ok(Self::Errors(errors))
+        } else {
+            Err(ValidationErrors {
+                object: Cow::Borrowed("ViewSubmissionResponse"),
+                errors: unknown
+                    .into_iter()
+                    .map(|block_id| ValidationError::SingleField {
+                        field: Cow::Owned(block_id.clone()),
+                        errors: vec![ValidationErrorKind::InputBlockNotFound],
+                    })
+                    .collect(),
+            })
+        }
+    }
+
+    /// Constructs [`ViewSubmissionResponse::Update`].
+    pub fn update(view: Modal) -> Self {
+        Self::Update(Box::new(view))
+    }
+
+    /// Constructs [`ViewSubmissionResponse::Push`].
+    pub fn push(view: Modal) -> Self {
+        Self::Push(Box::new(view))
+    }
+}
+
+impl Serialize for ViewSubmissionResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Errors(errors) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("response_action", "errors")?;
+                map.serialize_entry("errors", errors)?;
+                map.end()
+            }
+            Self::Update(view) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("response_action", "update")?;
+                map.serialize_entry("view", view)?;
+                map.end()
+            }
+            Self::Push(view) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("response_action", "push")?;
+                map.serialize_entry("view", view)?;
+                map.end()
+            }
+            Self::Clear => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("response_action", "clear")?;
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_serializes_errors() {
+        let response = ViewSubmissionResponse::errors(&view(), [("input_0", "invalid")]).unwrap();
+
+        let expected = serde_json::json!({
+            "response_action": "errors",
+            "errors": {
+                "input_0": "invalid"
+            }
+        });
+
+        assert_eq!(serde_json::to_value(response).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_rejects_errors_for_unknown_block_ids() {
+        let err = ViewSubmissionResponse::errors(
+            &view(),
+            [
+                ("input_0", "invalid"),
+                ("section_0", "not an input"),
+                ("unknown", "not exist"),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(err.object(), "ViewSubmissionResponse");
+        assert_eq!(err.errors().len(), 2);
+
+        assert!(
+            err.field("section_0")
+                .includes(ValidationErrorKind::InputBlockNotFound)
+        );
+        assert!(
+            err.field("unknown")
+                .includes(ValidationErrorKind::InputBlockNotFound)
+        );
+    }
+
+    #[test]
+    fn it_accepts_errors_for_input_blocks_kept_as_unknown() {
+        let view: ViewPayload = serde_json::from_value(serde_json::json!({
+            "id": "V0",
+            "type": "modal",
+            "blocks": [
+                {
+                    "type": "input",
+                    "block_id": "input_1",
+                    "label": { "type": "plain_text", "text": "Future" },
+                    "element": { "type": "some_future_input", "action_id": "future" }
+                }
+            ]
+        }))
+        .unwrap();
+        assert!(matches!(view.blocks[0], Block::Unknown(_)));
+
+        let response = ViewSubmissionResponse::errors(&view, [("input_1", "invalid")]).unwrap();
+        assert_eq!(
+            response,
+            ViewSubmissionResponse::Errors(HashMap::from([("input_1".into(), "invalid".into())]))
+        );
+    }
+
+    #[test]
+    fn it_serializes_update() {
+        let response = ViewSubmissionResponse::update(modal());
+
+        let expected = serde_json::json!({
+            "response_action": "update",
+            "view": {
+                "type": "modal",
+                "title": { "type": "plain_text", "text": "My App" },
+                "blocks": [
+                    {
+                        "type": "section",
+                        "text": { "type": "mrkdwn", "text": "Updated" }
+                    }
+                ]
+            }
+        });
+
+        assert_eq!(serde_json::to_value(response).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_serializes_push() {
+        let response = ViewSubmissionResponse::push(modal());
+
+        let json = serde_json::to_value(response).unwrap();
+
+        assert_eq!(json["response_action"], "push");
+        assert_eq!(json["view"]["type"], "modal");
+    }
+
+    #[test]
+    fn it_serializes_clear() {
+        let json = serde_json::to_value(ViewSubmissionResponse::Clear).unwrap();
+        assert_eq!(json, serde_json::json!({ "response_action": "clear" }));
+    }
+
+    fn view() -> ViewPayload {
+        let mut section = section("foo");
+        section.block_id = Some("section_0".into());
+
+        serde_json::from_value(serde_json::json!({
+            "id": "V0",
+            "type": "modal",
+            "blocks": [
+                serde_json::to_value(section).unwrap(),
+                serde_json::to_value(input()).unwrap(),
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn modal() -> Modal {
+        Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("Updated"))
+            .build()
+            .unwrap()
+    }
+}
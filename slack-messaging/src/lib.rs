@@ -3,15 +3,65 @@
 #[macro_use]
 mod macros;
 
+/// Extractors and responses to serve requests from Slack with axum.
+#[cfg(feature = "axum")]
+pub mod axum;
 /// Objects from that [`Message`] is composed.
 pub mod blocks;
+/// Payloads Slack sends to your app when users invoke slash commands.
+pub mod commands;
 /// Objects can be used inside of block elements.
 pub mod composition_objects;
 /// Error types used in this crate.
 pub mod errors;
+/// Payloads the Events API sends to your app.
+pub mod events;
+/// Payloads Slack sends to your app when users interact with it.
+pub mod interactions;
+/// Outbound message queue pacing messages per channel.
+#[cfg(feature = "queue")]
+pub mod queue;
+/// Request bodies of Slack Web API methods.
+pub mod requests;
+/// Responses through the `response_url` of interactions and slash commands.
+pub mod response_url;
+/// Client receiving events, interactions and slash commands over Socket Mode.
+#[cfg(feature = "socket-mode")]
+pub mod socket_mode;
+/// Verification of requests Slack sends to your app.
+#[cfg(feature = "verify")]
+pub mod verify;
+/// Client of the Slack Web API for the messaging and views methods.
+#[cfg(feature = "web-api")]
+pub mod web_api;
+/// Client to post messages to incoming webhooks.
+#[cfg(feature = "webhook")]
+pub mod webhook;
 
+mod attachment;
+mod de;
+mod home_view;
 mod message;
+mod metadata;
+mod modal;
+#[cfg(any(feature = "webhook", feature = "web-api"))]
+mod retry;
+mod surface;
+#[cfg(all(
+    test,
+    any(feature = "response-url", feature = "webhook", feature = "web-api")
+))]
+mod test_server;
+mod validate;
 mod validators;
 mod value;
 
-pub use message::{Message, MessageBuilder};
+pub use attachment::{Attachment, AttachmentBuilder, AttachmentField, AttachmentFieldBuilder};
+pub use home_view::{HomeView, HomeViewBuilder};
+pub use message::{Message, MessageBuilder, ResponseType};
+pub use metadata::{MessageMetadata, MessageMetadataBuilder};
+pub use modal::{Modal, ModalBuilder};
+#[cfg(any(feature = "webhook", feature = "web-api"))]
+pub use retry::RetryPolicy;
+pub use surface::Surface;
+pub use validate::Validate;
@@ -7,6 +7,93 @@ macro_rules! pipe {
     }};
 }
 
+// Implements Deserialize for an enum whose variants are distinguished by the `type` field of the
+// JSON object. The matched variant is deserialized from the whole object, so that it can check
+// the `type` field by itself. An optional `_ => Variant` arm keeps an object of unknown type as
+// raw JSON value instead of failing.
+//
+// Without the `_ => Variant` arm, DeserializeLenient is implemented as well, deserializing the
+// matched variant leniently. With `_ if lenient => Variant`, an object of unknown type or that
+// fails to deserialize is kept as raw JSON only by DeserializeLenient.
+macro_rules! deserialize_by_type {
+    ($ty:ty { $($($tag:literal)|+ => $variant:ident,)* _ if lenient => $fallback:ident, }) => {
+        deserialize_by_type!(@impl $ty, value, other, { $($($tag)|+ => $variant,)* }, {
+            Err(D::Error::unknown_variant(other, &[$($($tag),+),*]))
+        });
+        deserialize_by_type!(@lenient $ty, { $($($tag)|+ => $variant,)* }, $fallback);
+    };
+    ($ty:ty { $($($tag:literal)|+ => $variant:ident,)* _ => $fallback:ident, }) => {
+        deserialize_by_type!(@impl $ty, value, _other, { $($($tag)|+ => $variant,)* }, {
+            Ok(Self::$fallback(value))
+        });
+    };
+    ($ty:ty { $($($tag:literal)|+ => $variant:ident,)* }) => {
+        deserialize_by_type!(@impl $ty, value, other, { $($($tag)|+ => $variant,)* }, {
+            Err(D::Error::unknown_variant(other, &[$($($tag),+),*]))
+        });
+        deserialize_by_type!(@lenient $ty, { $($($tag)|+ => $variant,)* });
+    };
+    (@lenient $ty:ty, { $($($tag:literal)|+ => $variant:ident,)* } $(, $fallback:ident)?) => {
+        impl<'de> crate::de::DeserializeLenient<'de> for $ty {
+            fn deserialize_lenient<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                use ::serde::de::Error;
+
+                let value = <::serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+                let result = match value.get("type").and_then(::serde_json::Value::as_str) {
+                    $(
+                        $(::std::option::Option::Some($tag))|+ => {
+                            <crate::de::Lenient<_> as ::serde::Deserialize>::deserialize(&value)
+                                .map(|v| Self::$variant(v.0))
+                        }
+                    )*
+                    ::std::option::Option::Some(other) => ::std::result::Result::Err(
+                        ::serde_json::Error::unknown_variant(other, &[$($($tag),+),*]),
+                    ),
+                    ::std::option::Option::None => ::std::result::Result::Err(
+                        ::serde_json::Error::missing_field("type"),
+                    ),
+                };
+
+                $(
+                    if result.is_err() {
+                        return ::std::result::Result::Ok(Self::$fallback(value));
+                    }
+                )?
+                result.map_err(D::Error::custom)
+            }
+        }
+    };
+    (@impl $ty:ty, $value:ident, $other:ident, { $($($tag:literal)|+ => $variant:ident,)* }, $fallback:block) => {
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                use ::serde::de::Error;
+
+                let $value = ::serde_json::Value::deserialize(deserializer)?;
+                let r#type = $value
+                    .get("type")
+                    .and_then(::serde_json::Value::as_str)
+                    .map(::std::string::String::from)
+                    .ok_or_else(|| D::Error::missing_field("type"))?;
+
+                match r#type.as_str() {
+                    $(
+                        $($tag)|+ => ::serde_json::from_value($value)
+                            .map(Self::$variant)
+                            .map_err(D::Error::custom),
+                    )*
+                    $other => $fallback,
+                }
+            }
+        }
+    };
+}
+
 /// Shorthand to build [`Text`](crate::composition_objects::Text) object with `type` set to
 /// `plain_text`.
 ///
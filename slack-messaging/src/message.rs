@@ -1,7 +1,10 @@
+use crate::MessageMetadata;
+use crate::attachment::Attachment;
 use crate::blocks::Block;
+use crate::errors::ValidationErrorKind;
 use crate::validators::*;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use slack_messaging_derive::Builder;
 
 /// [`Message`](https://docs.slack.dev/messaging#payloads)
@@ -17,6 +20,10 @@ use slack_messaging_derive::Builder;
 /// you can include up to 50 blocks in each message. If you include more than 50
 /// blocks in a message, the build method of [MessageBuilder] returns Result::Err.
 ///
+/// Message also implements [Deserialize](serde::Deserialize). Deserialization
+/// runs the same validations as the builder, so an invalid payload is rejected
+/// rather than silently accepted.
+///
 /// # Fields and Validations
 ///
 /// For more details, see the [official
@@ -25,13 +32,20 @@ use slack_messaging_derive::Builder;
 /// | Field | Type | Required | Validation |
 /// |-------|------|----------|------------|
 /// | text | String | No | N/A |
-/// | blocks | Vec<[Block]> | No | Maximum 50 items |
+/// | blocks | Vec<[Block]> | No | Maximum 50 items, available in [message](crate::Surface) |
+/// | attachments | Vec<[Attachment]> | No | Maximum 100 items |
 /// | thread_ts | String | No | N/A |
 /// | mrkdwn | bool | No | N/A |
-/// | response_type | String | No | N/A |
+/// | response_type | [ResponseType] | No | N/A |
 /// | replace_original | bool | No | N/A |
 /// | delete_original | bool | No | N/A |
 /// | reply_broadcast | bool | No | N/A |
+/// | metadata | [MessageMetadata] | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * `replace_original` and `delete_original` cannot be both `true`.
+/// * The message and its attachments can include up to 50 blocks in total.
 ///
 /// # Example
 ///
@@ -118,14 +132,19 @@ use slack_messaging_derive::Builder;
 /// # }
 /// ```
 #[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate")]
 pub struct Message {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) text: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(push_item = "block", validate("list::max_item_50"))]
+    #[builder(push_item = "block", validate("list::max_item_50", "surface::message"))]
     pub(crate) blocks: Option<Vec<Block>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(push_item = "attachment", validate("list::max_item_100"))]
+    pub(crate) attachments: Option<Vec<Attachment>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) thread_ts: Option<String>,
 
@@ -133,7 +152,7 @@ pub struct Message {
     pub(crate) mrkdwn: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) response_type: Option<String>,
+    pub(crate) response_type: Option<ResponseType>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) replace_original: Option<bool>,
@@ -143,12 +162,88 @@ pub struct Message {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) reply_broadcast: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<MessageMetadata>,
+}
+
+impl MessageBuilder {
+    /// set [ResponseType::InChannel] to response_type field
+    pub fn in_channel(self) -> Self {
+        self.response_type(ResponseType::InChannel)
+    }
+
+    /// set [ResponseType::Ephemeral] to response_type field
+    pub fn ephemeral(self) -> Self {
+        self.response_type(ResponseType::Ephemeral)
+    }
+}
+
+impl Message {
+    // Errors for the fields only available when responding to a response_url, which request
+    // bodies of Web API methods flattening the message must not include.
+    pub(crate) fn response_url_only_fields(&self) -> Vec<ValidationErrorKind> {
+        [
+            ("response_type", self.response_type.is_some()),
+            ("replace_original", self.replace_original.is_some()),
+            ("delete_original", self.delete_original.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, is_set)| *is_set)
+        .map(|(field, _)| ValidationErrorKind::ResponseUrlOnlyField(field))
+        .collect()
+    }
+}
+
+/// Values that can be set to the `response_type` field of [Message]. See [Slack's
+/// document](https://docs.slack.dev/interactivity/implementing-slash-commands#responding_to_commands)
+/// for details.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    /// The response is visible to all members of the channel.
+    InChannel,
+    /// The response is visible only to the user who invoked the command.
+    Ephemeral,
+}
+
+fn validate(val: &Message) -> Vec<ValidationErrorKind> {
+    let mut errors = vec![];
+
+    if let (Some(true), Some(true)) = (val.replace_original, val.delete_original) {
+        errors.push(ValidationErrorKind::ExclusiveField(
+            "replace_original",
+            "delete_original",
+        ));
+    }
+
+    let total_blocks = val.blocks.as_ref().map(Vec::len).unwrap_or_default()
+        + val
+            .attachments
+            .iter()
+            .flatten()
+            .map(Attachment::block_count)
+            .sum::<usize>();
+
+    if total_blocks > 50 {
+        errors.push(ValidationErrorKind::MaxTotalBlocks {
+            max: 50,
+            actual: total_blocks,
+        });
+    }
+
+    errors
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Surface;
+    use crate::attachment::test_helpers::attachment;
+    use crate::blocks::Input;
+    use crate::blocks::elements::test_helpers::file_input;
     use crate::blocks::test_helpers::*;
+    use crate::composition_objects::test_helpers::plain_text;
     use crate::errors::*;
 
     #[test]
@@ -159,12 +254,14 @@ mod tests {
                 header("this is a header block").into(),
                 section("this is a section block").into(),
             ]),
+            attachments: Some(vec![attachment(vec![section("attachment").into()])]),
             thread_ts: Some("thread ts".into()),
             mrkdwn: Some(true),
-            response_type: Some("response type".into()),
+            response_type: Some(ResponseType::InChannel),
             replace_original: Some(true),
-            delete_original: Some(true),
+            delete_original: Some(false),
             reply_broadcast: Some(true),
+            metadata: Some(metadata()),
         };
 
         let val = Message::builder()
@@ -173,12 +270,14 @@ mod tests {
                 header("this is a header block").into(),
                 section("this is a section block").into(),
             ] as Vec<Block>))
+            .set_attachments(Some(vec![attachment(vec![section("attachment").into()])]))
             .set_thread_ts(Some("thread ts"))
             .set_mrkdwn(Some(true))
-            .set_response_type(Some("response type"))
+            .set_response_type(Some(ResponseType::InChannel))
             .set_replace_original(Some(true))
-            .set_delete_original(Some(true))
+            .set_delete_original(Some(false))
             .set_reply_broadcast(Some(true))
+            .set_metadata(Some(metadata()))
             .build()
             .unwrap();
 
@@ -190,12 +289,14 @@ mod tests {
                 header("this is a header block").into(),
                 section("this is a section block").into(),
             ] as Vec<Block>)
+            .attachments(vec![attachment(vec![section("attachment").into()])])
             .thread_ts("thread ts")
             .mrkdwn(true)
-            .response_type("response type")
+            .response_type(ResponseType::InChannel)
             .replace_original(true)
-            .delete_original(true)
+            .delete_original(false)
             .reply_broadcast(true)
+            .metadata(metadata())
             .build()
             .unwrap();
 
@@ -210,23 +311,68 @@ mod tests {
                 header("this is a header block").into(),
                 section("this is a section block").into(),
             ]),
+            attachments: Some(vec![
+                attachment(vec![section("attachment 0").into()]),
+                attachment(vec![section("attachment 1").into()]),
+            ]),
             thread_ts: None,
             mrkdwn: None,
             response_type: None,
             replace_original: None,
             delete_original: None,
             reply_broadcast: None,
+            metadata: None,
         };
 
         let val = Message::builder()
             .block(header("this is a header block"))
             .block(section("this is a section block"))
+            .attachment(attachment(vec![section("attachment 0").into()]))
+            .attachment(attachment(vec![section("attachment 1").into()]))
             .build()
             .unwrap();
 
         assert_eq!(val, expected);
     }
 
+    #[test]
+    fn it_sets_response_type_with_shortcut_methods() {
+        let val = Message::builder().text("foo").in_channel().build().unwrap();
+        assert_eq!(val.response_type, Some(ResponseType::InChannel));
+
+        let val = Message::builder().text("foo").ephemeral().build().unwrap();
+        assert_eq!(val.response_type, Some(ResponseType::Ephemeral));
+
+        let json = serde_json::to_value(val).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "text": "foo", "response_type": "ephemeral" })
+        );
+    }
+
+    #[test]
+    fn it_prevents_from_both_replace_original_and_delete_original_are_true() {
+        let err = Message::builder()
+            .text("foo")
+            .replace_original(true)
+            .delete_original(true)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Message");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::ExclusiveField(
+            "replace_original",
+            "delete_original"
+        )));
+
+        let val = Message::builder()
+            .replace_original(false)
+            .delete_original(true)
+            .build();
+        assert!(val.is_ok());
+    }
+
     #[test]
     fn it_requries_blocks_list_size_less_than_50() {
         let blocks: Vec<Block> = (0..51).map(|_| section("some section").into()).collect();
@@ -234,6 +380,267 @@ mod tests {
         assert_eq!(err.object(), "Message");
 
         let errors = err.field("blocks");
-        assert!(errors.includes(ValidationErrorKind::MaxArraySize(50)));
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 50,
+            actual: 51
+        }));
+    }
+
+    #[test]
+    fn it_requires_attachments_list_size_less_than_100() {
+        let attachments: Vec<Attachment> = (0..101)
+            .map(|_| attachment(vec![section("foo").into()]))
+            .collect();
+        let err = Message::builder()
+            .attachments(attachments)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Message");
+
+        let errors = err.field("attachments");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
+    }
+
+    #[test]
+    fn it_requires_total_blocks_including_attachments_less_than_50() {
+        let blocks: Vec<Block> = (0..30).map(|_| section("foo").into()).collect();
+        let attachment_blocks: Vec<Block> = (0..21).map(|_| section("bar").into()).collect();
+
+        let err = Message::builder()
+            .blocks(blocks.clone())
+            .attachment(attachment(attachment_blocks))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Message");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::MaxTotalBlocks {
+            max: 50,
+            actual: 51
+        }));
+
+        let attachment_blocks: Vec<Block> = (0..20).map(|_| section("bar").into()).collect();
+        let val = Message::builder()
+            .blocks(blocks)
+            .attachment(attachment(attachment_blocks))
+            .build();
+        assert!(val.is_ok());
+    }
+
+    #[test]
+    fn it_requires_blocks_available_in_messages() {
+        let input = Input {
+            label: Some(plain_text("Upload")),
+            element: Some(file_input().into()),
+            dispatch_action: None,
+            block_id: None,
+            hint: None,
+            optional: None,
+        };
+        let err = Message::builder()
+            .block(section("foo"))
+            .block(input)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Message");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::UnsupportedElement(
+            1,
+            "input",
+            "file_input",
+            Surface::Message
+        )));
+    }
+
+    #[test]
+    fn it_rejects_unknown_blocks() {
+        let err = Message::builder()
+            .block(section("foo"))
+            .block(Block::Unknown(serde_json::json!({ "type": "team" })))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Message");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::UnsupportedBlock(
+            1,
+            "unknown",
+            Surface::Message
+        )));
+    }
+
+    #[test]
+    fn it_deserializes_from_json() {
+        let json = serde_json::json!({
+            "text": "fallback text",
+            "blocks": [
+                {
+                    "type": "header",
+                    "text": { "type": "plain_text", "text": "New request", "emoji": true }
+                },
+                {
+                    "type": "section",
+                    "block_id": "section_1",
+                    "text": { "type": "mrkdwn", "text": "*bold*", "verbatim": false },
+                    "fields": [
+                        { "type": "mrkdwn", "text": "High" },
+                        { "type": "plain_text", "text": "String" }
+                    ],
+                    "accessory": {
+                        "type": "button",
+                        "text": { "type": "plain_text", "text": "Click Me" },
+                        "action_id": "button_0",
+                        "style": "primary",
+                        "confirm": {
+                            "title": { "type": "plain_text", "text": "Are you sure?" },
+                            "text": { "type": "plain_text", "text": "Wouldn't you prefer a good game of chess?" },
+                            "confirm": { "type": "plain_text", "text": "Do it" },
+                            "deny": { "type": "plain_text", "text": "Stop, I've changed my mind!" },
+                            "style": "danger"
+                        }
+                    }
+                },
+                { "type": "divider" },
+                {
+                    "type": "actions",
+                    "block_id": "actions1",
+                    "elements": [
+                        {
+                            "type": "static_select",
+                            "action_id": "select_2",
+                            "placeholder": { "type": "plain_text", "text": "Which witch?" },
+                            "options": [
+                                { "text": { "type": "plain_text", "text": "Matilda" }, "value": "matilda" },
+                                { "text": { "type": "plain_text", "text": "Glinda" }, "value": "glinda" }
+                            ]
+                        },
+                        {
+                            "type": "datepicker",
+                            "action_id": "datepicker123",
+                            "initial_date": "1990-04-28"
+                        }
+                    ]
+                },
+                {
+                    "type": "context",
+                    "elements": [
+                        {
+                            "type": "image",
+                            "image_url": "https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg",
+                            "alt_text": "images"
+                        },
+                        { "type": "mrkdwn", "text": "Location: **Dogpatch**" }
+                    ]
+                },
+                {
+                    "type": "input",
+                    "block_id": "input_1",
+                    "label": { "type": "plain_text", "text": "label text" },
+                    "element": {
+                        "type": "plain_text_input",
+                        "action_id": "text_area_1",
+                        "multiline": true
+                    },
+                    "optional": true
+                },
+                {
+                    "type": "rich_text",
+                    "elements": [
+                        {
+                            "type": "rich_text_section",
+                            "elements": [
+                                { "type": "text", "text": "Hello", "style": { "bold": true } },
+                                { "type": "user", "user_id": "U0123456" }
+                            ]
+                        },
+                        {
+                            "type": "rich_text_list",
+                            "style": "bullet",
+                            "elements": [
+                                {
+                                    "type": "rich_text_section",
+                                    "elements": [{ "type": "emoji", "name": "tada" }]
+                                }
+                            ]
+                        }
+                    ]
+                },
+                {
+                    "type": "table",
+                    "rows": [
+                        [
+                            { "type": "raw_text", "text": "Header A" },
+                            { "type": "raw_text", "text": "Header B" }
+                        ]
+                    ]
+                },
+                {
+                    "type": "data_visualization",
+                    "title": "My Favorite Candy Bars",
+                    "chart": {
+                        "type": "pie",
+                        "segments": [
+                            { "label": "Kit Kat", "value": 45 },
+                            { "label": "Twix", "value": 28 }
+                        ]
+                    }
+                },
+                {
+                    "type": "alert",
+                    "text": { "type": "mrkdwn", "text": "The work is mysterious and important." },
+                    "level": "info"
+                },
+                {
+                    "type": "file",
+                    "external_id": "ABCD1",
+                    "source": "remote"
+                }
+            ],
+            "thread_ts": "1234567890.123456",
+            "mrkdwn": true
+        });
+
+        let message: Message = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(message.blocks.as_ref().map(|b| b.len()), Some(11));
+
+        let val = serde_json::to_value(&message).unwrap();
+        assert_eq!(val, json);
+    }
+
+    #[test]
+    fn it_runs_validations_on_deserializing() {
+        let blocks: Vec<serde_json::Value> = (0..51)
+            .map(|_| serde_json::to_value(section("some section")).unwrap())
+            .collect();
+        let json = serde_json::json!({ "blocks": blocks });
+
+        let err = serde_json::from_value::<Message>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("blocks: max array length is `50` items but got `51`")
+        );
+    }
+
+    #[test]
+    fn it_fails_to_deserialize_unknown_block_type() {
+        let json = serde_json::json!({
+            "blocks": [{ "type": "unknown_block" }]
+        });
+
+        let err = serde_json::from_value::<Message>(json).unwrap_err();
+        assert!(err.to_string().contains("unknown variant `unknown_block`"));
+    }
+
+    fn metadata() -> MessageMetadata {
+        MessageMetadata::builder()
+            .event_type("task_created")
+            .event_payload(serde_json::json!({ "id": "TK-1" }))
+            .unwrap()
+            .build()
+            .unwrap()
     }
 }
@@ -0,0 +1,263 @@
+use crate::validators::*;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use slack_messaging_derive::Builder;
+
+/// [Message metadata](https://docs.slack.dev/messaging/message-metadata) representation.
+///
+/// Metadata attaches a structured event to a [Message](crate::Message) so that other apps can
+/// react to it. Any [Serialize] value which serializes into a JSON object can be set as the
+/// payload, and [`payload`](MessageMetadata::payload) parses it back into a typed value.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/messaging/message-metadata).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | event_type | String | Yes | Maximum 255 characters, snake_case alphanumeric |
+/// | event_payload | impl [Serialize] | Yes | Serialized into a JSON object |
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::MessageMetadata;
+/// use serde::{Deserialize, Serialize};
+/// # use std::error::Error;
+///
+/// #[derive(Debug, Serialize, Deserialize, PartialEq)]
+/// struct TaskCreated {
+///     id: String,
+///     title: String,
+/// }
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let task = TaskCreated {
+///     id: "TK-2132".into(),
+///     title: "Redesign Homepage".into(),
+/// };
+///
+/// let metadata = MessageMetadata::builder()
+///     .event_type("task_created")
+///     .event_payload(&task)?
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "event_type": "task_created",
+///     "event_payload": {
+///         "id": "TK-2132",
+///         "title": "Redesign Homepage"
+///     }
+/// });
+///
+/// let json = serde_json::to_value(&metadata).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// let payload: TaskCreated = metadata.payload()?;
+///
+/// assert_eq!(payload, task);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let metadata = MessageMetadata::builder()
+///     .event_type("TaskCreated")
+///     .event_payload(&task)?
+///     .build();
+///
+/// assert!(metadata.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct MessageMetadata {
+    #[builder(validate("required", "text::max_255", "text::event_type_format"))]
+    pub(crate) event_type: Option<String>,
+
+    #[builder(no_accessors, validate("required", "json::object"))]
+    pub(crate) event_payload: Option<serde_json::Value>,
+}
+
+impl MessageMetadata {
+    /// get event_type field value.
+    pub fn event_type(&self) -> &str {
+        self.event_type.as_deref().unwrap_or_default()
+    }
+
+    /// Parses event_payload field value into the type.
+    pub fn payload<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(
+            self.event_payload
+                .as_ref()
+                .unwrap_or(&serde_json::Value::Null),
+        )
+    }
+}
+
+impl MessageMetadataBuilder {
+    /// get event_payload field value.
+    pub fn get_event_payload(&self) -> Option<&serde_json::Value> {
+        self.event_payload.inner_ref()
+    }
+
+    /// set event_payload field value after serializing it into JSON value. Returns the error of
+    /// the serialization if the value fails to serialize.
+    pub fn set_event_payload(
+        self,
+        value: Option<impl Serialize>,
+    ) -> Result<Self, serde_json::Error> {
+        let value = value.map(serde_json::to_value).transpose()?;
+        Ok(Self {
+            event_payload: Self::new_event_payload(value),
+            ..self
+        })
+    }
+
+    /// set event_payload field value after serializing it into JSON value. Returns the error of
+    /// the serialization if the value fails to serialize.
+    pub fn event_payload(self, value: impl Serialize) -> Result<Self, serde_json::Error> {
+        self.set_event_payload(Some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::*;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct TaskCreated {
+        id: String,
+    }
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = MessageMetadata {
+            event_type: Some("task_created".into()),
+            event_payload: Some(serde_json::json!({ "id": "TK-1" })),
+        };
+
+        let val = MessageMetadata::builder()
+            .set_event_type(Some("task_created"))
+            .set_event_payload(Some(TaskCreated { id: "TK-1".into() }))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = MessageMetadata::builder()
+            .event_type("task_created")
+            .event_payload(serde_json::json!({ "id": "TK-1" }))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_parses_payload_into_typed_value() {
+        let val: MessageMetadata = serde_json::from_value(serde_json::json!({
+            "event_type": "task_created",
+            "event_payload": { "id": "TK-1" }
+        }))
+        .unwrap();
+
+        assert_eq!(val.event_type(), "task_created");
+        assert_eq!(
+            val.payload::<TaskCreated>().unwrap(),
+            TaskCreated { id: "TK-1".into() }
+        );
+        assert!(val.payload::<Vec<String>>().is_err());
+    }
+
+    #[test]
+    fn it_requires_event_type_field() {
+        let err = MessageMetadata::builder()
+            .event_payload(TaskCreated { id: "TK-1".into() })
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "MessageMetadata");
+
+        let errors = err.field("event_type");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_event_type_to_be_snake_case() {
+        let err = MessageMetadata::builder()
+            .event_type("task-created")
+            .event_payload(TaskCreated { id: "TK-1".into() })
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "MessageMetadata");
+
+        let errors = err.field("event_type");
+        assert!(errors.includes(ValidationErrorKind::InvalidFormat(
+            "snake_case alphanumeric"
+        )));
+    }
+
+    #[test]
+    fn it_requires_event_type_less_than_255_characters_long() {
+        let err = MessageMetadata::builder()
+            .event_type("a".repeat(256))
+            .event_payload(TaskCreated { id: "TK-1".into() })
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "MessageMetadata");
+
+        let errors = err.field("event_type");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
+    }
+
+    #[test]
+    fn it_requires_event_payload_field() {
+        let err = MessageMetadata::builder()
+            .event_type("task_created")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "MessageMetadata");
+
+        let errors = err.field("event_payload");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_event_payload_to_be_an_object() {
+        let err = MessageMetadata::builder()
+            .event_type("task_created")
+            .event_payload(vec!["TK-1"])
+            .unwrap()
+            .build()
+            .unwrap_err();
+
+        let errors = err.field("event_payload");
+        assert!(errors.includes(ValidationErrorKind::InvalidFormat("JSON object")));
+    }
+
+    #[test]
+    fn it_returns_error_if_event_payload_fails_to_serialize() {
+        // maps with non-string keys cannot be serialized into JSON.
+        let payload: HashMap<(u8, u8), u8> = HashMap::from([((1, 2), 3)]);
+        let err = MessageMetadata::builder()
+            .event_type("task_created")
+            .event_payload(payload)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "key must be a string");
+    }
+}
@@ -0,0 +1,432 @@
+use crate::blocks::Block;
+use crate::composition_objects::{Plain, Text};
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// [Modal view](https://docs.slack.dev/reference/views/modal-views) representation.
+///
+/// Use this to open, update or push a modal with `views.open`, `views.update` or `views.push`
+/// methods.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/views/modal-views#fields).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | title | [Text]<[Plain]> | Yes | Max length 24 characters |
+/// | blocks | Vec<[Block]> | Yes | Maximum 100 items, available in [modal](crate::Surface) |
+/// | close | [Text]<[Plain]> | No | Max length 24 characters |
+/// | submit | [Text]<[Plain]> | Conditionally* | Max length 24 characters |
+/// | private_metadata | String | No | Max length 3000 characters |
+/// | callback_id | String | No | Max length 255 characters |
+/// | clear_on_close | bool | No | N/A |
+/// | notify_on_close | bool | No | N/A |
+/// | external_id | String | No | Max length 255 characters |
+/// | submit_disabled | bool | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * `submit` is required when `blocks` contains an [Input](crate::blocks::Input) block.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{plain_text, Modal};
+/// use slack_messaging::blocks::{elements::PlainTextInput, Input};
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let modal = Modal::builder()
+///     .title(plain_text!("My App")?)
+///     .submit(plain_text!("Submit")?)
+///     .close(plain_text!("Cancel")?)
+///     .callback_id("ticket_modal")
+///     .block(
+///         Input::builder()
+///             .block_id("title_block")
+///             .label(plain_text!("Title")?)
+///             .element(
+///                 PlainTextInput::builder()
+///                     .action_id("title_input")
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "type": "modal",
+///     "title": {
+///         "type": "plain_text",
+///         "text": "My App"
+///     },
+///     "blocks": [
+///         {
+///             "type": "input",
+///             "block_id": "title_block",
+///             "label": {
+///                 "type": "plain_text",
+///                 "text": "Title"
+///             },
+///             "element": {
+///                 "type": "plain_text_input",
+///                 "action_id": "title_input"
+///             }
+///         }
+///     ],
+///     "close": {
+///         "type": "plain_text",
+///         "text": "Cancel"
+///     },
+///     "submit": {
+///         "type": "plain_text",
+///         "text": "Submit"
+///     },
+///     "callback_id": "ticket_modal"
+/// });
+///
+/// let json = serde_json::to_value(modal).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // A modal containing input blocks without submit button is invalid.
+/// let modal = Modal::builder()
+///     .title(plain_text!("My App")?)
+///     .block(
+///         Input::builder()
+///             .label(plain_text!("Title")?)
+///             .element(PlainTextInput::builder().build()?)
+///             .build()?
+///     )
+///     .build();
+///
+/// assert!(modal.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[serde(tag = "type", rename = "modal")]
+#[builder(validate = "validate")]
+pub struct Modal {
+    #[builder(validate("required", "text_object::max_24"))]
+    pub(crate) title: Option<Text<Plain>>,
+
+    #[builder(
+        push_item = "block",
+        validate("required", "list::max_item_100", "surface::modal")
+    )]
+    pub(crate) blocks: Option<Vec<Block>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text_object::max_24"))]
+    pub(crate) close: Option<Text<Plain>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text_object::max_24"))]
+    pub(crate) submit: Option<Text<Plain>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::max_3000"))]
+    pub(crate) private_metadata: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::max_255"))]
+    pub(crate) callback_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) clear_on_close: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) notify_on_close: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(validate("text::max_255"))]
+    pub(crate) external_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) submit_disabled: Option<bool>,
+}
+
+fn validate(val: &Modal) -> Vec<ValidationErrorKind> {
+    let has_input = val
+        .blocks
+        .as_ref()
+        .is_some_and(|blocks| blocks.iter().any(|b| matches!(b, Block::Input(_))));
+
+    if has_input && val.submit.is_none() {
+        vec![ValidationErrorKind::ModalSubmitRequired]
+    } else {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Surface;
+    use crate::blocks::test_helpers::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = Modal {
+            title: Some(plain_text("My App")),
+            blocks: Some(vec![section("this is a section block").into()]),
+            close: Some(plain_text("Cancel")),
+            submit: Some(plain_text("Submit")),
+            private_metadata: Some("shhh-its-secret".into()),
+            callback_id: Some("modal_0".into()),
+            clear_on_close: Some(true),
+            notify_on_close: Some(true),
+            external_id: Some("external_0".into()),
+            submit_disabled: Some(false),
+        };
+
+        let val = Modal::builder()
+            .set_title(Some(plain_text("My App")))
+            .set_blocks(Some(
+                vec![section("this is a section block").into()] as Vec<Block>
+            ))
+            .set_close(Some(plain_text("Cancel")))
+            .set_submit(Some(plain_text("Submit")))
+            .set_private_metadata(Some("shhh-its-secret"))
+            .set_callback_id(Some("modal_0"))
+            .set_clear_on_close(Some(true))
+            .set_notify_on_close(Some(true))
+            .set_external_id(Some("external_0"))
+            .set_submit_disabled(Some(false))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = Modal::builder()
+            .title(plain_text("My App"))
+            .blocks(vec![section("this is a section block").into()] as Vec<Block>)
+            .close(plain_text("Cancel"))
+            .submit(plain_text("Submit"))
+            .private_metadata("shhh-its-secret")
+            .callback_id("modal_0")
+            .clear_on_close(true)
+            .notify_on_close(true)
+            .external_id("external_0")
+            .submit_disabled(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_implements_push_item_method() {
+        let expected = Modal {
+            title: Some(plain_text("My App")),
+            blocks: Some(vec![
+                header("this is a header block").into(),
+                section("this is a section block").into(),
+            ]),
+            close: None,
+            submit: None,
+            private_metadata: None,
+            callback_id: None,
+            clear_on_close: None,
+            notify_on_close: None,
+            external_id: None,
+            submit_disabled: None,
+        };
+
+        let val = Modal::builder()
+            .title(plain_text("My App"))
+            .block(header("this is a header block"))
+            .block(section("this is a section block"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_title_field() {
+        let err = Modal::builder().block(section("foo")).build().unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("title");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_title_less_than_24_characters_long() {
+        let err = Modal::builder()
+            .title(plain_text("a".repeat(25)))
+            .block(section("foo"))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("title");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 24,
+            actual: 25
+        }));
+    }
+
+    #[test]
+    fn it_requires_blocks_field() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_blocks_list_size_less_than_100() {
+        let blocks: Vec<Block> = (0..101).map(|_| section("foo").into()).collect();
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .blocks(blocks)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 100,
+            actual: 101
+        }));
+    }
+
+    #[test]
+    fn it_requires_blocks_available_in_modals() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .block(task_card())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::UnsupportedBlock(
+            1,
+            "task_card",
+            Surface::Modal
+        )));
+    }
+
+    #[test]
+    fn it_requires_close_less_than_24_characters_long() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .close(plain_text("a".repeat(25)))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("close");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 24,
+            actual: 25
+        }));
+    }
+
+    #[test]
+    fn it_requires_submit_less_than_24_characters_long() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .submit(plain_text("a".repeat(25)))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("submit");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 24,
+            actual: 25
+        }));
+    }
+
+    #[test]
+    fn it_requires_private_metadata_less_than_3000_characters_long() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .private_metadata("a".repeat(3001))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("private_metadata");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 3000,
+            actual: 3001
+        }));
+    }
+
+    #[test]
+    fn it_requires_callback_id_less_than_255_characters_long() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .callback_id("a".repeat(256))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("callback_id");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
+    }
+
+    #[test]
+    fn it_requires_external_id_less_than_255_characters_long() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .external_id("a".repeat(256))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.field("external_id");
+        assert!(errors.includes(ValidationErrorKind::MaxTextLength {
+            max: 255,
+            actual: 256
+        }));
+    }
+
+    #[test]
+    fn it_requires_submit_if_blocks_contain_input_block() {
+        let err = Modal::builder()
+            .title(plain_text("My App"))
+            .block(input())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "Modal");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::ModalSubmitRequired));
+
+        let modal = Modal::builder()
+            .title(plain_text("My App"))
+            .block(input())
+            .submit(plain_text("Submit"))
+            .build();
+        assert!(modal.is_ok());
+    }
+}
@@ -0,0 +1,628 @@
+use crate::Message;
+use crate::errors::QueueError;
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::{Notify, oneshot};
+use tokio::time::Instant;
+
+/// Destination the [`MessageQueue`] delivers messages to.
+///
+/// With the `web-api` feature, [`WebApiClient`](crate::web_api::WebApiClient) implements this
+/// trait by calling `chat.postMessage`. Implement it yourself to send messages in another way or
+/// to test your code without network.
+pub trait MessageSender: Send + Sync + 'static {
+    /// Error returned when the message could not be sent.
+    type Error: Send + 'static;
+
+    /// Sends the message to the channel.
+    fn send(
+        &self,
+        channel: &str,
+        message: &Message,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+#[cfg(feature = "web-api")]
+impl MessageSender for crate::web_api::WebApiClient {
+    type Error = crate::errors::WebApiError;
+
+    async fn send(&self, channel: &str, message: &Message) -> Result<(), Self::Error> {
+        let body = crate::requests::ChatPostMessage {
+            channel: Some(channel.into()),
+            message: Some(message.clone()),
+            username: None,
+            icon_emoji: None,
+            icon_url: None,
+            unfurl_links: None,
+            unfurl_media: None,
+            link_names: None,
+            parse: None,
+        };
+        self.chat_post_message(&body).await.map(|_| ())
+    }
+}
+
+/// What to do when a message is enqueued to a channel whose queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// [`enqueue`](MessageQueue::enqueue) waits until the queue has space.
+    Wait,
+    /// The new message is rejected with [`QueueError::Full`].
+    Reject,
+    /// The oldest pending message is dropped to make space for the new one.
+    DropOldest,
+    /// The new message is dropped.
+    DropNewest,
+}
+
+/// How pending messages of the same channel are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoalescePolicy {
+    /// Every message is sent.
+    None,
+    /// A new message replaces the pending messages of the channel, so only the latest one is
+    /// sent. This suits status updates where older messages become stale.
+    Latest,
+}
+
+/// Configuration of the [`MessageQueue`].
+///
+/// By default, each channel sends one message per second and holds up to 100 pending messages,
+/// and [`enqueue`](MessageQueue::enqueue) waits while the queue of the channel is full.
+///
+/// ```
+/// use slack_messaging::queue::{CoalescePolicy, OverflowPolicy, QueueConfig};
+/// use std::time::Duration;
+///
+/// let config = QueueConfig::default()
+///     .interval(Duration::from_millis(1500))
+///     .capacity(10)
+///     .overflow(OverflowPolicy::DropOldest)
+///     .coalesce(CoalescePolicy::None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueueConfig {
+    interval: Duration,
+    capacity: usize,
+    overflow: OverflowPolicy,
+    coalesce: CoalescePolicy,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            capacity: 100,
+            overflow: OverflowPolicy::Wait,
+            coalesce: CoalescePolicy::None,
+        }
+    }
+}
+
+impl QueueConfig {
+    /// Sets the minimum interval between two messages to the same channel.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the maximum number of pending messages per channel. The minimum is 1.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Sets the policy applied when the queue of a channel is full.
+    pub fn overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the policy to combine pending messages of a channel.
+    pub fn coalesce(mut self, coalesce: CoalescePolicy) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+}
+
+/// Final state of an enqueued message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome<E> {
+    /// The message has been sent.
+    Sent,
+    /// The sender returned the error.
+    Failed(E),
+    /// The message has been replaced by a newer message of the channel.
+    Coalesced,
+    /// The message has been dropped by the overflow policy, or its background task stopped
+    /// before sending it, e.g. because the runtime shut down.
+    Dropped,
+}
+
+/// Future resolving to the [`Outcome`] of an enqueued message.
+///
+/// Awaiting it is optional; the message is delivered whether or not the `Delivery` is kept.
+#[derive(Debug)]
+pub struct Delivery<E> {
+    rx: oneshot::Receiver<Outcome<E>>,
+}
+
+impl<E> Future for Delivery<E> {
+    type Output = Outcome<E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx)
+            .poll(cx)
+            .map(|res| res.unwrap_or(Outcome::Dropped))
+    }
+}
+
+/// Outbound queue pacing messages to each channel independently.
+///
+/// Slack allows roughly one message per second per channel. The queue keeps pending messages per
+/// channel and a background task per active channel sends them through the [`MessageSender`] at
+/// the configured interval, so bursts to one channel do not delay the others.
+///
+/// Backpressure is exposed through [`enqueue`](Self::enqueue), which waits or fails according
+/// to the [`OverflowPolicy`] when the queue of the channel is full, and through
+/// [`pending`](Self::pending).
+///
+/// The queue must be used within a tokio runtime. Dropping the queue does not cancel pending
+/// messages; the background tasks keep sending them until their channels are empty.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::Message;
+/// use slack_messaging::queue::{MessageQueue, MessageSender, Outcome, QueueConfig};
+/// use std::convert::Infallible;
+///
+/// struct Stdout;
+///
+/// impl MessageSender for Stdout {
+///     type Error = Infallible;
+///
+///     async fn send(&self, channel: &str, message: &Message) -> Result<(), Infallible> {
+///         println!("{channel}: {}", serde_json::to_string(message).unwrap());
+///         Ok(())
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let queue = MessageQueue::new(Stdout, QueueConfig::default());
+///
+/// let delivery = queue
+///     .enqueue("C123ABC456", Message::builder().text("disk usage 91%").build()?)
+///     .await?;
+///
+/// assert_eq!(delivery.await, Outcome::Sent);
+/// #     Ok(())
+/// # }
+/// ```
+pub struct MessageQueue<S: MessageSender> {
+    inner: Arc<Inner<S>>,
+}
+
+impl<S: MessageSender> Clone for MessageQueue<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+struct Inner<S: MessageSender> {
+    sender: S,
+    config: QueueConfig,
+    channels: Mutex<HashMap<String, ChannelState<S::Error>>>,
+}
+
+struct ChannelState<E> {
+    pending: VecDeque<Pending<E>>,
+    next_at: Option<Instant>,
+    space: Arc<Notify>,
+}
+
+struct Pending<E> {
+    message: Message,
+    tx: oneshot::Sender<Outcome<E>>,
+}
+
+impl<E> Pending<E> {
+    fn new(message: Message) -> (Self, Delivery<E>) {
+        let (tx, rx) = oneshot::channel();
+        (Self { message, tx }, Delivery { rx })
+    }
+
+    fn resolve(self, outcome: Outcome<E>) {
+        let _ = self.tx.send(outcome);
+    }
+}
+
+enum Offer<E> {
+    Queued(Delivery<E>),
+    Full(Message, Arc<Notify>),
+}
+
+impl<S: MessageSender> MessageQueue<S> {
+    /// Constructs a queue delivering messages through the sender.
+    pub fn new(sender: S, config: QueueConfig) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                sender,
+                config,
+                channels: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// Enqueues the message to the channel. If the queue of the channel is full, it waits for
+    /// space or fails according to the [`OverflowPolicy`].
+    pub async fn enqueue(
+        &self,
+        channel: impl Into<String>,
+        message: Message,
+    ) -> Result<Delivery<S::Error>, QueueError> {
+        let channel = channel.into();
+        let mut message = message;
+
+        loop {
+            match self.offer(&channel, message)? {
+                Offer::Queued(delivery) => return Ok(delivery),
+                Offer::Full(returned, space) => {
+                    message = returned;
+                    space.notified().await;
+                }
+            }
+        }
+    }
+
+    /// Enqueues the message to the channel without waiting. If the queue of the channel is full
+    /// and the [`OverflowPolicy`] is [`Wait`](OverflowPolicy::Wait), it returns
+    /// [`QueueError::Full`].
+    pub fn try_enqueue(
+        &self,
+        channel: impl Into<String>,
+        message: Message,
+    ) -> Result<Delivery<S::Error>, QueueError> {
+        let channel = channel.into();
+
+        match self.offer(&channel, message)? {
+            Offer::Queued(delivery) => Ok(delivery),
+            Offer::Full(..) => Err(QueueError::Full(channel)),
+        }
+    }
+
+    /// Returns the number of messages waiting to be sent to the channel.
+    pub fn pending(&self, channel: &str) -> usize {
+        self.inner
+            .channels
+            .lock()
+            .unwrap()
+            .get(channel)
+            .map(|state| state.pending.len())
+            .unwrap_or_default()
+    }
+
+    fn offer(&self, channel: &str, message: Message) -> Result<Offer<S::Error>, QueueError> {
+        let config = &self.inner.config;
+        let mut channels = self.inner.channels.lock().unwrap();
+
+        let state = match channels.entry(channel.into()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                tokio::spawn(worker(Arc::clone(&self.inner), channel.into()));
+                entry.insert(ChannelState {
+                    pending: VecDeque::new(),
+                    next_at: None,
+                    space: Arc::new(Notify::new()),
+                })
+            }
+        };
+
+        if config.coalesce == CoalescePolicy::Latest {
+            for stale in state.pending.drain(..) {
+                stale.resolve(Outcome::Coalesced);
+            }
+        }
+
+        if state.pending.len() >= config.capacity {
+            match config.overflow {
+                OverflowPolicy::Wait => {
+                    return Ok(Offer::Full(message, Arc::clone(&state.space)));
+                }
+                OverflowPolicy::Reject => {
+                    return Err(QueueError::Full(channel.into()));
+                }
+                OverflowPolicy::DropOldest => {
+                    if let Some(oldest) = state.pending.pop_front() {
+                        oldest.resolve(Outcome::Dropped);
+                    }
+                }
+                OverflowPolicy::DropNewest => {
+                    let (pending, delivery) = Pending::new(message);
+                    pending.resolve(Outcome::Dropped);
+                    return Ok(Offer::Queued(delivery));
+                }
+            }
+        }
+
+        let (pending, delivery) = Pending::new(message);
+        state.pending.push_back(pending);
+        Ok(Offer::Queued(delivery))
+    }
+}
+
+async fn worker<S: MessageSender>(inner: Arc<Inner<S>>, channel: String) {
+    loop {
+        let next_at = inner
+            .channels
+            .lock()
+            .unwrap()
+            .get(&channel)
+            .and_then(|s| s.next_at);
+
+        if let Some(at) = next_at {
+            tokio::time::sleep_until(at).await;
+        }
+
+        let item = {
+            let mut channels = inner.channels.lock().unwrap();
+            let Some(state) = channels.get_mut(&channel) else {
+                return;
+            };
+
+            match state.pending.pop_front() {
+                Some(item) => {
+                    state.space.notify_one();
+                    item
+                }
+                None => {
+                    channels.remove(&channel);
+                    return;
+                }
+            }
+        };
+
+        let started = Instant::now();
+        let result = inner.sender.send(&channel, &item.message).await;
+
+        if let Some(state) = inner.channels.lock().unwrap().get_mut(&channel) {
+            state.next_at = Some(started + inner.config.interval);
+        }
+
+        item.resolve(match result {
+            Ok(()) => Outcome::Sent,
+            Err(err) => Outcome::Failed(err),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        sent: Arc<Mutex<Vec<(String, String, Instant)>>>,
+    }
+
+    impl MessageSender for Recorder {
+        type Error = String;
+
+        async fn send(&self, channel: &str, message: &Message) -> Result<(), String> {
+            let text = message.text.clone().unwrap_or_default();
+            if text == "fail" {
+                return Err("channel_not_found".into());
+            }
+            self.sent
+                .lock()
+                .unwrap()
+                .push((channel.into(), text, Instant::now()));
+            Ok(())
+        }
+    }
+
+    impl Recorder {
+        fn texts(&self) -> Vec<String> {
+            self.sent
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(_, text, _)| text.clone())
+                .collect()
+        }
+    }
+
+    fn message(text: &str) -> Message {
+        Message::builder().text(text).build().unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_paces_messages_per_channel() {
+        let recorder = Recorder::default();
+        let queue = MessageQueue::new(recorder.clone(), QueueConfig::default());
+        let start = Instant::now();
+
+        let a1 = queue.enqueue("C1", message("a1")).await.unwrap();
+        let a2 = queue.enqueue("C1", message("a2")).await.unwrap();
+        let a3 = queue.enqueue("C1", message("a3")).await.unwrap();
+        let b1 = queue.enqueue("C2", message("b1")).await.unwrap();
+
+        assert_eq!(queue.pending("C1"), 3);
+
+        for delivery in [a1, a2, a3, b1] {
+            assert_eq!(delivery.await, Outcome::Sent);
+        }
+
+        let sent = recorder.sent.lock().unwrap().clone();
+        let elapsed = |channel: &str, text: &str| {
+            sent.iter()
+                .find(|(c, t, _)| c == channel && t == text)
+                .map(|(_, _, at)| *at - start)
+                .unwrap()
+        };
+
+        assert_eq!(elapsed("C1", "a1"), Duration::ZERO);
+        assert_eq!(elapsed("C1", "a2"), Duration::from_secs(1));
+        assert_eq!(elapsed("C1", "a3"), Duration::from_secs(2));
+        assert_eq!(elapsed("C2", "b1"), Duration::ZERO);
+        assert_eq!(queue.pending("C1"), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_sends_pending_messages_after_the_queue_is_dropped() {
+        let recorder = Recorder::default();
+        let queue = MessageQueue::new(recorder.clone(), QueueConfig::default());
+
+        let first = queue.enqueue("C1", message("1")).await.unwrap();
+        let second = queue.enqueue("C1", message("2")).await.unwrap();
+        drop(queue);
+
+        assert_eq!(first.await, Outcome::Sent);
+        assert_eq!(second.await, Outcome::Sent);
+        assert_eq!(recorder.texts(), ["1", "2"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_reports_sender_errors() {
+        let queue = MessageQueue::new(Recorder::default(), QueueConfig::default());
+
+        let delivery = queue.enqueue("C1", message("fail")).await.unwrap();
+
+        assert_eq!(
+            delivery.await,
+            Outcome::Failed("channel_not_found".to_string())
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_rejects_messages_when_full() {
+        let config = QueueConfig::default()
+            .capacity(1)
+            .overflow(OverflowPolicy::Reject);
+        let queue = MessageQueue::new(Recorder::default(), config);
+
+        let first = queue.enqueue("C1", message("1")).await.unwrap();
+        assert_eq!(first.await, Outcome::Sent);
+
+        let _second = queue.enqueue("C1", message("2")).await.unwrap();
+        let err = queue.enqueue("C1", message("3")).await.unwrap_err();
+
+        assert_eq!(err, QueueError::Full("C1".into()));
+        assert!(queue.enqueue("C2", message("4")).await.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_drops_oldest_message_when_full() {
+        let recorder = Recorder::default();
+        let config = QueueConfig::default()
+            .capacity(1)
+            .overflow(OverflowPolicy::DropOldest);
+        let queue = MessageQueue::new(recorder.clone(), config);
+
+        let first = queue.enqueue("C1", message("1")).await.unwrap();
+        assert_eq!(first.await, Outcome::Sent);
+
+        let second = queue.enqueue("C1", message("2")).await.unwrap();
+        let third = queue.enqueue("C1", message("3")).await.unwrap();
+
+        assert_eq!(second.await, Outcome::Dropped);
+        assert_eq!(third.await, Outcome::Sent);
+        assert_eq!(recorder.texts(), ["1", "3"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_drops_newest_message_when_full() {
+        let recorder = Recorder::default();
+        let config = QueueConfig::default()
+            .capacity(1)
+            .overflow(OverflowPolicy::DropNewest);
+        let queue = MessageQueue::new(recorder.clone(), config);
+
+        let first = queue.enqueue("C1", message("1")).await.unwrap();
+        assert_eq!(first.await, Outcome::Sent);
+
+        let second = queue.enqueue("C1", message("2")).await.unwrap();
+        let third = queue.enqueue("C1", message("3")).await.unwrap();
+
+        assert_eq!(third.await, Outcome::Dropped);
+        assert_eq!(second.await, Outcome::Sent);
+        assert_eq!(recorder.texts(), ["1", "2"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_waits_for_space_when_full() {
+        let config = QueueConfig::default().capacity(1);
+        let queue = MessageQueue::new(Recorder::default(), config);
+        let start = Instant::now();
+
+        let first = queue.enqueue("C1", message("1")).await.unwrap();
+        assert_eq!(first.await, Outcome::Sent);
+
+        let _second = queue.enqueue("C1", message("2")).await.unwrap();
+        assert_eq!(
+            queue.try_enqueue("C1", message("3")).unwrap_err(),
+            QueueError::Full("C1".into())
+        );
+
+        let third = queue.enqueue("C1", message("3")).await.unwrap();
+        assert_eq!(Instant::now() - start, Duration::from_secs(1));
+
+        assert_eq!(third.await, Outcome::Sent);
+        assert_eq!(Instant::now() - start, Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_coalesces_pending_messages() {
+        let recorder = Recorder::default();
+        let config = QueueConfig::default().coalesce(CoalescePolicy::Latest);
+        let queue = MessageQueue::new(recorder.clone(), config);
+
+        let first = queue.enqueue("C1", message("1")).await.unwrap();
+        assert_eq!(first.await, Outcome::Sent);
+
+        let second = queue.enqueue("C1", message("2")).await.unwrap();
+        let third = queue.enqueue("C1", message("3")).await.unwrap();
+        assert_eq!(queue.pending("C1"), 1);
+
+        assert_eq!(second.await, Outcome::Coalesced);
+        assert_eq!(third.await, Outcome::Sent);
+        assert_eq!(recorder.texts(), ["1", "3"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_respects_interval_after_channel_becomes_idle() {
+        let recorder = Recorder::default();
+        let queue = MessageQueue::new(recorder.clone(), QueueConfig::default());
+        let start = Instant::now();
+
+        assert_eq!(
+            queue.enqueue("C1", message("1")).await.unwrap().await,
+            Outcome::Sent
+        );
+
+        tokio::time::sleep(Duration::from_millis(400)).await;
+
+        assert_eq!(
+            queue.enqueue("C1", message("2")).await.unwrap().await,
+            Outcome::Sent
+        );
+        assert_eq!(Instant::now() - start, Duration::from_secs(1));
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        assert_eq!(
+            queue.enqueue("C1", message("3")).await.unwrap().await,
+            Outcome::Sent
+        );
+        assert_eq!(Instant::now() - start, Duration::from_secs(6));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// Request body of [`chat.delete`](https://docs.slack.dev/reference/methods/chat.delete) method.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/chat.delete).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | channel | String | Yes | N/A |
+/// | ts | String | Yes | N/A |
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::requests::ChatDelete;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ChatDelete::builder()
+///     .channel("C123ABC456")
+///     .ts("1405894322.002768")
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "channel": "C123ABC456",
+///     "ts": "1405894322.002768"
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(no_deserialize)]
+pub struct ChatDelete {
+    #[builder(validate("required"))]
+    pub(crate) channel: Option<String>,
+
+    #[builder(validate("required"))]
+    pub(crate) ts: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ChatDelete {
+            channel: Some("C123ABC456".into()),
+            ts: Some("1405894322.002768".into()),
+        };
+
+        let val = ChatDelete::builder()
+            .set_channel(Some("C123ABC456"))
+            .set_ts(Some("1405894322.002768"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ChatDelete::builder()
+            .channel("C123ABC456")
+            .ts("1405894322.002768")
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_channel_and_ts_fields() {
+        let err = ChatDelete::builder().build().unwrap_err();
+        assert_eq!(err.object(), "ChatDelete");
+
+        for field in ["channel", "ts"] {
+            let errors = err.field(field);
+            assert!(errors.includes(ValidationErrorKind::Required));
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use super::Parse;
+use crate::Message;
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// Request body of
+/// [`chat.postEphemeral`](https://docs.slack.dev/reference/methods/chat.postEphemeral) method.
+///
+/// The fields of the [Message] are flattened into the request body.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/chat.postEphemeral).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | channel | String | Yes | N/A |
+/// | user | String | Yes | N/A |
+/// | message | [Message] | Yes | N/A |
+/// | username | String | No | N/A |
+/// | icon_emoji | String | No | N/A |
+/// | icon_url | String | No | N/A |
+/// | link_names | bool | No | N/A |
+/// | parse | [Parse] | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * `icon_emoji` and `icon_url` are mutually exclusive.
+/// * `response_type`, `replace_original` and `delete_original` of the message are only available
+///   when responding to a `response_url`, so they cannot be set.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::Message;
+/// use slack_messaging::requests::ChatPostEphemeral;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ChatPostEphemeral::builder()
+///     .channel("C123ABC456")
+///     .user("U0BPQUNTA")
+///     .message(Message::builder().text("Only you can see this").build()?)
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "channel": "C123ABC456",
+///     "user": "U0BPQUNTA",
+///     "text": "Only you can see this"
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let body = ChatPostEphemeral::builder()
+///     .channel("C123ABC456")
+///     .message(Message::builder().text("Only you can see this").build()?)
+///     .build();
+///
+/// assert!(body.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate", no_deserialize)]
+pub struct ChatPostEphemeral {
+    #[builder(validate("required"))]
+    pub(crate) channel: Option<String>,
+
+    #[builder(validate("required"))]
+    pub(crate) user: Option<String>,
+
+    #[serde(flatten)]
+    #[builder(validate("required"))]
+    pub(crate) message: Option<Message>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) icon_emoji: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) icon_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) link_names: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parse: Option<Parse>,
+}
+
+fn validate(val: &ChatPostEphemeral) -> Vec<ValidationErrorKind> {
+    let mut errors = match (&val.icon_emoji, &val.icon_url) {
+        (Some(_), Some(_)) => vec![ValidationErrorKind::ExclusiveField(
+            "icon_emoji",
+            "icon_url",
+        )],
+        _ => vec![],
+    };
+
+    if let Some(message) = &val.message {
+        errors.extend(message.response_url_only_fields());
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ChatPostEphemeral {
+            channel: Some("C123ABC456".into()),
+            user: Some("U0BPQUNTA".into()),
+            message: Some(message()),
+            username: Some("bot".into()),
+            icon_emoji: Some(":ghost:".into()),
+            icon_url: None,
+            link_names: Some(true),
+            parse: Some(Parse::None),
+        };
+
+        let val = ChatPostEphemeral::builder()
+            .set_channel(Some("C123ABC456"))
+            .set_user(Some("U0BPQUNTA"))
+            .set_message(Some(message()))
+            .set_username(Some("bot"))
+            .set_icon_emoji(Some(":ghost:"))
+            .set_link_names(Some(true))
+            .set_parse(Some(Parse::None))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ChatPostEphemeral::builder()
+            .channel("C123ABC456")
+            .user("U0BPQUNTA")
+            .message(message())
+            .username("bot")
+            .icon_emoji(":ghost:")
+            .link_names(true)
+            .parse(Parse::None)
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_channel_user_and_message_fields() {
+        let err = ChatPostEphemeral::builder().build().unwrap_err();
+        assert_eq!(err.object(), "ChatPostEphemeral");
+
+        for field in ["channel", "user", "message"] {
+            let errors = err.field(field);
+            assert!(errors.includes(ValidationErrorKind::Required));
+        }
+    }
+
+    #[test]
+    fn it_prevents_from_setting_both_icon_emoji_and_icon_url() {
+        let err = ChatPostEphemeral::builder()
+            .channel("C123ABC456")
+            .user("U0BPQUNTA")
+            .message(message())
+            .icon_emoji(":ghost:")
+            .icon_url("https://example.com/icon.png")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatPostEphemeral");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::ExclusiveField(
+            "icon_emoji",
+            "icon_url"
+        )));
+    }
+
+    #[test]
+    fn it_prevents_from_setting_fields_only_for_response_url() {
+        let message = Message::builder()
+            .text("hello")
+            .ephemeral()
+            .replace_original(true)
+            .delete_original(false)
+            .build()
+            .unwrap();
+
+        let err = ChatPostEphemeral::builder()
+            .channel("C123ABC456")
+            .user("U0BPQUNTA")
+            .message(message)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatPostEphemeral");
+
+        let errors = err.across_fields();
+        for field in ["response_type", "replace_original", "delete_original"] {
+            assert!(errors.includes(ValidationErrorKind::ResponseUrlOnlyField(field)));
+        }
+    }
+
+    fn message() -> Message {
+        Message::builder().text("hello").build().unwrap()
+    }
+}
@@ -0,0 +1,287 @@
+use crate::Message;
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// Request body of [`chat.postMessage`](https://docs.slack.dev/reference/methods/chat.postMessage)
+/// method.
+///
+/// The fields of the [Message] are flattened into the request body.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/chat.postMessage).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | channel | String | Yes | N/A |
+/// | message | [Message] | Yes | N/A |
+/// | username | String | No | N/A |
+/// | icon_emoji | String | No | N/A |
+/// | icon_url | String | No | N/A |
+/// | unfurl_links | bool | No | N/A |
+/// | unfurl_media | bool | No | N/A |
+/// | link_names | bool | No | N/A |
+/// | parse | [Parse] | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * `icon_emoji` and `icon_url` are mutually exclusive.
+/// * `response_type`, `replace_original` and `delete_original` of the message are only available
+///   when responding to a `response_url`, so they cannot be set.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{mrkdwn, Message};
+/// use slack_messaging::blocks::Section;
+/// use slack_messaging::requests::ChatPostMessage;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ChatPostMessage::builder()
+///     .channel("C123ABC456")
+///     .message(
+///         Message::builder()
+///             .text("Deploy finished")
+///             .block(
+///                 Section::builder()
+///                     .text(mrkdwn!("*Deploy finished* :rocket:")?)
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .username("deploy-bot")
+///     .icon_emoji(":rocket:")
+///     .unfurl_links(false)
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "channel": "C123ABC456",
+///     "text": "Deploy finished",
+///     "blocks": [
+///         {
+///             "type": "section",
+///             "text": {
+///                 "type": "mrkdwn",
+///                 "text": "*Deploy finished* :rocket:"
+///             }
+///         }
+///     ],
+///     "username": "deploy-bot",
+///     "icon_emoji": ":rocket:",
+///     "unfurl_links": false
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let body = ChatPostMessage::builder()
+///     .channel("C123ABC456")
+///     .message(Message::builder().text("hello").build()?)
+///     .icon_emoji(":rocket:")
+///     .icon_url("https://example.com/icon.png")
+///     .build();
+///
+/// assert!(body.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate", no_deserialize)]
+pub struct ChatPostMessage {
+    #[builder(validate("required"))]
+    pub(crate) channel: Option<String>,
+
+    #[serde(flatten)]
+    #[builder(validate("required"))]
+    pub(crate) message: Option<Message>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) icon_emoji: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) icon_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) unfurl_links: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) unfurl_media: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) link_names: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parse: Option<Parse>,
+}
+
+/// Values that can be set to the `parse` field of the chat requests. See [Slack's
+/// document](https://docs.slack.dev/messaging/formatting-message-text#automatic-parsing) for
+/// details.
+#[derive(Debug, Copy, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Parse {
+    /// Slack parses the text as if a user posted it, linkifying names and URLs.
+    Full,
+    /// Slack does not parse the text.
+    None,
+}
+
+fn validate(val: &ChatPostMessage) -> Vec<ValidationErrorKind> {
+    let mut errors = match (&val.icon_emoji, &val.icon_url) {
+        (Some(_), Some(_)) => vec![ValidationErrorKind::ExclusiveField(
+            "icon_emoji",
+            "icon_url",
+        )],
+        _ => vec![],
+    };
+
+    if let Some(message) = &val.message {
+        errors.extend(message.response_url_only_fields());
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ChatPostMessage {
+            channel: Some("C123ABC456".into()),
+            message: Some(message()),
+            username: Some("bot".into()),
+            icon_emoji: None,
+            icon_url: Some("https://example.com/icon.png".into()),
+            unfurl_links: Some(false),
+            unfurl_media: Some(true),
+            link_names: Some(true),
+            parse: Some(Parse::Full),
+        };
+
+        let val = ChatPostMessage::builder()
+            .set_channel(Some("C123ABC456"))
+            .set_message(Some(message()))
+            .set_username(Some("bot"))
+            .set_icon_url(Some("https://example.com/icon.png"))
+            .set_unfurl_links(Some(false))
+            .set_unfurl_media(Some(true))
+            .set_link_names(Some(true))
+            .set_parse(Some(Parse::Full))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ChatPostMessage::builder()
+            .channel("C123ABC456")
+            .message(message())
+            .username("bot")
+            .icon_url("https://example.com/icon.png")
+            .unfurl_links(false)
+            .unfurl_media(true)
+            .link_names(true)
+            .parse(Parse::Full)
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_flattens_message_fields() {
+        let val = ChatPostMessage::builder()
+            .channel("C123ABC456")
+            .message(message())
+            .parse(Parse::None)
+            .build()
+            .unwrap();
+
+        let expected = serde_json::json!({
+            "channel": "C123ABC456",
+            "text": "hello",
+            "blocks": [
+                { "type": "section", "text": { "type": "mrkdwn", "text": "hello" } }
+            ],
+            "parse": "none"
+        });
+
+        assert_eq!(serde_json::to_value(val).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_requires_channel_and_message_fields() {
+        let err = ChatPostMessage::builder().build().unwrap_err();
+        assert_eq!(err.object(), "ChatPostMessage");
+
+        let errors = err.field("channel");
+        assert!(errors.includes(ValidationErrorKind::Required));
+
+        let errors = err.field("message");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_prevents_from_setting_both_icon_emoji_and_icon_url() {
+        let err = ChatPostMessage::builder()
+            .channel("C123ABC456")
+            .message(message())
+            .icon_emoji(":ghost:")
+            .icon_url("https://example.com/icon.png")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatPostMessage");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::ExclusiveField(
+            "icon_emoji",
+            "icon_url"
+        )));
+    }
+
+    #[test]
+    fn it_prevents_from_setting_fields_only_for_response_url() {
+        let message = Message::builder()
+            .text("hello")
+            .ephemeral()
+            .replace_original(true)
+            .delete_original(false)
+            .build()
+            .unwrap();
+
+        let err = ChatPostMessage::builder()
+            .channel("C123ABC456")
+            .message(message)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatPostMessage");
+
+        let errors = err.across_fields();
+        for field in ["response_type", "replace_original", "delete_original"] {
+            assert!(errors.includes(ValidationErrorKind::ResponseUrlOnlyField(field)));
+        }
+    }
+
+    fn message() -> Message {
+        Message::builder()
+            .text("hello")
+            .block(section("hello"))
+            .build()
+            .unwrap()
+    }
+}
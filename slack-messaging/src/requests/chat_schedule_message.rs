@@ -0,0 +1,201 @@
+use super::Parse;
+use crate::Message;
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// Request body of
+/// [`chat.scheduleMessage`](https://docs.slack.dev/reference/methods/chat.scheduleMessage) method.
+///
+/// The fields of the [Message] are flattened into the request body.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/chat.scheduleMessage).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | channel | String | Yes | N/A |
+/// | post_at | i64 | Yes | 10 digits |
+/// | message | [Message] | Yes | N/A |
+/// | unfurl_links | bool | No | N/A |
+/// | unfurl_media | bool | No | N/A |
+/// | link_names | bool | No | N/A |
+/// | parse | [Parse] | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * `response_type`, `replace_original` and `delete_original` of the message are only available
+///   when responding to a `response_url`, so they cannot be set.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::Message;
+/// use slack_messaging::requests::ChatScheduleMessage;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ChatScheduleMessage::builder()
+///     .channel("C123ABC456")
+///     .post_at(1780000000)
+///     .message(Message::builder().text("Stand-up in 5 minutes").build()?)
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "channel": "C123ABC456",
+///     "post_at": 1780000000,
+///     "text": "Stand-up in 5 minutes"
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let body = ChatScheduleMessage::builder()
+///     .channel("C123ABC456")
+///     .post_at(178000)
+///     .message(Message::builder().text("Stand-up in 5 minutes").build()?)
+///     .build();
+///
+/// assert!(body.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate", no_deserialize)]
+pub struct ChatScheduleMessage {
+    #[builder(validate("required"))]
+    pub(crate) channel: Option<String>,
+
+    #[builder(validate("required", "integer::ten_digits"))]
+    pub(crate) post_at: Option<i64>,
+
+    #[serde(flatten)]
+    #[builder(validate("required"))]
+    pub(crate) message: Option<Message>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) unfurl_links: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) unfurl_media: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) link_names: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parse: Option<Parse>,
+}
+
+fn validate(val: &ChatScheduleMessage) -> Vec<ValidationErrorKind> {
+    val.message
+        .as_ref()
+        .map(Message::response_url_only_fields)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ChatScheduleMessage {
+            channel: Some("C123ABC456".into()),
+            post_at: Some(1780000000),
+            message: Some(message()),
+            unfurl_links: Some(true),
+            unfurl_media: Some(false),
+            link_names: Some(true),
+            parse: Some(Parse::Full),
+        };
+
+        let val = ChatScheduleMessage::builder()
+            .set_channel(Some("C123ABC456"))
+            .set_post_at(Some(1780000000))
+            .set_message(Some(message()))
+            .set_unfurl_links(Some(true))
+            .set_unfurl_media(Some(false))
+            .set_link_names(Some(true))
+            .set_parse(Some(Parse::Full))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ChatScheduleMessage::builder()
+            .channel("C123ABC456")
+            .post_at(1780000000)
+            .message(message())
+            .unfurl_links(true)
+            .unfurl_media(false)
+            .link_names(true)
+            .parse(Parse::Full)
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_channel_post_at_and_message_fields() {
+        let err = ChatScheduleMessage::builder().build().unwrap_err();
+        assert_eq!(err.object(), "ChatScheduleMessage");
+
+        for field in ["channel", "post_at", "message"] {
+            let errors = err.field(field);
+            assert!(errors.includes(ValidationErrorKind::Required));
+        }
+    }
+
+    #[test]
+    fn it_requires_post_at_to_be_unix_timestamp() {
+        let err = ChatScheduleMessage::builder()
+            .channel("C123ABC456")
+            .post_at(1780000000000_i64)
+            .message(message())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatScheduleMessage");
+
+        let errors = err.field("post_at");
+        assert!(errors.includes(ValidationErrorKind::InvalidFormat("10 digits")));
+    }
+
+    #[test]
+    fn it_prevents_from_setting_fields_only_for_response_url() {
+        let message = Message::builder()
+            .text("hello")
+            .ephemeral()
+            .replace_original(true)
+            .delete_original(false)
+            .build()
+            .unwrap();
+
+        let err = ChatScheduleMessage::builder()
+            .channel("C123ABC456")
+            .post_at(1780000000)
+            .message(message)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatScheduleMessage");
+
+        let errors = err.across_fields();
+        for field in ["response_type", "replace_original", "delete_original"] {
+            assert!(errors.includes(ValidationErrorKind::ResponseUrlOnlyField(field)));
+        }
+    }
+
+    fn message() -> Message {
+        Message::builder().text("hello").build().unwrap()
+    }
+}
@@ -0,0 +1,434 @@
+use crate::Validate;
+use crate::blocks::Block;
+use crate::errors::{
+    NestedValidationError, ValidationError, ValidationErrorKind, ValidationErrors,
+};
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Request body of [`chat.unfurl`](https://docs.slack.dev/reference/methods/chat.unfurl) method.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/chat.unfurl).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | channel | String | Conditionally* | N/A |
+/// | ts | String | Conditionally* | N/A |
+/// | unfurl_id | String | Conditionally* | N/A |
+/// | source | String | Conditionally* | N/A |
+/// | unfurls | [Unfurls] | Yes | N/A |
+/// | user_auth_message | String | No | N/A |
+/// | user_auth_required | bool | No | N/A |
+/// | user_auth_url | String | No | N/A |
+/// | user_auth_blocks | Vec<[Block]> | No | Maximum 50 items, available in [message](crate::Surface) |
+///
+/// # Validation Across Fields
+///
+/// * Either `channel` and `ts`, or `unfurl_id` and `source` is required.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::mrkdwn;
+/// use slack_messaging::blocks::Section;
+/// use slack_messaging::requests::{ChatUnfurl, Unfurl, Unfurls};
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ChatUnfurl::builder()
+///     .channel("C123ABC456")
+///     .ts("1579112306.000300")
+///     .unfurls(
+///         Unfurls::builder()
+///             .unfurl(
+///                 "https://example.com/tickets/1",
+///                 Unfurl::builder()
+///                     .block(
+///                         Section::builder()
+///                             .text(mrkdwn!("*Ticket #1*: Printer is on fire")?)
+///                             .build()?
+///                     )
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "channel": "C123ABC456",
+///     "ts": "1579112306.000300",
+///     "unfurls": {
+///         "https://example.com/tickets/1": {
+///             "blocks": [
+///                 {
+///                     "type": "section",
+///                     "text": {
+///                         "type": "mrkdwn",
+///                         "text": "*Ticket #1*: Printer is on fire"
+///                     }
+///                 }
+///             ]
+///         }
+///     }
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate", no_deserialize)]
+pub struct ChatUnfurl {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) channel: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ts: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) unfurl_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) source: Option<String>,
+
+    #[builder(validate("required"))]
+    pub(crate) unfurls: Option<Unfurls>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) user_auth_message: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) user_auth_required: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) user_auth_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(
+        push_item = "user_auth_block",
+        validate("list::max_item_50", "surface::message")
+    )]
+    pub(crate) user_auth_blocks: Option<Vec<Block>>,
+}
+
+fn validate(val: &ChatUnfurl) -> Vec<ValidationErrorKind> {
+    let by_message = val.channel.is_some() && val.ts.is_some();
+    let by_unfurl_id = val.unfurl_id.is_some() && val.source.is_some();
+
+    if by_message || by_unfurl_id {
+        vec![]
+    } else {
+        vec![ValidationErrorKind::EitherRequired(
+            "channel and ts",
+            "unfurl_id and source",
+        )]
+    }
+}
+
+/// Preview of a URL in [ChatUnfurl].
+///
+/// # Fields and Validations
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | blocks | Vec<[Block]> | Yes | Maximum 50 items, available in [message](crate::Surface) |
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(no_deserialize)]
+pub struct Unfurl {
+    #[builder(
+        push_item = "block",
+        validate("required", "list::max_item_50", "surface::message")
+    )]
+    pub(crate) blocks: Option<Vec<Block>>,
+}
+
+/// Map of URLs to their [Unfurl] in [ChatUnfurl].
+///
+/// URLs must be exactly the ones Slack sent in the
+/// [`link_shared`](crate::events::LinkShared) event. At least one URL is required.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Unfurls(BTreeMap<String, Unfurl>);
+
+impl Unfurls {
+    /// constructs a [`UnfurlsBuilder`].
+    pub fn builder() -> UnfurlsBuilder {
+        UnfurlsBuilder::default()
+    }
+
+    /// Returns the preview for the URL.
+    pub fn get(&self, url: &str) -> Option<&Unfurl> {
+        self.0.get(url)
+    }
+}
+
+/// Builder for [Unfurls] object.
+#[derive(Debug, Default)]
+pub struct UnfurlsBuilder {
+    unfurls: BTreeMap<String, Unfurl>,
+}
+
+impl UnfurlsBuilder {
+    /// Adds the preview for the URL. A preview already added for the same URL is replaced.
+    pub fn unfurl(mut self, url: impl Into<String>, unfurl: impl Into<Unfurl>) -> Self {
+        self.unfurls.insert(url.into(), unfurl.into());
+        self
+    }
+
+    /// Builds [Unfurls] object. Returns error if no URL is added or any URL is empty.
+    pub fn build(self) -> Result<Unfurls, ValidationErrors> {
+        let mut errors = vec![];
+
+        if self.unfurls.is_empty() {
+            errors.extend(ValidationError::new_across_fields(vec![
+                ValidationErrorKind::EmptyArray,
+            ]));
+        }
+
+        if self.unfurls.keys().any(|url| url.is_empty()) {
+            errors.push(ValidationError::SingleField {
+                field: Cow::Borrowed("url"),
+                errors: vec![ValidationErrorKind::MinTextLength { min: 1, actual: 0 }],
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(Unfurls(self.unfurls))
+        } else {
+            Err(ValidationErrors {
+                object: Cow::Borrowed("Unfurls"),
+                errors,
+            })
+        }
+    }
+
+    /// Builds [Unfurls] object without validations. Call
+    /// [`Validate::validate`](crate::Validate::validate) on the object containing it to get all
+    /// errors at once.
+    pub fn build_unchecked(self) -> Unfurls {
+        Unfurls(self.unfurls)
+    }
+}
+
+impl Validate for Unfurls {
+    fn validate_at(&self, path: &str, errors: &mut Vec<NestedValidationError>) {
+        if self.0.is_empty() {
+            crate::validate::push_errors(
+                errors,
+                path,
+                "Unfurls",
+                vec![ValidationErrorKind::EmptyArray],
+            );
+        }
+
+        for (url, unfurl) in self.0.iter() {
+            let url_path = format!("{path}[{url:?}]");
+
+            if url.is_empty() {
+                crate::validate::push_errors(
+                    errors,
+                    &url_path,
+                    "Unfurls",
+                    vec![ValidationErrorKind::MinTextLength { min: 1, actual: 0 }],
+                );
+            }
+
+            unfurl.validate_at(&url_path, errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ChatUnfurl {
+            channel: Some("C123ABC456".into()),
+            ts: Some("1579112306.000300".into()),
+            unfurl_id: None,
+            source: None,
+            unfurls: Some(unfurls()),
+            user_auth_message: Some("Please sign in".into()),
+            user_auth_required: Some(true),
+            user_auth_url: Some("https://example.com/auth".into()),
+            user_auth_blocks: Some(vec![section("sign in").into()]),
+        };
+
+        let val = ChatUnfurl::builder()
+            .set_channel(Some("C123ABC456"))
+            .set_ts(Some("1579112306.000300"))
+            .set_unfurls(Some(unfurls()))
+            .set_user_auth_message(Some("Please sign in"))
+            .set_user_auth_required(Some(true))
+            .set_user_auth_url(Some("https://example.com/auth"))
+            .set_user_auth_blocks(Some(vec![section("sign in").into()] as Vec<Block>))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ChatUnfurl::builder()
+            .channel("C123ABC456")
+            .ts("1579112306.000300")
+            .unfurls(unfurls())
+            .user_auth_message("Please sign in")
+            .user_auth_required(true)
+            .user_auth_url("https://example.com/auth")
+            .user_auth_block(section("sign in"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_accepts_unfurl_id_and_source_instead_of_channel_and_ts() {
+        let val = ChatUnfurl::builder()
+            .unfurl_id("Uxxxxxxx-909b5454-75f8-4ac4-b325-1b40e230bbd8")
+            .source("composer")
+            .unfurls(unfurls())
+            .build();
+
+        assert!(val.is_ok());
+    }
+
+    #[test]
+    fn it_requires_either_channel_and_ts_or_unfurl_id_and_source() {
+        let err = ChatUnfurl::builder()
+            .channel("C123ABC456")
+            .source("composer")
+            .unfurls(unfurls())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatUnfurl");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::EitherRequired(
+            "channel and ts",
+            "unfurl_id and source"
+        )));
+    }
+
+    #[test]
+    fn it_requires_unfurls_field() {
+        let err = ChatUnfurl::builder()
+            .channel("C123ABC456")
+            .ts("1579112306.000300")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatUnfurl");
+
+        let errors = err.field("unfurls");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_user_auth_blocks_list_size_less_than_50() {
+        let blocks: Vec<Block> = (0..51).map(|_| section("foo").into()).collect();
+        let err = ChatUnfurl::builder()
+            .channel("C123ABC456")
+            .ts("1579112306.000300")
+            .unfurls(unfurls())
+            .user_auth_blocks(blocks)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatUnfurl");
+
+        let errors = err.field("user_auth_blocks");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 50,
+            actual: 51
+        }));
+    }
+
+    #[test]
+    fn it_requires_unfurl_blocks() {
+        let err = Unfurl::builder().build().unwrap_err();
+        assert_eq!(err.object(), "Unfurl");
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::Required));
+
+        let blocks: Vec<Block> = (0..51).map(|_| section("foo").into()).collect();
+        let err = Unfurl::builder().blocks(blocks).build().unwrap_err();
+
+        let errors = err.field("blocks");
+        assert!(errors.includes(ValidationErrorKind::MaxArraySize {
+            max: 50,
+            actual: 51
+        }));
+    }
+
+    #[test]
+    fn it_serializes_unfurls_keyed_by_url() {
+        let unfurls = Unfurls::builder()
+            .unfurl("https://example.com/b", unfurl("b"))
+            .unfurl("https://example.com/a", unfurl("a"))
+            .build()
+            .unwrap();
+
+        assert_eq!(unfurls.get("https://example.com/a"), Some(&unfurl("a")));
+
+        let expected = serde_json::json!({
+            "https://example.com/a": {
+                "blocks": [
+                    { "type": "section", "text": { "type": "mrkdwn", "text": "a" } }
+                ]
+            },
+            "https://example.com/b": {
+                "blocks": [
+                    { "type": "section", "text": { "type": "mrkdwn", "text": "b" } }
+                ]
+            }
+        });
+
+        assert_eq!(serde_json::to_value(unfurls).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_requires_at_least_one_unfurl() {
+        let err = Unfurls::builder().build().unwrap_err();
+        assert_eq!(err.object(), "Unfurls");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::EmptyArray));
+    }
+
+    #[test]
+    fn it_requires_non_empty_url() {
+        let err = Unfurls::builder()
+            .unfurl("", unfurl("a"))
+            .build()
+            .unwrap_err();
+
+        let errors = err.field("url");
+        assert!(errors.includes(ValidationErrorKind::MinTextLength { min: 1, actual: 0 }));
+    }
+
+    fn unfurl(text: &str) -> Unfurl {
+        Unfurl {
+            blocks: Some(vec![section(text).into()]),
+        }
+    }
+
+    fn unfurls() -> Unfurls {
+        Unfurls::builder()
+            .unfurl("https://example.com/tickets/1", unfurl("Ticket #1"))
+            .build()
+            .unwrap()
+    }
+}
@@ -0,0 +1,171 @@
+use super::Parse;
+use crate::Message;
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// Request body of [`chat.update`](https://docs.slack.dev/reference/methods/chat.update) method.
+///
+/// The fields of the [Message] are flattened into the request body.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/chat.update).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | channel | String | Yes | N/A |
+/// | ts | String | Yes | N/A |
+/// | message | [Message] | Yes | N/A |
+/// | link_names | bool | No | N/A |
+/// | parse | [Parse] | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * `response_type`, `replace_original` and `delete_original` of the message are only available
+///   when responding to a `response_url`, so they cannot be set.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::Message;
+/// use slack_messaging::requests::ChatUpdate;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ChatUpdate::builder()
+///     .channel("C123ABC456")
+///     .ts("1405894322.002768")
+///     .message(Message::builder().text("Updated text").build()?)
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "channel": "C123ABC456",
+///     "ts": "1405894322.002768",
+///     "text": "Updated text"
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let body = ChatUpdate::builder()
+///     .channel("C123ABC456")
+///     .message(Message::builder().text("Updated text").build()?)
+///     .build();
+///
+/// assert!(body.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate", no_deserialize)]
+pub struct ChatUpdate {
+    #[builder(validate("required"))]
+    pub(crate) channel: Option<String>,
+
+    #[builder(validate("required"))]
+    pub(crate) ts: Option<String>,
+
+    #[serde(flatten)]
+    #[builder(validate("required"))]
+    pub(crate) message: Option<Message>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) link_names: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parse: Option<Parse>,
+}
+
+fn validate(val: &ChatUpdate) -> Vec<ValidationErrorKind> {
+    val.message
+        .as_ref()
+        .map(Message::response_url_only_fields)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ChatUpdate {
+            channel: Some("C123ABC456".into()),
+            ts: Some("1405894322.002768".into()),
+            message: Some(message()),
+            link_names: Some(false),
+            parse: Some(Parse::Full),
+        };
+
+        let val = ChatUpdate::builder()
+            .set_channel(Some("C123ABC456"))
+            .set_ts(Some("1405894322.002768"))
+            .set_message(Some(message()))
+            .set_link_names(Some(false))
+            .set_parse(Some(Parse::Full))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ChatUpdate::builder()
+            .channel("C123ABC456")
+            .ts("1405894322.002768")
+            .message(message())
+            .link_names(false)
+            .parse(Parse::Full)
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_channel_ts_and_message_fields() {
+        let err = ChatUpdate::builder().build().unwrap_err();
+        assert_eq!(err.object(), "ChatUpdate");
+
+        for field in ["channel", "ts", "message"] {
+            let errors = err.field(field);
+            assert!(errors.includes(ValidationErrorKind::Required));
+        }
+    }
+
+    #[test]
+    fn it_prevents_from_setting_fields_only_for_response_url() {
+        let message = Message::builder()
+            .text("hello")
+            .ephemeral()
+            .replace_original(true)
+            .delete_original(false)
+            .build()
+            .unwrap();
+
+        let err = ChatUpdate::builder()
+            .channel("C123ABC456")
+            .ts("1405894322.002768")
+            .message(message)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ChatUpdate");
+
+        let errors = err.across_fields();
+        for field in ["response_type", "replace_original", "delete_original"] {
+            assert!(errors.includes(ValidationErrorKind::ResponseUrlOnlyField(field)));
+        }
+    }
+
+    fn message() -> Message {
+        Message::builder().text("hello").build().unwrap()
+    }
+}
@@ -0,0 +1,23 @@
+mod chat_delete;
+mod chat_post_ephemeral;
+mod chat_post_message;
+mod chat_schedule_message;
+mod chat_unfurl;
+mod chat_update;
+mod views_open;
+mod views_publish;
+mod views_push;
+mod views_update;
+
+pub use chat_delete::{ChatDelete, ChatDeleteBuilder};
+pub use chat_post_ephemeral::{ChatPostEphemeral, ChatPostEphemeralBuilder};
+pub use chat_post_message::{ChatPostMessage, ChatPostMessageBuilder, Parse};
+pub use chat_schedule_message::{ChatScheduleMessage, ChatScheduleMessageBuilder};
+pub use chat_unfurl::{
+    ChatUnfurl, ChatUnfurlBuilder, Unfurl, UnfurlBuilder, Unfurls, UnfurlsBuilder,
+};
+pub use chat_update::{ChatUpdate, ChatUpdateBuilder};
+pub use views_open::{ViewsOpen, ViewsOpenBuilder};
+pub use views_publish::{ViewsPublish, ViewsPublishBuilder};
+pub use views_push::{ViewsPush, ViewsPushBuilder};
+pub use views_update::{View, ViewsUpdate, ViewsUpdateBuilder};
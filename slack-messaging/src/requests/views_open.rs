@@ -0,0 +1,188 @@
+use crate::Modal;
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// Request body of [`views.open`](https://docs.slack.dev/reference/methods/views.open) method.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/views.open).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | trigger_id | String | Conditionally | N/A |
+/// | interactivity_pointer | String | Conditionally | N/A |
+/// | view | [Modal] | Yes | N/A |
+///
+/// # Validation Across Fields
+///
+/// * Either `trigger_id` or `interactivity_pointer` is required.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{plain_text, Modal};
+/// use slack_messaging::blocks::Section;
+/// use slack_messaging::requests::ViewsOpen;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ViewsOpen::builder()
+///     .trigger_id("12345.98765.abcd2358fdea")
+///     .view(
+///         Modal::builder()
+///             .title(plain_text!("My App")?)
+///             .block(
+///                 Section::builder()
+///                     .text(plain_text!("Hello!")?)
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "trigger_id": "12345.98765.abcd2358fdea",
+///     "view": {
+///         "type": "modal",
+///         "title": {
+///             "type": "plain_text",
+///             "text": "My App"
+///         },
+///         "blocks": [
+///             {
+///                 "type": "section",
+///                 "text": {
+///                     "type": "plain_text",
+///                     "text": "Hello!"
+///                 }
+///             }
+///         ]
+///     }
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let body = ViewsOpen::builder()
+///     .view(
+///         Modal::builder()
+///             .title(plain_text!("My App")?)
+///             .block(
+///                 Section::builder()
+///                     .text(plain_text!("Hello!")?)
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .build();
+///
+/// assert!(body.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate", no_deserialize)]
+pub struct ViewsOpen {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) trigger_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) interactivity_pointer: Option<String>,
+
+    #[builder(validate("required"))]
+    pub(crate) view: Option<Modal>,
+}
+
+fn validate(val: &ViewsOpen) -> Vec<ValidationErrorKind> {
+    match (&val.trigger_id, &val.interactivity_pointer) {
+        (None, None) => vec![ValidationErrorKind::EitherRequired(
+            "trigger_id",
+            "interactivity_pointer",
+        )],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ViewsOpen {
+            trigger_id: Some("12345.98765.abcd2358fdea".into()),
+            interactivity_pointer: None,
+            view: Some(modal()),
+        };
+
+        let val = ViewsOpen::builder()
+            .set_trigger_id(Some("12345.98765.abcd2358fdea"))
+            .set_view(Some(modal()))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ViewsOpen::builder()
+            .trigger_id("12345.98765.abcd2358fdea")
+            .view(modal())
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_accepts_interactivity_pointer() {
+        let val = ViewsOpen::builder()
+            .interactivity_pointer("12345.98765.abcd2358fdea")
+            .view(modal())
+            .build();
+
+        assert!(val.is_ok());
+    }
+
+    #[test]
+    fn it_requires_view_field() {
+        let err = ViewsOpen::builder()
+            .trigger_id("12345.98765.abcd2358fdea")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ViewsOpen");
+
+        let errors = err.field("view");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_either_trigger_id_or_interactivity_pointer() {
+        let err = ViewsOpen::builder().view(modal()).build().unwrap_err();
+        assert_eq!(err.object(), "ViewsOpen");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::EitherRequired(
+            "trigger_id",
+            "interactivity_pointer"
+        )));
+    }
+
+    fn modal() -> Modal {
+        Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .build()
+            .unwrap()
+    }
+}
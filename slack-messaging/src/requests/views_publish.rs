@@ -0,0 +1,150 @@
+use crate::HomeView;
+use crate::validators::*;
+
+use serde::Serialize;
+use slack_messaging_derive::Builder;
+
+/// Request body of [`views.publish`](https://docs.slack.dev/reference/methods/views.publish)
+/// method.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/views.publish).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | user_id | String | Yes | N/A |
+/// | view | [HomeView] | Yes | N/A |
+/// | hash | String | No | N/A |
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{mrkdwn, HomeView};
+/// use slack_messaging::blocks::Section;
+/// use slack_messaging::requests::ViewsPublish;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ViewsPublish::builder()
+///     .user_id("U0123ABCD")
+///     .view(
+///         HomeView::builder()
+///             .block(
+///                 Section::builder()
+///                     .text(mrkdwn!("*Welcome home!*")?)
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .hash("156772938.1827394")
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "user_id": "U0123ABCD",
+///     "view": {
+///         "type": "home",
+///         "blocks": [
+///             {
+///                 "type": "section",
+///                 "text": {
+///                     "type": "mrkdwn",
+///                     "text": "*Welcome home!*"
+///                 }
+///             }
+///         ]
+///     },
+///     "hash": "156772938.1827394"
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let body = ViewsPublish::builder().user_id("U0123ABCD").build();
+///
+/// assert!(body.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(no_deserialize)]
+pub struct ViewsPublish {
+    #[builder(validate("required"))]
+    pub(crate) user_id: Option<String>,
+
+    #[builder(validate("required"))]
+    pub(crate) view: Option<HomeView>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hash: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+    use crate::errors::ValidationErrorKind;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ViewsPublish {
+            user_id: Some("U0123ABCD".into()),
+            view: Some(home()),
+            hash: Some("156772938.1827394".into()),
+        };
+
+        let val = ViewsPublish::builder()
+            .set_user_id(Some("U0123ABCD"))
+            .set_view(Some(home()))
+            .set_hash(Some("156772938.1827394"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ViewsPublish::builder()
+            .user_id("U0123ABCD")
+            .view(home())
+            .hash("156772938.1827394")
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_requires_user_id_field() {
+        let err = ViewsPublish::builder().view(home()).build().unwrap_err();
+        assert_eq!(err.object(), "ViewsPublish");
+
+        let errors = err.field("user_id");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_view_field() {
+        let err = ViewsPublish::builder()
+            .user_id("U0123ABCD")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ViewsPublish");
+
+        let errors = err.field("view");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    fn home() -> HomeView {
+        HomeView {
+            blocks: Some(vec![section("foo").into()]),
+            private_metadata: None,
+            callback_id: None,
+            external_id: None,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::errors::ValidationErrorKind;
+use crate::validators::*;
+use crate::{HomeView, Modal};
+
+use serde::Serialize;
+use slack_messaging_derive::{Builder, Validate};
+
+/// Request body of [`views.update`](https://docs.slack.dev/reference/methods/views.update)
+/// method.
+///
+/// # Fields and Validations
+///
+/// For more details, see the [official
+/// documentation](https://docs.slack.dev/reference/methods/views.update).
+///
+/// | Field | Type | Required | Validation |
+/// |-------|------|----------|------------|
+/// | view_id | String | Conditionally | N/A |
+/// | external_id | String | Conditionally | N/A |
+/// | view | [View] | Yes | N/A |
+/// | hash | String | No | N/A |
+///
+/// # Validation Across Fields
+///
+/// * Either `view_id` or `external_id` is required.
+/// * `view_id` and `external_id` are mutually exclusive.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{plain_text, Modal};
+/// use slack_messaging::blocks::Section;
+/// use slack_messaging::requests::ViewsUpdate;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let body = ViewsUpdate::builder()
+///     .view_id("VMM512F2U")
+///     .view(
+///         Modal::builder()
+///             .title(plain_text!("My App")?)
+///             .block(
+///                 Section::builder()
+///                     .text(plain_text!("Updated!")?)
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .hash("156772938.1827394")
+///     .build()?;
+///
+/// let expected = serde_json::json!({
+///     "view_id": "VMM512F2U",
+///     "view": {
+///         "type": "modal",
+///         "title": {
+///             "type": "plain_text",
+///             "text": "My App"
+///         },
+///         "blocks": [
+///             {
+///                 "type": "section",
+///                 "text": {
+///                     "type": "plain_text",
+///                     "text": "Updated!"
+///                 }
+///             }
+///         ]
+///     },
+///     "hash": "156772938.1827394"
+/// });
+///
+/// let json = serde_json::to_value(body).unwrap();
+///
+/// assert_eq!(json, expected);
+///
+/// // If your object has any validation errors, the build method returns Result::Err
+/// let body = ViewsUpdate::builder()
+///     .view(
+///         Modal::builder()
+///             .title(plain_text!("My App")?)
+///             .block(
+///                 Section::builder()
+///                     .text(plain_text!("Hello!")?)
+///                     .build()?
+///             )
+///             .build()?
+///     )
+///     .build();
+///
+/// assert!(body.is_err());
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[builder(validate = "validate", no_deserialize)]
+pub struct ViewsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) view_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) external_id: Option<String>,
+
+    #[builder(validate("required"))]
+    pub(crate) view: Option<View>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hash: Option<String>,
+}
+
+/// View to update with [ViewsUpdate], either a modal or the App Home tab.
+#[derive(Debug, Clone, Serialize, PartialEq, Validate)]
+#[serde(untagged)]
+pub enum View {
+    /// [Modal](https://docs.slack.dev/surfaces/modals) representation
+    Modal(Box<Modal>),
+
+    /// [App Home tab](https://docs.slack.dev/surfaces/app-home) representation
+    Home(Box<HomeView>),
+}
+
+impl From<Modal> for View {
+    fn from(value: Modal) -> Self {
+        Self::Modal(Box::new(value))
+    }
+}
+
+impl From<HomeView> for View {
+    fn from(value: HomeView) -> Self {
+        Self::Home(Box::new(value))
+    }
+}
+
+fn validate(val: &ViewsUpdate) -> Vec<ValidationErrorKind> {
+    match (&val.view_id, &val.external_id) {
+        (None, None) => vec![ValidationErrorKind::EitherRequired(
+            "view_id",
+            "external_id",
+        )],
+        (Some(_), Some(_)) => vec![ValidationErrorKind::ExclusiveField(
+            "view_id",
+            "external_id",
+        )],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+    use crate::composition_objects::test_helpers::*;
+
+    #[test]
+    fn it_implements_builder() {
+        let expected = ViewsUpdate {
+            view_id: Some("VMM512F2U".into()),
+            external_id: None,
+            view: Some(modal().into()),
+            hash: Some("156772938.1827394".into()),
+        };
+
+        let val = ViewsUpdate::builder()
+            .set_view_id(Some("VMM512F2U"))
+            .set_view(Some(modal()))
+            .set_hash(Some("156772938.1827394"))
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+
+        let val = ViewsUpdate::builder()
+            .view_id("VMM512F2U")
+            .view(modal())
+            .hash("156772938.1827394")
+            .build()
+            .unwrap();
+
+        assert_eq!(val, expected);
+    }
+
+    #[test]
+    fn it_accepts_external_id() {
+        let val = ViewsUpdate::builder()
+            .external_id("bmarley_view2")
+            .view(modal())
+            .build();
+
+        assert!(val.is_ok());
+    }
+
+    #[test]
+    fn it_accepts_home_view() {
+        let home = HomeView::builder().block(section("foo")).build().unwrap();
+        let val = ViewsUpdate::builder()
+            .view_id("VMM512F2U")
+            .view(home.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(val.view, Some(View::Home(Box::new(home))));
+
+        let json = serde_json::to_value(val).unwrap();
+        assert_eq!(json["view"]["type"], "home");
+    }
+
+    #[test]
+    fn it_requires_view_field() {
+        let err = ViewsUpdate::builder()
+            .view_id("VMM512F2U")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ViewsUpdate");
+
+        let errors = err.field("view");
+        assert!(errors.includes(ValidationErrorKind::Required));
+    }
+
+    #[test]
+    fn it_requires_either_view_id_or_external_id() {
+        let err = ViewsUpdate::builder().view(modal()).build().unwrap_err();
+        assert_eq!(err.object(), "ViewsUpdate");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::EitherRequired(
+            "view_id",
+            "external_id"
+        )));
+    }
+
+    #[test]
+    fn it_prevents_from_setting_both_view_id_and_external_id() {
+        let err = ViewsUpdate::builder()
+            .view_id("VMM512F2U")
+            .external_id("bmarley_view2")
+            .view(modal())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.object(), "ViewsUpdate");
+
+        let errors = err.across_fields();
+        assert!(errors.includes(ValidationErrorKind::ExclusiveField(
+            "view_id",
+            "external_id"
+        )));
+    }
+
+    fn modal() -> Modal {
+        Modal::builder()
+            .title(plain_text("My App"))
+            .block(section("foo"))
+            .build()
+            .unwrap()
+    }
+}
@@ -0,0 +1,398 @@
+use crate::errors::ResponseUrlError;
+use crate::{Message, ResponseType};
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Number of times a `response_url` can be used.
+pub const MAX_USES: u8 = 5;
+
+/// Seconds a `response_url` stays valid after it is issued.
+pub const EXPIRES_IN: u64 = 60 * 30;
+
+/// Responder through the `response_url` Slack sends with interactions and slash commands.
+///
+/// Each operation builds the payload to post to [`url`](Self::url) with the right
+/// `response_type`, `replace_original` and `delete_original` fields, and counts a use of the
+/// URL. Slack accepts up to 5 responses within 30 minutes of the interaction, so the operations
+/// return [`ResponseUrlError`] once the URL has been used 5 times or has expired.
+///
+/// With the `response-url` feature, [`send`](Self::send) also posts the payload.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::Message;
+/// use slack_messaging::errors::ResponseUrlError;
+/// use slack_messaging::response_url::ResponseUrl;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let mut response_url =
+///     ResponseUrl::new("https://hooks.slack.com/actions/T0MJR11A4/21974584944/yk1S9ndf35Q1flupVG5JbpM6");
+///
+/// let payload = response_url.replace(Message::builder().text("Approved!").build()?)?;
+///
+/// let expected = serde_json::json!({
+///     "text": "Approved!",
+///     "replace_original": true
+/// });
+///
+/// assert_eq!(serde_json::to_value(payload)?, expected);
+///
+/// let payload = response_url.delete()?;
+///
+/// let expected = serde_json::json!({
+///     "delete_original": true
+/// });
+///
+/// assert_eq!(serde_json::to_value(payload)?, expected);
+/// assert_eq!(response_url.remaining_uses(), 3);
+///
+/// // The response_url can be used up to 5 times.
+/// for _ in 0..3 {
+///     response_url.delete()?;
+/// }
+///
+/// assert!(matches!(
+///     response_url.delete(),
+///     Err(ResponseUrlError::UsesExhausted(5)),
+/// ));
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseUrl {
+    url: String,
+    issued_at: i64,
+    uses: u8,
+}
+
+/// Operation through the `response_url`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseAction {
+    /// Posts a new message visible only to the user who interacted.
+    Ephemeral(Message),
+    /// Posts a new message visible to all members of the channel.
+    InChannel(Message),
+    /// Replaces the message the interaction came from.
+    Replace(Message),
+    /// Deletes the message the interaction came from.
+    Delete,
+}
+
+impl ResponseAction {
+    /// Returns the payload to post to the `response_url`.
+    pub fn into_payload(self) -> Message {
+        match self {
+            Self::Ephemeral(message) => Message {
+                response_type: Some(ResponseType::Ephemeral),
+                replace_original: Some(false),
+                delete_original: None,
+                ..message
+            },
+            Self::InChannel(message) => Message {
+                response_type: Some(ResponseType::InChannel),
+                replace_original: Some(false),
+                delete_original: None,
+                ..message
+            },
+            Self::Replace(message) => Message {
+                replace_original: Some(true),
+                delete_original: None,
+                ..message
+            },
+            Self::Delete => Message {
+                text: None,
+                blocks: None,
+                attachments: None,
+                thread_ts: None,
+                mrkdwn: None,
+                response_type: None,
+                replace_original: None,
+                delete_original: Some(true),
+                reply_broadcast: None,
+                metadata: None,
+            },
+        }
+    }
+}
+
+impl ResponseUrl {
+    /// Constructs a responder issued at the current system time.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            issued_at: now(),
+            uses: 0,
+        }
+    }
+
+    /// Sets the unix timestamp the `response_url` was issued at.
+    pub fn issued_at(mut self, timestamp: i64) -> Self {
+        self.issued_at = timestamp;
+        self
+    }
+
+    /// Returns the `response_url`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns how many more times the `response_url` can be used.
+    pub fn remaining_uses(&self) -> u8 {
+        MAX_USES.saturating_sub(self.uses)
+    }
+
+    /// Builds the payload to post a new ephemeral message.
+    pub fn reply_ephemeral(&mut self, message: Message) -> Result<Message, ResponseUrlError> {
+        self.respond(ResponseAction::Ephemeral(message))
+    }
+
+    /// Builds the payload to post a new message visible to the channel.
+    pub fn reply_in_channel(&mut self, message: Message) -> Result<Message, ResponseUrlError> {
+        self.respond(ResponseAction::InChannel(message))
+    }
+
+    /// Builds the payload to replace the original message.
+    pub fn replace(&mut self, message: Message) -> Result<Message, ResponseUrlError> {
+        self.respond(ResponseAction::Replace(message))
+    }
+
+    /// Builds the payload to delete the original message.
+    pub fn delete(&mut self) -> Result<Message, ResponseUrlError> {
+        self.respond(ResponseAction::Delete)
+    }
+
+    /// Counts a use of the `response_url` and builds the payload of the action, against the
+    /// current system time.
+    pub fn respond(&mut self, action: ResponseAction) -> Result<Message, ResponseUrlError> {
+        self.respond_at(action, now())
+    }
+
+    /// Same as [`respond`](Self::respond) but checks the expiration against the given unix
+    /// timestamp instead of the current system time.
+    pub fn respond_at(
+        &mut self,
+        action: ResponseAction,
+        now: i64,
+    ) -> Result<Message, ResponseUrlError> {
+        if now.saturating_sub(self.issued_at) > EXPIRES_IN as i64 {
+            return Err(ResponseUrlError::Expired {
+                issued_at: self.issued_at,
+                now,
+            });
+        }
+
+        if self.uses >= MAX_USES {
+            return Err(ResponseUrlError::UsesExhausted(self.uses));
+        }
+
+        self.uses += 1;
+        Ok(action.into_payload())
+    }
+
+    /// Counts a use of the `response_url` and posts the payload of the action.
+    #[cfg(feature = "response-url")]
+    pub async fn send(
+        &mut self,
+        client: &reqwest::Client,
+        action: ResponseAction,
+    ) -> Result<(), ResponseUrlError> {
+        let payload = self.respond(action)?;
+        let response = client.post(&self.url).json(&payload).send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await?;
+        let error = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|v| v.get("error").and_then(|e| e.as_str()).map(String::from))
+            .unwrap_or(body);
+
+        Err(ResponseUrlError::Rejected {
+            status: status.as_u16(),
+            error,
+        })
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+
+    const ISSUED_AT: i64 = 1531420618;
+
+    fn response_url() -> ResponseUrl {
+        ResponseUrl::new("https://hooks.slack.com/actions/T0/1/XXX").issued_at(ISSUED_AT)
+    }
+
+    fn message() -> Message {
+        Message::builder()
+            .text("hello")
+            .block(section("foo"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_builds_ephemeral_reply() {
+        let payload = response_url()
+            .respond_at(ResponseAction::Ephemeral(message()), ISSUED_AT)
+            .unwrap();
+
+        assert_eq!(payload.response_type, Some(ResponseType::Ephemeral));
+        assert_eq!(payload.replace_original, Some(false));
+        assert_eq!(payload.delete_original, None);
+        assert_eq!(payload.text, Some("hello".into()));
+    }
+
+    #[test]
+    fn it_builds_in_channel_reply() {
+        let payload = response_url()
+            .respond_at(ResponseAction::InChannel(message()), ISSUED_AT)
+            .unwrap();
+
+        assert_eq!(payload.response_type, Some(ResponseType::InChannel));
+        assert_eq!(payload.replace_original, Some(false));
+        assert_eq!(payload.delete_original, None);
+    }
+
+    #[test]
+    fn it_builds_replacement() {
+        let original = Message {
+            delete_original: Some(true),
+            ..message()
+        };
+
+        let payload = response_url()
+            .respond_at(ResponseAction::Replace(original), ISSUED_AT)
+            .unwrap();
+
+        assert_eq!(payload.replace_original, Some(true));
+        assert_eq!(payload.delete_original, None);
+        assert_eq!(payload.blocks, message().blocks);
+    }
+
+    #[test]
+    fn it_builds_deletion() {
+        let payload = response_url()
+            .respond_at(ResponseAction::Delete, ISSUED_AT)
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(payload).unwrap(),
+            serde_json::json!({ "delete_original": true })
+        );
+    }
+
+    #[test]
+    fn it_allows_five_uses() {
+        let mut response_url = response_url();
+
+        for remaining in (0..5).rev() {
+            response_url
+                .respond_at(ResponseAction::Delete, ISSUED_AT)
+                .unwrap();
+            assert_eq!(response_url.remaining_uses(), remaining);
+        }
+
+        let err = response_url
+            .respond_at(ResponseAction::Delete, ISSUED_AT)
+            .unwrap_err();
+        assert!(matches!(err, ResponseUrlError::UsesExhausted(5)));
+    }
+
+    #[test]
+    fn it_expires_after_30_minutes() {
+        let mut response_url = response_url();
+
+        let result = response_url.respond_at(ResponseAction::Delete, ISSUED_AT + 1800);
+        assert!(result.is_ok());
+
+        let err = response_url
+            .respond_at(ResponseAction::Delete, ISSUED_AT + 1801)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ResponseUrlError::Expired {
+                issued_at: ISSUED_AT,
+                now,
+            } if now == ISSUED_AT + 1801
+        ));
+        assert_eq!(response_url.remaining_uses(), 4);
+    }
+
+    #[cfg(feature = "response-url")]
+    mod send {
+        use super::*;
+        use crate::test_server::{Response, TestServer};
+
+        #[tokio::test]
+        async fn it_posts_payload() {
+            let server = TestServer::start([Response::new(200, r#"{"ok":true}"#)]).await;
+            let mut response_url = ResponseUrl::new(server.url("/actions/T0/1/XXX"));
+
+            response_url
+                .send(&reqwest::Client::new(), ResponseAction::Replace(message()))
+                .await
+                .unwrap();
+
+            let requests = server.requests();
+            assert_eq!(requests[0].method, "POST");
+            assert_eq!(requests[0].path, "/actions/T0/1/XXX");
+            assert_eq!(requests[0].header("content-type"), Some("application/json"));
+            assert_eq!(requests[0].json()["replace_original"], true);
+            assert_eq!(response_url.remaining_uses(), 4);
+        }
+
+        #[tokio::test]
+        async fn it_returns_rejection() {
+            let server =
+                TestServer::start([Response::new(404, r#"{"ok":false,"error":"expired_url"}"#)])
+                    .await;
+            let mut response_url = ResponseUrl::new(server.url("/actions/T0/1/XXX"));
+
+            let err = response_url
+                .send(&reqwest::Client::new(), ResponseAction::Delete)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(
+                err,
+                ResponseUrlError::Rejected { status: 404, error } if error == "expired_url"
+            ));
+        }
+
+        #[tokio::test]
+        async fn it_does_not_send_once_uses_are_exhausted() {
+            let server = TestServer::start([]).await;
+            let mut response_url = ResponseUrl::new(server.url("/actions/T0/1/XXX"));
+
+            for _ in 0..5 {
+                response_url.delete().unwrap();
+            }
+
+            let err = response_url
+                .send(&reqwest::Client::new(), ResponseAction::Delete)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, ResponseUrlError::UsesExhausted(5)));
+            assert!(server.requests().is_empty());
+        }
+    }
+}
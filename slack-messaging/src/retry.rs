@@ -0,0 +1,119 @@
+use reqwest::Response;
+use reqwest::header::RETRY_AFTER;
+use std::time::Duration;
+
+/// Policy to retry rate limited (`429`) and server error (`5xx`) responses.
+///
+/// The delay before each retry is the `Retry-After` value of the response if any. Otherwise it
+/// doubles from `base_delay` on every attempt, up to `max_delay`.
+///
+/// ```
+/// use slack_messaging::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::default()
+///     .max_retries(5)
+///     .base_delay(Duration::from_millis(200))
+///     .max_delay(Duration::from_secs(10));
+///
+/// assert_eq!(policy.delay(0, None), Duration::from_millis(200));
+/// assert_eq!(policy.delay(2, None), Duration::from_millis(800));
+/// assert_eq!(policy.delay(10, None), Duration::from_secs(10));
+/// assert_eq!(policy.delay(0, Some(Duration::from_secs(30))), Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries.
+    pub fn none() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    /// Sets the maximum number of retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Sets the upper bound of the exponential backoff.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Returns true if the policy allows another retry after the given zero-based attempt.
+    pub(crate) fn allows(&self, attempt: u32) -> bool {
+        attempt < self.max_retries
+    }
+
+    /// Returns the delay before the retry following the given zero-based attempt.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        retry_after.unwrap_or_else(|| {
+            self.base_delay
+                .checked_mul(2u32.saturating_pow(attempt))
+                .unwrap_or(self.max_delay)
+                .min(self.max_delay)
+        })
+    }
+}
+
+/// Returns the value of the `Retry-After` header of the response.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_backs_off_exponentially() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(policy.delay(0, None), Duration::from_secs(1));
+        assert_eq!(policy.delay(1, None), Duration::from_secs(2));
+        assert_eq!(policy.delay(4, None), Duration::from_secs(16));
+        assert_eq!(policy.delay(5, None), Duration::from_secs(30));
+        assert_eq!(policy.delay(40, None), Duration::from_secs(30));
+        assert_eq!(
+            policy.delay(3, Some(Duration::from_secs(2))),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn it_limits_number_of_retries() {
+        let policy = RetryPolicy::default().max_retries(2);
+
+        assert!(policy.allows(0));
+        assert!(policy.allows(1));
+        assert!(!policy.allows(2));
+        assert!(!RetryPolicy::none().allows(0));
+    }
+}
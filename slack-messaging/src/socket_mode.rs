@@ -0,0 +1,688 @@
+use crate::commands::SlashCommand;
+use crate::errors::SocketModeError;
+use crate::events::EventCallback;
+use crate::interactions::Interaction;
+use crate::web_api::WebApiClient;
+
+use futures_util::{SinkExt, StreamExt};
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+/// Error type of the [`Transport`] and [`Connection`].
+pub type TransportError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Transport opening WebSocket connections to the URL `apps.connections.open` returns.
+///
+/// The default transport is [`WebSocketTransport`]. Implement this trait to swap it, for example
+/// with an in-memory connection in tests.
+pub trait Transport: Send + Sync + 'static {
+    /// Connection the transport opens.
+    type Connection: Connection;
+
+    /// Opens a connection to the URL.
+    fn connect(
+        &self,
+        url: &str,
+    ) -> impl Future<Output = Result<Self::Connection, TransportError>> + Send;
+}
+
+/// Connection opened by a [`Transport`], exchanging text frames.
+pub trait Connection: Send + 'static {
+    /// Receives the next text frame. Returns `None` once the connection is closed.
+    fn recv(&mut self) -> impl Future<Output = Option<Result<String, TransportError>>> + Send;
+
+    /// Sends the text frame.
+    fn send(&mut self, text: String) -> impl Future<Output = Result<(), TransportError>> + Send;
+}
+
+/// [`Transport`] over WebSocket backed by `tokio-tungstenite`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebSocketTransport;
+
+/// [`Connection`] opened by the [`WebSocketTransport`].
+#[derive(Debug)]
+pub struct WebSocketConnection {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl Transport for WebSocketTransport {
+    type Connection = WebSocketConnection;
+
+    async fn connect(&self, url: &str) -> Result<WebSocketConnection, TransportError> {
+        let (stream, _) = tokio_tungstenite::connect_async(url).await?;
+        Ok(WebSocketConnection { stream })
+    }
+}
+
+impl Connection for WebSocketConnection {
+    async fn recv(&mut self) -> Option<Result<String, TransportError>> {
+        loop {
+            match self.stream.next().await? {
+                Ok(WsMessage::Text(text)) => return Some(Ok(text.to_string())),
+                Ok(WsMessage::Close(_)) => return None,
+                Ok(_) => continue,
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+
+    async fn send(&mut self, text: String) -> Result<(), TransportError> {
+        self.stream
+            .send(WsMessage::Text(text.into()))
+            .await
+            .map_err(Into::into)
+    }
+}
+
+/// Request Slack delivers over a [Socket Mode](https://docs.slack.dev/apis/events-api/using-socket-mode)
+/// connection. Acknowledge it with [`SocketModeClient::ack`] or [`SocketModeClient::ack_with`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SocketModeRequest {
+    /// ID to acknowledge the request with.
+    pub envelope_id: String,
+
+    /// Typed payload of the request.
+    pub payload: SocketModePayload,
+
+    /// Whether Slack accepts a response payload in the acknowledgement or not.
+    pub accepts_response_payload: bool,
+
+    /// Number of times Slack has retried the request.
+    pub retry_attempt: u32,
+
+    /// Reason Slack retried the request.
+    pub retry_reason: Option<String>,
+}
+
+/// Payload of the [`SocketModeRequest`], distinguished by the `type` of the envelope.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SocketModePayload {
+    /// `events_api` envelope wrapping an event.
+    EventsApi(Box<EventCallback>),
+
+    /// `interactive` envelope wrapping an interaction payload.
+    Interactive(Interaction),
+
+    /// `slash_commands` envelope wrapping a slash command.
+    SlashCommands(Box<SlashCommand>),
+
+    /// Envelope of a type this crate does not know or whose payload fails to decode, kept as
+    /// raw JSON. Acknowledge it as any other request so that Slack does not retry it.
+    Unknown(serde_json::Value),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Frame {
+    Hello {},
+    Disconnect {},
+    EventsApi(Envelope<Box<EventCallback>>),
+    Interactive(Envelope<Interaction>),
+    SlashCommands(Envelope<Box<SlashCommand>>),
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize)]
+struct Envelope<P> {
+    envelope_id: String,
+    payload: P,
+    #[serde(default)]
+    accepts_response_payload: bool,
+    #[serde(default)]
+    retry_attempt: u32,
+    #[serde(default)]
+    retry_reason: Option<String>,
+}
+
+impl Envelope<IgnoredAny> {
+    /// Returns the frame as a request with [`SocketModePayload::Unknown`] if it has an
+    /// `envelope_id` to acknowledge.
+    fn unknown(frame: serde_json::Value) -> Option<SocketModeRequest> {
+        let envelope = Self::deserialize(&frame).ok()?;
+        Some(envelope.into_request(|_| SocketModePayload::Unknown(frame)))
+    }
+}
+
+impl<P> Envelope<P> {
+    fn into_request(self, payload: impl FnOnce(P) -> SocketModePayload) -> SocketModeRequest {
+        SocketModeRequest {
+            envelope_id: self.envelope_id,
+            payload: payload(self.payload),
+            accepts_response_payload: self.accepts_response_payload,
+            retry_attempt: self.retry_attempt,
+            retry_reason: self.retry_reason.filter(|r| !r.is_empty()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Ack<'a, P: Serialize> {
+    envelope_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payload: Option<&'a P>,
+}
+
+#[derive(Deserialize)]
+struct ConnectionsOpen {
+    url: String,
+}
+
+/// Client receiving requests over [Socket Mode](https://docs.slack.dev/apis/events-api/using-socket-mode).
+///
+/// It opens a connection with the app-level token through `apps.connections.open`, decodes the
+/// `events_api`, `interactive` and `slash_commands` envelopes into [`SocketModeRequest`]s and
+/// sends acknowledgements. Envelopes it cannot decode are returned with
+/// [`SocketModePayload::Unknown`] so that they can still be acknowledged. When Slack sends a
+/// `disconnect` message or closes the connection, the client opens a new connection on the next
+/// call of [`next`](Self::next).
+///
+/// # Example
+///
+/// ```no_run
+/// use slack_messaging::Message;
+/// use slack_messaging::socket_mode::{SocketModeClient, SocketModePayload};
+/// # use std::error::Error;
+///
+/// # async fn try_main() -> Result<(), Box<dyn Error>> {
+/// let mut client = SocketModeClient::new("xapp-your-app-level-token");
+///
+/// loop {
+///     let request = client.next().await?;
+///
+///     match &request.payload {
+///         SocketModePayload::SlashCommands(command) => {
+///             let message = Message::builder()
+///                 .text(format!("You said {}", command.text))
+///                 .ephemeral()
+///                 .build()?;
+///             client.ack_with(&request, &message).await?;
+///         }
+///         _ => client.ack(&request).await?,
+///     }
+/// }
+/// # }
+/// ```
+pub struct SocketModeClient<T: Transport = WebSocketTransport> {
+    api: WebApiClient,
+    transport: T,
+    connection: Option<T::Connection>,
+}
+
+impl SocketModeClient {
+    /// Constructs a client with the app-level token (`xapp-...`).
+    pub fn new(app_token: impl Into<String>) -> Self {
+        Self {
+            api: WebApiClient::new(app_token),
+            transport: WebSocketTransport,
+            connection: None,
+        }
+    }
+}
+
+impl<T: Transport> SocketModeClient<T> {
+    /// Sets the [`WebApiClient`] calling `apps.connections.open`. It must be constructed with the
+    /// app-level token.
+    pub fn api_client(mut self, api: WebApiClient) -> Self {
+        self.api = api;
+        self
+    }
+
+    /// Swaps the transport.
+    pub fn transport<U: Transport>(self, transport: U) -> SocketModeClient<U> {
+        SocketModeClient {
+            api: self.api,
+            transport,
+            connection: None,
+        }
+    }
+
+    /// Opens a new connection, replacing the current one if any.
+    pub async fn connect(&mut self) -> Result<(), SocketModeError> {
+        self.connection = None;
+
+        let open: ConnectionsOpen = self
+            .api
+            .call("apps.connections.open", &serde_json::json!({}))
+            .await?;
+
+        let connection = self
+            .transport
+            .connect(&open.url)
+            .await
+            .map_err(SocketModeError::Transport)?;
+
+        self.connection = Some(connection);
+        Ok(())
+    }
+
+    /// Waits for the next request, connecting or reconnecting as needed.
+    pub async fn next(&mut self) -> Result<SocketModeRequest, SocketModeError> {
+        loop {
+            let connection = match self.connection.as_mut() {
+                Some(connection) => connection,
+                None => {
+                    self.connect().await?;
+                    continue;
+                }
+            };
+
+            let text = match connection.recv().await {
+                Some(Ok(text)) => text,
+                Some(Err(err)) => {
+                    self.connection = None;
+                    return Err(SocketModeError::Transport(err));
+                }
+                None => {
+                    self.connection = None;
+                    continue;
+                }
+            };
+
+            let value: serde_json::Value = serde_json::from_str(&text)?;
+            let frame = match Frame::deserialize(&value) {
+                Ok(frame) => frame,
+                Err(err) => return Envelope::unknown(value).ok_or(err.into()),
+            };
+
+            match frame {
+                Frame::EventsApi(envelope) => {
+                    return Ok(envelope.into_request(SocketModePayload::EventsApi));
+                }
+                Frame::Interactive(envelope) => {
+                    return Ok(envelope.into_request(SocketModePayload::Interactive));
+                }
+                Frame::SlashCommands(envelope) => {
+                    return Ok(envelope.into_request(SocketModePayload::SlashCommands));
+                }
+                Frame::Disconnect {} => {
+                    self.connection = None;
+                }
+                Frame::Unknown => {
+                    if let Some(request) = Envelope::unknown(value) {
+                        return Ok(request);
+                    }
+                }
+                Frame::Hello {} => {}
+            }
+        }
+    }
+
+    /// Acknowledges the request without payload.
+    pub async fn ack(&mut self, request: &SocketModeRequest) -> Result<(), SocketModeError> {
+        self.send_ack::<()>(request, None).await
+    }
+
+    /// Acknowledges the request with the response payload, such as a
+    /// [`Message`](crate::Message) for slash commands, a
+    /// [`ViewSubmissionResponse`](crate::interactions::ViewSubmissionResponse) for view
+    /// submissions or an [`OptionsResponse`](crate::interactions::OptionsResponse) for block
+    /// suggestions.
+    pub async fn ack_with<P: Serialize>(
+        &mut self,
+        request: &SocketModeRequest,
+        payload: &P,
+    ) -> Result<(), SocketModeError> {
+        self.send_ack(request, Some(payload)).await
+    }
+
+    async fn send_ack<P: Serialize>(
+        &mut self,
+        request: &SocketModeRequest,
+        payload: Option<&P>,
+    ) -> Result<(), SocketModeError> {
+        let ack = serde_json::to_string(&Ack {
+            envelope_id: &request.envelope_id,
+            payload,
+        })
+        .map_err(SocketModeError::InvalidAck)?;
+
+        let connection = self
+            .connection
+            .as_mut()
+            .ok_or(SocketModeError::NotConnected)?;
+
+        if let Err(err) = connection.send(ack).await {
+            self.connection = None;
+            return Err(SocketModeError::Transport(err));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Message;
+    use crate::test_server::{Response, TestServer};
+    use serde_json::{Value, json};
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+
+    /// WebSocket server sending the scripted frames to each connection in order. After sending
+    /// a frame with `envelope_id`, it waits for the acknowledgement and records it.
+    struct WsServer {
+        url: String,
+        acks: Arc<Mutex<Vec<Value>>>,
+    }
+
+    impl WsServer {
+        async fn start(scripts: Vec<Vec<Value>>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("ws://{}/link", listener.local_addr().unwrap());
+            let acks = Arc::new(Mutex::new(vec![]));
+
+            let recorded = Arc::clone(&acks);
+            tokio::spawn(async move {
+                for script in scripts {
+                    let (stream, _) = listener.accept().await.unwrap();
+                    let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+                    for frame in script {
+                        let expects_ack = frame.get("envelope_id").is_some();
+                        ws.send(WsMessage::Text(frame.to_string().into()))
+                            .await
+                            .unwrap();
+
+                        if expects_ack && let Some(Ok(WsMessage::Text(text))) = ws.next().await {
+                            let ack = serde_json::from_str(&text).unwrap();
+                            recorded.lock().unwrap().push(ack);
+                        }
+                    }
+                }
+            });
+
+            Self { url, acks }
+        }
+
+        fn acks(&self) -> Vec<Value> {
+            self.acks.lock().unwrap().clone()
+        }
+    }
+
+    fn events_api() -> Value {
+        json!({
+            "envelope_id": "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545",
+            "type": "events_api",
+            "accepts_response_payload": false,
+            "retry_attempt": 0,
+            "retry_reason": "",
+            "payload": {
+                "type": "event_callback",
+                "team_id": "T1",
+                "api_app_id": "A1",
+                "event": {
+                    "type": "reaction_added",
+                    "user": "U1",
+                    "reaction": "thumbsup",
+                    "event_ts": "1360782804.083113"
+                },
+                "event_id": "Ev1",
+                "event_time": 1360782804
+            }
+        })
+    }
+
+    fn interactive() -> Value {
+        json!({
+            "envelope_id": "57d6a792-4d35-4d0b-b6aa-3361493e1caf",
+            "type": "interactive",
+            "accepts_response_payload": false,
+            "payload": {
+                "type": "block_actions",
+                "user": { "id": "U1" },
+                "api_app_id": "A1",
+                "container": { "type": "view", "view_id": "V1" },
+                "trigger_id": "1.2.3",
+                "actions": []
+            }
+        })
+    }
+
+    fn slash_commands() -> Value {
+        json!({
+            "envelope_id": "1d3c79ab-0ffb-41f3-a080-d19e85f53649",
+            "type": "slash_commands",
+            "accepts_response_payload": true,
+            "payload": {
+                "team_id": "T1",
+                "channel_id": "C1",
+                "user_id": "U1",
+                "command": "/deploy",
+                "text": "app",
+                "response_url": "https://hooks.slack.com/commands/1/2/3",
+                "trigger_id": "1.2.3",
+                "is_enterprise_install": "false"
+            }
+        })
+    }
+
+    async fn client(ws: &WsServer, connections: usize) -> (SocketModeClient, TestServer) {
+        let body = json!({ "ok": true, "url": ws.url }).to_string();
+        let http =
+            TestServer::start((0..connections).map(|_| Response::new(200, body.clone()))).await;
+        let client = SocketModeClient::new("xapp-token")
+            .api_client(WebApiClient::new("xapp-token").base_url(http.url("/api")));
+        (client, http)
+    }
+
+    #[tokio::test]
+    async fn it_decodes_envelopes_and_sends_acks() {
+        let ws = WsServer::start(vec![vec![
+            json!({ "type": "hello", "num_connections": 1 }),
+            events_api(),
+            interactive(),
+            slash_commands(),
+        ]])
+        .await;
+        let (mut client, http) = client(&ws, 1).await;
+
+        let request = client.next().await.unwrap();
+        assert_eq!(request.envelope_id, "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545");
+        assert_eq!(request.retry_reason, None);
+        let SocketModePayload::EventsApi(callback) = &request.payload else {
+            panic!("expected events_api");
+        };
+        assert_eq!(callback.event.r#type(), "reaction_added");
+        client.ack(&request).await.unwrap();
+
+        let request = client.next().await.unwrap();
+        assert!(matches!(
+            request.payload,
+            SocketModePayload::Interactive(Interaction::BlockActions(_))
+        ));
+        client.ack(&request).await.unwrap();
+
+        let request = client.next().await.unwrap();
+        assert!(request.accepts_response_payload);
+        let SocketModePayload::SlashCommands(command) = &request.payload else {
+            panic!("expected slash_commands");
+        };
+        assert_eq!(command.command, "/deploy");
+
+        let message = Message::builder().text("deploying").build().unwrap();
+        client.ack_with(&request, &message).await.unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        assert_eq!(
+            ws.acks(),
+            [
+                json!({ "envelope_id": "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545" }),
+                json!({ "envelope_id": "57d6a792-4d35-4d0b-b6aa-3361493e1caf" }),
+                json!({
+                    "envelope_id": "1d3c79ab-0ffb-41f3-a080-d19e85f53649",
+                    "payload": { "text": "deploying" }
+                }),
+            ]
+        );
+
+        let requests = http.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/api/apps.connections.open");
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer xapp-token")
+        );
+    }
+
+    #[tokio::test]
+    async fn it_reconnects_on_disconnect() {
+        let ws = WsServer::start(vec![
+            vec![
+                json!({ "type": "hello" }),
+                json!({ "type": "disconnect", "reason": "refresh_requested" }),
+            ],
+            vec![json!({ "type": "hello" }), events_api()],
+        ])
+        .await;
+        let (mut client, http) = client(&ws, 2).await;
+
+        let request = client.next().await.unwrap();
+
+        assert!(matches!(request.payload, SocketModePayload::EventsApi(_)));
+        assert_eq!(http.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn it_returns_undecodable_envelopes_to_ack() {
+        let mut broken = events_api();
+        broken["payload"] = json!({ "type": "event_callback" });
+        let future = json!({
+            "envelope_id": "b1b2c3d4-0000-4000-8000-000000000000",
+            "type": "future_type",
+            "payload": {}
+        });
+
+        let ws = WsServer::start(vec![vec![
+            json!({ "type": "hello" }),
+            broken.clone(),
+            future.clone(),
+        ]])
+        .await;
+        let (mut client, _http) = client(&ws, 1).await;
+
+        let request = client.next().await.unwrap();
+        assert_eq!(request.envelope_id, "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545");
+        assert_eq!(request.payload, SocketModePayload::Unknown(broken));
+        client.ack(&request).await.unwrap();
+
+        let request = client.next().await.unwrap();
+        assert_eq!(request.payload, SocketModePayload::Unknown(future));
+        client.ack(&request).await.unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        assert_eq!(
+            ws.acks(),
+            [
+                json!({ "envelope_id": "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545" }),
+                json!({ "envelope_id": "b1b2c3d4-0000-4000-8000-000000000000" }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_returns_invalid_frame_without_envelope_id() {
+        let ws = WsServer::start(vec![vec![json!({ "type": "events_api" })]]).await;
+        let (mut client, _http) = client(&ws, 1).await;
+
+        let err = client.next().await.unwrap_err();
+        assert!(matches!(err, SocketModeError::InvalidFrame(_)));
+    }
+
+    #[tokio::test]
+    async fn it_returns_invalid_ack_when_payload_fails_to_serialize() {
+        let ws = WsServer::start(vec![]).await;
+        let (mut client, _http) = client(&ws, 0).await;
+
+        let request = SocketModeRequest {
+            envelope_id: "1".into(),
+            payload: SocketModePayload::Unknown(json!({})),
+            accepts_response_payload: true,
+            retry_attempt: 0,
+            retry_reason: None,
+        };
+        let payload = std::collections::HashMap::from([((1, 2), "not a string key")]);
+
+        let err = client.ack_with(&request, &payload).await.unwrap_err();
+        assert!(matches!(err, SocketModeError::InvalidAck(_)));
+    }
+
+    #[tokio::test]
+    async fn it_fails_to_ack_without_connection() {
+        let ws = WsServer::start(vec![]).await;
+        let (mut client, _http) = client(&ws, 0).await;
+
+        let request = SocketModeRequest {
+            envelope_id: "1".into(),
+            payload: SocketModePayload::Interactive(
+                serde_json::from_value(interactive()["payload"].clone()).unwrap(),
+            ),
+            accepts_response_payload: false,
+            retry_attempt: 0,
+            retry_reason: None,
+        };
+
+        let err = client.ack(&request).await.unwrap_err();
+        assert!(matches!(err, SocketModeError::NotConnected));
+    }
+
+    #[tokio::test]
+    async fn it_returns_api_error_when_connection_cannot_be_opened() {
+        let http =
+            TestServer::start([Response::new(200, r#"{"ok":false,"error":"invalid_auth"}"#)]).await;
+        let mut client = SocketModeClient::new("xapp-token")
+            .api_client(WebApiClient::new("xapp-token").base_url(http.url("/api")));
+
+        let err = client.next().await.unwrap_err();
+        assert!(matches!(err, SocketModeError::Api(_)));
+    }
+
+    #[tokio::test]
+    async fn it_works_with_swapped_transport() {
+        struct Scripted(Vec<String>);
+
+        struct ScriptedConnection(std::vec::IntoIter<String>);
+
+        impl Transport for Scripted {
+            type Connection = ScriptedConnection;
+
+            async fn connect(&self, url: &str) -> Result<ScriptedConnection, TransportError> {
+                assert_eq!(url, "wss://example.com/link");
+                Ok(ScriptedConnection(self.0.clone().into_iter()))
+            }
+        }
+
+        impl Connection for ScriptedConnection {
+            async fn recv(&mut self) -> Option<Result<String, TransportError>> {
+                self.0.next().map(Ok)
+            }
+
+            async fn send(&mut self, _: String) -> Result<(), TransportError> {
+                Ok(())
+            }
+        }
+
+        let http = TestServer::start([Response::new(
+            200,
+            r#"{"ok":true,"url":"wss://example.com/link"}"#,
+        )])
+        .await;
+        let mut client = SocketModeClient::new("xapp-token")
+            .api_client(WebApiClient::new("xapp-token").base_url(http.url("/api")))
+            .transport(Scripted(vec![slash_commands().to_string()]));
+
+        let request = client.next().await.unwrap();
+        assert!(matches!(
+            request.payload,
+            SocketModePayload::SlashCommands(_)
+        ));
+        client.ack(&request).await.unwrap();
+    }
+}
@@ -0,0 +1,381 @@
+use crate::blocks::{Accessory, ActionsElement, Block, InputElement};
+use crate::errors::ValidationErrorKind;
+
+use std::fmt;
+
+/// [Surfaces](https://docs.slack.dev/surfaces) where blocks can be displayed.
+///
+/// Slack accepts different blocks and block elements depending on the surface. [`Message`](crate::Message),
+/// [`Modal`](crate::Modal) and [`HomeView`](crate::HomeView) run this check when they are built,
+/// and you can run it against any list of blocks with [`Surface::validate`].
+///
+/// | Block | Message | Modal | Home | Attachment |
+/// |-------|---------|-------|------|------------|
+/// | actions, context, divider, header, image, input, rich_text, section, video | Yes | Yes | Yes | Yes |
+/// | alert, card, carousel, context_actions, data_table, data_visualization, file, markdown, plan, table, task_card | Yes | No | No | Yes |
+/// | [`Block::Unknown`] | No | No | No | No |
+///
+/// Elements of input blocks, the accessory of section blocks and elements of actions blocks are
+/// checked as well. Elements not listed below are available in every surface.
+///
+/// | Element | Message | Modal | Home | Attachment |
+/// |---------|---------|-------|------|------------|
+/// | file_input | No | Yes | No | No |
+/// | rich_text_input | Yes | Yes | No | Yes |
+/// | workflow_button | Yes | Yes | Yes | No |
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{mrkdwn, Surface};
+/// use slack_messaging::blocks::{Block, Markdown, Section};
+/// use slack_messaging::errors::ValidationErrorKind;
+/// # use std::error::Error;
+///
+/// # fn try_main() -> Result<(), Box<dyn Error>> {
+/// let blocks: Vec<Block> = vec![
+///     Section::builder()
+///         .text(mrkdwn!("hello")?)
+///         .build()?
+///         .into(),
+///     Markdown::builder()
+///         .text("**hello**")
+///         .build()?
+///         .into(),
+/// ];
+///
+/// assert!(Surface::Message.validate(&blocks).is_empty());
+/// assert_eq!(
+///     Surface::Modal.validate(&blocks),
+///     vec![ValidationErrorKind::UnsupportedBlock(1, "markdown", Surface::Modal)],
+/// );
+/// #     Ok(())
+/// # }
+/// # fn main() {
+/// #     try_main().unwrap()
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Surface {
+    /// Messages sent to channels or users.
+    Message,
+    /// Modal views.
+    Modal,
+    /// App Home tab views.
+    Home,
+    /// Secondary attachments of messages.
+    Attachment,
+}
+
+impl fmt::Display for Surface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Message => "message",
+            Self::Modal => "modal",
+            Self::Home => "home",
+            Self::Attachment => "attachment",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Surface {
+    /// Returns whether the block itself is available in the surface. Elements inside the block
+    /// are not checked.
+    pub fn supports(self, block: &Block) -> bool {
+        match block {
+            Block::Actions(_)
+            | Block::Context(_)
+            | Block::Divider(_)
+            | Block::Header(_)
+            | Block::Image(_)
+            | Block::Input(_)
+            | Block::RichText(_)
+            | Block::Section(_)
+            | Block::Video(_) => true,
+            Block::Alert(_)
+            | Block::Card(_)
+            | Block::Carousel(_)
+            | Block::ContextActions(_)
+            | Block::DataTable(_)
+            | Block::DataVisualization(_)
+            | Block::File(_)
+            | Block::Markdown(_)
+            | Block::Plan(_)
+            | Block::Table(_)
+            | Block::TaskCard(_) => matches!(self, Self::Message | Self::Attachment),
+            Block::Unknown(_) => false,
+        }
+    }
+
+    /// Checks every block and its elements, and returns an error for each one that is not
+    /// available in the surface. Each error includes the index and the type of the block.
+    pub fn validate(self, blocks: &[Block]) -> Vec<ValidationErrorKind> {
+        let mut errors = vec![];
+
+        for (index, block) in blocks.iter().enumerate() {
+            if !self.supports(block) {
+                errors.push(ValidationErrorKind::UnsupportedBlock(
+                    index,
+                    block.r#type(),
+                    self,
+                ));
+            }
+
+            for element in self.unsupported_elements(block) {
+                errors.push(ValidationErrorKind::UnsupportedElement(
+                    index,
+                    block.r#type(),
+                    element,
+                    self,
+                ));
+            }
+        }
+
+        errors
+    }
+
+    /// Returns whether the element of the `type` is available in the surface.
+    pub fn supports_element(self, element: &str) -> bool {
+        match element {
+            "file_input" => self == Self::Modal,
+            "rich_text_input" => self != Self::Home,
+            "workflow_button" => self != Self::Attachment,
+            _ => true,
+        }
+    }
+
+    fn unsupported_elements(self, block: &Block) -> Vec<&'static str> {
+        let elements = match block {
+            Block::Input(input) => input.element.iter().filter_map(input_element).collect(),
+            Block::Section(section) => section.accessory.iter().filter_map(accessory).collect(),
+            Block::Actions(actions) => actions
+                .elements
+                .iter()
+                .flatten()
+                .filter_map(actions_element)
+                .collect(),
+            _ => vec![],
+        };
+
+        elements
+            .into_iter()
+            .filter(|element| !self.supports_element(element))
+            .collect()
+    }
+}
+
+// Only elements with a surface restriction need a name here.
+fn input_element(element: &InputElement) -> Option<&'static str> {
+    match element {
+        InputElement::FileInput(_) => Some("file_input"),
+        InputElement::RichTextInput(_) => Some("rich_text_input"),
+        _ => None,
+    }
+}
+
+fn accessory(element: &Accessory) -> Option<&'static str> {
+    match element {
+        Accessory::WorkflowButton(_) => Some("workflow_button"),
+        _ => None,
+    }
+}
+
+fn actions_element(element: &ActionsElement) -> Option<&'static str> {
+    match element {
+        ActionsElement::WorkflowButton(_) => Some("workflow_button"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::elements::test_helpers::*;
+    use crate::blocks::elements::{RichTextInput, WorkflowButton};
+    use crate::blocks::test_helpers::*;
+    use crate::blocks::{Actions, Divider, Input, Markdown, Section};
+    use crate::composition_objects::test_helpers::*;
+    use crate::composition_objects::{Trigger, Workflow};
+
+    #[test]
+    fn it_rejects_unknown_blocks_in_every_surface() {
+        let blocks = vec![Block::Unknown(serde_json::json!({ "type": "call" }))];
+
+        for surface in [
+            Surface::Message,
+            Surface::Modal,
+            Surface::Home,
+            Surface::Attachment,
+        ] {
+            assert_eq!(
+                surface.validate(&blocks),
+                vec![ValidationErrorKind::UnsupportedBlock(0, "unknown", surface)]
+            );
+        }
+    }
+
+    #[test]
+    fn it_accepts_common_blocks_in_every_surface() {
+        let blocks: Vec<Block> = vec![
+            header("foo").into(),
+            section("bar").into(),
+            Divider { block_id: None }.into(),
+            input().into(),
+        ];
+
+        for surface in [
+            Surface::Message,
+            Surface::Modal,
+            Surface::Home,
+            Surface::Attachment,
+        ] {
+            assert!(surface.validate(&blocks).is_empty());
+        }
+    }
+
+    #[test]
+    fn it_reports_message_only_blocks_with_index_and_type() {
+        let blocks: Vec<Block> = vec![
+            section("foo").into(),
+            task_card().into(),
+            Markdown {
+                block_id: None,
+                text: Some("**bar**".into()),
+            }
+            .into(),
+        ];
+
+        assert!(Surface::Message.validate(&blocks).is_empty());
+        assert!(Surface::Attachment.validate(&blocks).is_empty());
+
+        assert_eq!(
+            Surface::Modal.validate(&blocks),
+            vec![
+                ValidationErrorKind::UnsupportedBlock(1, "task_card", Surface::Modal),
+                ValidationErrorKind::UnsupportedBlock(2, "markdown", Surface::Modal),
+            ]
+        );
+        assert_eq!(
+            Surface::Home.validate(&blocks),
+            vec![
+                ValidationErrorKind::UnsupportedBlock(1, "task_card", Surface::Home),
+                ValidationErrorKind::UnsupportedBlock(2, "markdown", Surface::Home),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_checks_elements_in_messages() {
+        assert_eq!(
+            Surface::Message.validate(&restricted_elements()),
+            vec![ValidationErrorKind::UnsupportedElement(
+                0,
+                "input",
+                "file_input",
+                Surface::Message
+            )]
+        );
+    }
+
+    #[test]
+    fn it_checks_elements_in_modals() {
+        assert!(Surface::Modal.validate(&restricted_elements()).is_empty());
+    }
+
+    #[test]
+    fn it_checks_elements_in_home_tabs() {
+        assert_eq!(
+            Surface::Home.validate(&restricted_elements()),
+            vec![
+                ValidationErrorKind::UnsupportedElement(0, "input", "file_input", Surface::Home),
+                ValidationErrorKind::UnsupportedElement(
+                    1,
+                    "input",
+                    "rich_text_input",
+                    Surface::Home
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_checks_elements_in_attachments() {
+        assert_eq!(
+            Surface::Attachment.validate(&restricted_elements()),
+            vec![
+                ValidationErrorKind::UnsupportedElement(
+                    0,
+                    "input",
+                    "file_input",
+                    Surface::Attachment
+                ),
+                ValidationErrorKind::UnsupportedElement(
+                    2,
+                    "section",
+                    "workflow_button",
+                    Surface::Attachment
+                ),
+                ValidationErrorKind::UnsupportedElement(
+                    3,
+                    "actions",
+                    "workflow_button",
+                    Surface::Attachment
+                ),
+            ]
+        );
+    }
+
+    fn restricted_elements() -> Vec<Block> {
+        vec![
+            input_with(file_input()).into(),
+            input_with(
+                RichTextInput::builder()
+                    .action_id("rich_text_input_0")
+                    .build()
+                    .unwrap(),
+            )
+            .into(),
+            Section {
+                accessory: Some(workflow_button().into()),
+                ..section("foo")
+            }
+            .into(),
+            Actions {
+                block_id: None,
+                elements: Some(vec![btn("bar", "baz").into(), workflow_button().into()]),
+            }
+            .into(),
+        ]
+    }
+
+    fn input_with(element: impl Into<InputElement>) -> Input {
+        Input {
+            label: Some(plain_text("Label")),
+            element: Some(element.into()),
+            dispatch_action: None,
+            block_id: None,
+            hint: None,
+            optional: None,
+        }
+    }
+
+    fn workflow_button() -> WorkflowButton {
+        WorkflowButton::builder()
+            .text(plain_text("Run"))
+            .action_id("workflow_button_0")
+            .workflow(
+                Workflow::builder()
+                    .trigger(
+                        Trigger::builder()
+                            .url("https://slack.com/shortcuts/Ft0123ABC456/123")
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+}
@@ -0,0 +1,256 @@
+use crate::errors::{NestedValidationError, NestedValidationErrors, ValidationErrorKind};
+
+use std::borrow::Cow;
+
+/// Validation of an object together with all objects nested in it.
+///
+/// The `build` method of each builder stops at the object being built, so a child that fails
+/// to build never reaches its parent. Build the objects with `build_unchecked` instead and call
+/// [`validate`](Self::validate) on the outermost one, such as [`Message`](crate::Message) or
+/// [`Modal`](crate::Modal), to get every error at once with the path to the failing field, e.g.
+/// `blocks[1].accessory.confirm.title`.
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::{Message, Validate};
+/// use slack_messaging::blocks::{Header, Section};
+/// use slack_messaging::blocks::elements::Button;
+/// use slack_messaging::composition_objects::{ConfirmationDialog, Plain, Text};
+/// use slack_messaging::errors::ValidationErrorKind;
+///
+/// let confirm = ConfirmationDialog::builder()
+///     .title(Text::<Plain>::builder().text("a".repeat(101)).build_unchecked())
+///     .build_unchecked();
+///
+/// let message = Message::builder()
+///     .block(Header::builder().build_unchecked())
+///     .block(
+///         Section::builder()
+///             .text(Text::<Plain>::builder().text("Deploy?").build_unchecked())
+///             .accessory(Button::builder().confirm(confirm).build_unchecked())
+///             .build_unchecked(),
+///     )
+///     .build_unchecked();
+///
+/// let err = message.validate().unwrap_err();
+///
+/// let paths: Vec<&str> = err.errors().iter().map(|e| e.path()).collect();
+/// assert_eq!(
+///     paths,
+///     [
+///         "blocks[0].text",
+///         "blocks[1].accessory.text",
+///         "blocks[1].accessory.confirm.title",
+///         "blocks[1].accessory.confirm.text",
+///         "blocks[1].accessory.confirm.confirm",
+///         "blocks[1].accessory.confirm.deny",
+///     ]
+/// );
+/// assert_eq!(
+///     err.errors()[2].errors(),
+///     [ValidationErrorKind::MaxTextLength { max: 100, actual: 101 }]
+/// );
+/// ```
+pub trait Validate {
+    /// Validates the object and all objects nested in it.
+    fn validate(&self) -> Result<(), NestedValidationErrors> {
+        let mut errors = vec![];
+        self.validate_at("", &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(NestedValidationErrors { errors })
+        }
+    }
+
+    /// Validates the object at the path, collecting the errors into `errors`.
+    #[doc(hidden)]
+    fn validate_at(&self, path: &str, errors: &mut Vec<NestedValidationError>);
+}
+
+pub(crate) fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+pub(crate) fn push_errors(
+    errors: &mut Vec<NestedValidationError>,
+    path: &str,
+    object: &'static str,
+    kinds: Vec<ValidationErrorKind>,
+) {
+    if !kinds.is_empty() {
+        errors.push(NestedValidationError {
+            path: path.to_string(),
+            object: Cow::Borrowed(object),
+            errors: kinds,
+        });
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<NestedValidationError>) {
+        if let Some(value) = self {
+            value.validate_at(path, errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<NestedValidationError>) {
+        for (i, value) in self.iter().enumerate() {
+            value.validate_at(&format!("{path}[{i}]"), errors);
+        }
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<NestedValidationError>) {
+        (**self).validate_at(path, errors);
+    }
+}
+
+macro_rules! impl_validate_nothing {
+    ($($ty:ty),*) => {
+        $(
+            impl Validate for $ty {
+                fn validate_at(&self, _: &str, _: &mut Vec<NestedValidationError>) {}
+            }
+        )*
+    };
+}
+
+impl_validate_nothing!(
+    String,
+    &'static str,
+    bool,
+    i64,
+    serde_json::Value,
+    serde_json::Number,
+    crate::blocks::table::RawText,
+    crate::blocks::data_table::RawNumber,
+    crate::ResponseType,
+    crate::requests::Parse,
+    crate::composition_objects::types::Conversation,
+    crate::composition_objects::types::TriggerAction,
+    crate::blocks::AlertLevel,
+    crate::blocks::FileSource,
+    crate::blocks::TaskStatus,
+    crate::blocks::elements::types::FileType,
+    crate::blocks::elements::types::Icon,
+    crate::blocks::rich_text::ListStyle,
+    crate::blocks::rich_text::types::BroadcastRange,
+    crate::blocks::table::ColumnAlignment
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::elements::Button;
+    use crate::blocks::test_helpers::*;
+    use crate::blocks::{Actions, Section};
+    use crate::composition_objects::test_helpers::*;
+    use crate::composition_objects::{Mrkdwn, Text};
+    use crate::{Message, Modal};
+
+    fn paths(err: &NestedValidationErrors) -> Vec<&str> {
+        err.errors()
+            .iter()
+            .map(NestedValidationError::path)
+            .collect()
+    }
+
+    #[test]
+    fn it_passes_valid_objects() {
+        let message = Message::builder()
+            .text("hello")
+            .block(section("foo"))
+            .build()
+            .unwrap();
+
+        assert!(message.validate().is_ok());
+    }
+
+    #[test]
+    fn it_reports_errors_of_all_nested_levels_with_path() {
+        let message = Message::builder()
+            .block(section("foo"))
+            .block(
+                Actions::builder()
+                    .element(Button::builder().text(plain_text("ok")).build().unwrap())
+                    .element(
+                        Button::builder()
+                            .text(plain_text("a".repeat(76)))
+                            .value("a".repeat(2001))
+                            .build_unchecked(),
+                    )
+                    .build_unchecked(),
+            )
+            .replace_original(true)
+            .delete_original(true)
+            .build_unchecked();
+
+        let err = message.validate().unwrap_err();
+
+        assert_eq!(
+            paths(&err),
+            [
+                "",
+                "blocks[1].elements[1].text",
+                "blocks[1].elements[1].value"
+            ]
+        );
+
+        let errors = err.errors();
+        assert_eq!(errors[0].object(), "Message");
+        assert_eq!(
+            errors[0].errors(),
+            [ValidationErrorKind::ExclusiveField(
+                "replace_original",
+                "delete_original"
+            )]
+        );
+        assert_eq!(errors[1].object(), "Button");
+        assert_eq!(
+            errors[1].errors(),
+            [ValidationErrorKind::MaxTextLength {
+                max: 75,
+                actual: 76
+            }]
+        );
+        assert_eq!(
+            errors[2].errors(),
+            [ValidationErrorKind::MaxTextLength {
+                max: 2000,
+                actual: 2001
+            }]
+        );
+    }
+
+    #[test]
+    fn it_reports_errors_of_text_objects() {
+        let modal = Modal::builder()
+            .title(plain_text("a".repeat(25)))
+            .block(
+                Section::builder()
+                    .text(Text::<Mrkdwn>::builder().build_unchecked())
+                    .build_unchecked(),
+            )
+            .build_unchecked();
+
+        let err = modal.validate().unwrap_err();
+
+        assert_eq!(paths(&err), ["title", "blocks[0].text.text"]);
+        assert_eq!(
+            err.to_string(),
+            "title (Modal): max text length is `24` characters but got `25`\n\
+             blocks[0].text.text (Text): required"
+        );
+        assert_eq!(err.errors()[1].errors(), [ValidationErrorKind::Required]);
+    }
+}
@@ -1,7 +1,7 @@
 use super::*;
 use paste::paste;
 
-type I64 = Value<i64>;
+type I64<'a> = ValueRef<'a, i64>;
 
 static BILLION: i64 = 1_000_000_000;
 
@@ -24,16 +24,18 @@ pub(crate) fn ten_digits(value: I64) -> I64 {
     )
 }
 
-fn max(max: i64, value: I64) -> I64 {
-    inner_validator(value, ValidationErrorKind::MaxIntegerValue(max), |v| {
-        v > max
-    })
+fn max(max: i64, mut value: I64) -> I64 {
+    if let Some(actual) = value.inner_ref().copied().filter(|&v| v > max) {
+        value.push(ValidationErrorKind::MaxIntegerValue { max, actual });
+    }
+    value
 }
 
-fn min(min: i64, value: I64) -> I64 {
-    inner_validator(value, ValidationErrorKind::MinIntegerValue(min), |v| {
-        v < min
-    })
+fn min(min: i64, mut value: I64) -> I64 {
+    if let Some(actual) = value.inner_ref().copied().filter(|&v| v < min) {
+        value.push(ValidationErrorKind::MinIntegerValue { min, actual });
+    }
+    value
 }
 
 macro_rules! impl_max {
@@ -73,23 +75,23 @@ mod tests {
         #[test]
         fn it_passes_if_the_value_is_greater_than_one_billion() {
             let v = 1_000_000_000i64;
-            let result = test(v);
-            assert!(result.errors.is_empty());
+            let errors = test(v);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_passes_if_the_value_is_smaller_than_9999999999() {
             let v = 9_999_999_999i64;
-            let result = test(v);
-            assert!(result.errors.is_empty());
+            let errors = test(v);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_value_is_smaller_than_one_billion() {
             let v = 999_999_999i64;
-            let result = test(v);
+            let errors = test(v);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("10 digits")]
             );
         }
@@ -97,15 +99,15 @@ mod tests {
         #[test]
         fn it_sets_an_error_if_the_value_is_greater_than_ten_billion() {
             let v = 10_000_000_000i64;
-            let result = test(v);
+            let errors = test(v);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("10 digits")]
             );
         }
 
-        fn test(int: i64) -> I64 {
-            ten_digits(Value::new(Some(int)))
+        fn test(int: i64) -> Vec<ValidationErrorKind> {
+            ten_digits(ValueRef::new(Some(&int))).errors
         }
     }
 
@@ -115,22 +117,25 @@ mod tests {
         #[test]
         fn it_passes_if_the_value_is_smaller_than_10() {
             let v = 10i64;
-            let result = test(v);
-            assert!(result.errors.is_empty());
+            let errors = test(v);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_errors_if_the_value_is_greater_than_10() {
             let v = 11i64;
-            let result = test(v);
+            let errors = test(v);
             assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MaxIntegerValue(10)]
+                errors,
+                vec![ValidationErrorKind::MaxIntegerValue {
+                    max: 10,
+                    actual: 11
+                }]
             );
         }
 
-        fn test(int: i64) -> I64 {
-            max_10(Value::new(Some(int)))
+        fn test(int: i64) -> Vec<ValidationErrorKind> {
+            max_10(ValueRef::new(Some(&int))).errors
         }
     }
 
@@ -140,19 +145,22 @@ mod tests {
         #[test]
         fn it_passes_if_the_value_is_greater_than_1() {
             let v = 1i64;
-            let result = test(v);
-            assert!(result.errors.is_empty());
+            let errors = test(v);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_errors_if_the_value_is_smaller_than_1() {
             let v = 0i64;
-            let result = test(v);
-            assert_eq!(result.errors, vec![ValidationErrorKind::MinIntegerValue(1)]);
+            let errors = test(v);
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MinIntegerValue { min: 1, actual: 0 }]
+            );
         }
 
-        fn test(int: i64) -> I64 {
-            min_1(Value::new(Some(int)))
+        fn test(int: i64) -> Vec<ValidationErrorKind> {
+            min_1(ValueRef::new(Some(&int))).errors
         }
     }
 }
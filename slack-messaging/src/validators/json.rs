@@ -0,0 +1,44 @@
+use super::*;
+
+type Json<'a> = ValueRef<'a, serde_json::Value>;
+
+pub(crate) fn object(mut value: Json) -> Json {
+    if value.inner_ref().is_some_and(|v| !v.is_object()) {
+        value.push(ValidationErrorKind::InvalidFormat("JSON object"));
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod fn_object {
+        use super::*;
+
+        #[test]
+        fn it_passes_if_the_value_is_an_object() {
+            let errors = test(serde_json::json!({ "id": 1 }));
+            assert!(errors.is_empty());
+        }
+
+        #[test]
+        fn it_sets_an_error_if_the_value_is_not_an_object() {
+            for value in [
+                serde_json::Value::Null,
+                serde_json::json!("foo"),
+                serde_json::json!([1, 2]),
+            ] {
+                let errors = test(value);
+                assert_eq!(
+                    errors,
+                    vec![ValidationErrorKind::InvalidFormat("JSON object")]
+                );
+            }
+        }
+
+        fn test(value: serde_json::Value) -> Vec<ValidationErrorKind> {
+            object(ValueRef::new(Some(&value))).errors
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::composition_objects::TextExt;
 
 use paste::paste;
 
-type List<T> = Value<Vec<T>>;
+type List<'a, T> = ValueRef<'a, Vec<T>>;
 
 pub(crate) fn inner_validator<T>(
     mut value: List<T>,
@@ -16,16 +16,30 @@ pub(crate) fn inner_validator<T>(
     value
 }
 
-fn max_item<T>(max: usize, value: List<T>) -> List<T> {
-    inner_validator(value, ValidationErrorKind::MaxArraySize(max), |l| {
-        l.len() > max
-    })
+fn max_item<T>(max: usize, mut value: List<T>) -> List<T> {
+    if let Some(actual) = value.inner_ref().map(Vec::len).filter(|&len| len > max) {
+        value.push(ValidationErrorKind::MaxArraySize { max, actual });
+    }
+    value
 }
 
-fn min_item<T>(min: usize, value: List<T>) -> List<T> {
-    inner_validator(value, ValidationErrorKind::MinArraySize(min), |l| {
-        l.len() < min
-    })
+fn min_item<T>(min: usize, mut value: List<T>) -> List<T> {
+    if let Some(actual) = value.inner_ref().map(Vec::len).filter(|&len| len < min) {
+        value.push(ValidationErrorKind::MinArraySize { min, actual });
+    }
+    value
+}
+
+// Validates the text length of each item, reporting the length of the longest one.
+fn each_max<T>(max: usize, mut value: List<T>, len: impl Fn(&T) -> usize) -> List<T> {
+    if let Some(actual) = value
+        .inner_ref()
+        .and_then(|l| l.iter().map(len).max())
+        .filter(|&len| len > max)
+    {
+        value.push(ValidationErrorKind::MaxTextLength { max, actual });
+    }
+    value
 }
 
 macro_rules! impl_max_item {
@@ -61,16 +75,13 @@ pub(crate) fn not_empty<T>(value: List<T>) -> List<T> {
 }
 
 pub(crate) fn each_text_max_2000<T: TextExt>(value: List<T>) -> List<T> {
-    inner_validator(value, ValidationErrorKind::MaxTextLength(2000), |l| {
-        l.iter()
-            .any(|t| t.text().is_some_and(|text| text.len() > 2000))
+    each_max(2000, value, |t| {
+        t.text().map(text_length).unwrap_or_default()
     })
 }
 
 pub(crate) fn each_max_20_chars(value: List<String>) -> List<String> {
-    inner_validator(value, ValidationErrorKind::MaxTextLength(20), |l| {
-        l.iter().any(|s| s.len() > 20)
-    })
+    each_max(20, value, |s| text_length(s))
 }
 
 #[cfg(test)]
@@ -83,19 +94,25 @@ mod tests {
         #[test]
         fn it_passes_if_the_list_length_is_smaller_than_100() {
             let list: Vec<u8> = (0..100).collect();
-            let result = test(list);
-            assert!(result.errors.is_empty());
+            let errors = test(list);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_list_length_is_greater_than_101() {
             let list: Vec<u8> = (0..101).collect();
-            let result = test(list);
-            assert_eq!(result.errors, vec![ValidationErrorKind::MaxArraySize(100)]);
+            let errors = test(list);
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MaxArraySize {
+                    max: 100,
+                    actual: 101
+                }]
+            );
         }
 
-        fn test<T>(list: Vec<T>) -> List<T> {
-            max_item_100(Value::new(Some(list)))
+        fn test<T>(list: Vec<T>) -> Vec<ValidationErrorKind> {
+            max_item_100(ValueRef::new(Some(&list))).errors
         }
     }
 
@@ -105,19 +122,22 @@ mod tests {
         #[test]
         fn it_passes_if_the_list_length_is_greater_than_2() {
             let list: Vec<u8> = (0..3).collect();
-            let result = test(list);
-            assert!(result.errors.is_empty());
+            let errors = test(list);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_list_length_is_smaller_than_2() {
             let list: Vec<u8> = vec![0];
-            let result = test(list);
-            assert_eq!(result.errors, vec![ValidationErrorKind::MinArraySize(2)]);
+            let errors = test(list);
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MinArraySize { min: 2, actual: 1 }]
+            );
         }
 
-        fn test<T>(list: Vec<T>) -> List<T> {
-            min_item_2(Value::new(Some(list)))
+        fn test<T>(list: Vec<T>) -> Vec<ValidationErrorKind> {
+            min_item_2(ValueRef::new(Some(&list))).errors
         }
     }
 
@@ -127,19 +147,19 @@ mod tests {
         #[test]
         fn it_passes_if_the_list_is_not_empty() {
             let list: Vec<u8> = vec![0];
-            let result = test(list);
-            assert!(result.errors.is_empty());
+            let errors = test(list);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_list_is_empty() {
             let list: Vec<u8> = vec![];
-            let result = test(list);
-            assert_eq!(result.errors, vec![ValidationErrorKind::EmptyArray]);
+            let errors = test(list);
+            assert_eq!(errors, vec![ValidationErrorKind::EmptyArray]);
         }
 
-        fn test<T>(list: Vec<T>) -> List<T> {
-            not_empty(Value::new(Some(list)))
+        fn test<T>(list: Vec<T>) -> Vec<ValidationErrorKind> {
+            not_empty(ValueRef::new(Some(&list))).errors
         }
     }
 
@@ -150,26 +170,29 @@ mod tests {
         #[test]
         fn it_passes_if_the_all_text_length_is_less_than_2000() {
             let list = vec!["a".repeat(2000), "foobar".into()];
-            let result = test(list);
-            assert!(result.errors.is_empty());
+            let errors = test(list);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_at_least_one_text_length_is_more_than_2000() {
             let list = vec!["a".repeat(2001), "foobar".into()];
-            let result = test(list);
+            let errors = test(list);
             assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MaxTextLength(2000)]
+                errors,
+                vec![ValidationErrorKind::MaxTextLength {
+                    max: 2000,
+                    actual: 2001
+                }]
             );
         }
 
-        fn test(list: Vec<String>) -> List<Text<Plain>> {
-            let list = list
+        fn test(list: Vec<String>) -> Vec<ValidationErrorKind> {
+            let list: Vec<Text<Plain>> = list
                 .into_iter()
                 .map(|text| Text::builder().text(text).build().unwrap())
                 .collect();
-            each_text_max_2000(Value::new(Some(list)))
+            each_text_max_2000(ValueRef::new(Some(&list))).errors
         }
     }
 
@@ -179,22 +202,45 @@ mod tests {
         #[test]
         fn it_passes_if_the_all_item_length_is_less_than_20() {
             let list = vec!["a".repeat(20), "foobar".into()];
-            let result = test(list);
-            assert!(result.errors.is_empty());
+            let errors = test(list);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_at_least_one_item_length_is_more_than_20() {
             let list = vec!["a".repeat(21), "foobar".into()];
-            let result = test(list);
+            let errors = test(list);
             assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MaxTextLength(20)]
+                errors,
+                vec![ValidationErrorKind::MaxTextLength {
+                    max: 20,
+                    actual: 21
+                }]
             );
         }
 
-        fn test(list: Vec<String>) -> List<String> {
-            each_max_20_chars(Value::new(Some(list)))
+        #[test]
+        fn it_reports_the_length_of_the_longest_item() {
+            let list = vec!["a".repeat(21), "a".repeat(25), "foobar".into()];
+            let errors = test(list);
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MaxTextLength {
+                    max: 20,
+                    actual: 25
+                }]
+            );
+        }
+
+        #[test]
+        fn it_counts_multibyte_characters_as_one() {
+            let list = vec!["ö".repeat(20), "foobar".into()];
+            let errors = test(list);
+            assert!(errors.is_empty());
+        }
+
+        fn test(list: Vec<String>) -> Vec<ValidationErrorKind> {
+            each_max_20_chars(ValueRef::new(Some(&list))).errors
         }
     }
 }
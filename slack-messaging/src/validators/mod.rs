@@ -1,14 +1,39 @@
+use crate::composition_objects::TextExt;
 use crate::errors::ValidationErrorKind;
-use crate::value::Value;
+use crate::value::ValueRef;
 
 pub(crate) mod integer;
+pub(crate) mod json;
 pub(crate) mod list;
 pub(crate) mod number;
 pub(crate) mod rich_text;
+pub(crate) mod surface;
 pub(crate) mod text;
 pub(crate) mod text_object;
 
-pub(crate) fn required<T>(mut value: Value<T>) -> Value<T> {
+/// Counts the length of the text in characters as Slack does, not in UTF-8 bytes.
+pub(crate) fn text_length(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Length of text values counted by [`text_length`].
+pub(crate) trait TextLength {
+    fn text_length(&self) -> usize;
+}
+
+impl TextLength for String {
+    fn text_length(&self) -> usize {
+        text_length(self)
+    }
+}
+
+impl<T: TextExt> TextLength for T {
+    fn text_length(&self) -> usize {
+        self.text().map(text_length).unwrap_or_default()
+    }
+}
+
+pub(crate) fn required<T>(mut value: ValueRef<T>) -> ValueRef<T> {
     if value.inner_ref().is_none() {
         value.push(ValidationErrorKind::Required);
     }
@@ -21,8 +46,16 @@ mod tests {
 
     #[test]
     fn required_sets_error_if_the_value_is_none() {
-        let value: Value<String> = Value::new(None);
+        let value: ValueRef<String> = ValueRef::new(None);
         let result = required(value);
         assert_eq!(result.errors, vec![ValidationErrorKind::Required]);
     }
+
+    #[test]
+    fn text_length_counts_characters_instead_of_bytes() {
+        assert_eq!(text_length("hello"), 5);
+        assert_eq!(text_length("こんにちは"), 5);
+        assert_eq!(text_length("👍🏽"), 2);
+        assert_eq!(text_length(""), 0);
+    }
 }
@@ -1,6 +1,6 @@
 use super::*;
 
-type Number = Value<serde_json::Number>;
+type Number<'a> = ValueRef<'a, serde_json::Number>;
 
 fn inner_validator(
     mut value: Number,
@@ -36,63 +36,48 @@ mod tests {
 
         #[test]
         fn it_sets_an_error_if_the_value_is_less_than_or_equal_to_zero() {
-            let result = subject_u64(0);
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MustBeGreaterThanZero]
-            );
-
-            let result = subject_f64(0.0);
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MustBeGreaterThanZero]
-            );
-
-            let result = subject_f64(-0.1);
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MustBeGreaterThanZero]
-            );
-
-            let result = subject_i64(0);
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MustBeGreaterThanZero]
-            );
-
-            let result = subject_i64(-1);
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MustBeGreaterThanZero]
-            );
+            let errors = subject_u64(0);
+            assert_eq!(errors, vec![ValidationErrorKind::MustBeGreaterThanZero]);
+
+            let errors = subject_f64(0.0);
+            assert_eq!(errors, vec![ValidationErrorKind::MustBeGreaterThanZero]);
+
+            let errors = subject_f64(-0.1);
+            assert_eq!(errors, vec![ValidationErrorKind::MustBeGreaterThanZero]);
+
+            let errors = subject_i64(0);
+            assert_eq!(errors, vec![ValidationErrorKind::MustBeGreaterThanZero]);
+
+            let errors = subject_i64(-1);
+            assert_eq!(errors, vec![ValidationErrorKind::MustBeGreaterThanZero]);
         }
 
         #[test]
         fn it_passes_if_the_value_is_greater_than_zero() {
-            let result = subject_u64(1);
-            assert!(result.errors.is_empty());
+            let errors = subject_u64(1);
+            assert!(errors.is_empty());
 
-            let result = subject_i64(1);
-            assert!(result.errors.is_empty());
+            let errors = subject_i64(1);
+            assert!(errors.is_empty());
 
-            let result = subject_f64(0.1);
-            assert!(result.errors.is_empty());
+            let errors = subject_f64(0.1);
+            assert!(errors.is_empty());
         }
 
-        fn subject_u64(n: u64) -> Number {
+        fn subject_u64(n: u64) -> Vec<ValidationErrorKind> {
             subject(n.into())
         }
 
-        fn subject_i64(n: i64) -> Number {
+        fn subject_i64(n: i64) -> Vec<ValidationErrorKind> {
             subject(n.into())
         }
 
-        fn subject_f64(n: f64) -> Number {
+        fn subject_f64(n: f64) -> Vec<ValidationErrorKind> {
             subject(serde_json::Number::from_f64(n).unwrap())
         }
 
-        fn subject(num: serde_json::Number) -> Number {
-            greater_than_zero(Value::new(Some(num)))
+        fn subject(num: serde_json::Number) -> Vec<ValidationErrorKind> {
+            greater_than_zero(ValueRef::new(Some(&num))).errors
         }
     }
 }
@@ -2,10 +2,10 @@ use super::*;
 use crate::blocks::RichText;
 
 fn inner_validator<F>(
-    mut value: Value<RichText>,
+    mut value: ValueRef<RichText>,
     error: ValidationErrorKind,
     f: F,
-) -> Value<RichText>
+) -> ValueRef<RichText>
 where
     F: Fn(&RichText) -> bool,
 {
@@ -15,7 +15,7 @@ where
     value
 }
 
-pub(crate) fn single_element(value: Value<RichText>) -> Value<RichText> {
+pub(crate) fn single_element(value: ValueRef<RichText>) -> ValueRef<RichText> {
     inner_validator(value, ValidationErrorKind::RichTextSingleElement, |v| {
         v.elements.as_ref().is_none_or(|e| e.len() != 1)
     })
@@ -32,52 +32,43 @@ mod tests {
         #[test]
         fn it_passes_if_elements_field_has_exactly_one_element() {
             let elements = vec![rich_text_section()];
-            let result = test(Some(elements));
-            assert!(result.errors.is_empty());
+            let errors = test(Some(elements));
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_elements_field_has_no_elements() {
             let elements = vec![];
-            let result = test(Some(elements));
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::RichTextSingleElement]
-            );
+            let errors = test(Some(elements));
+            assert_eq!(errors, vec![ValidationErrorKind::RichTextSingleElement]);
         }
 
         #[test]
         fn it_sets_an_error_if_elements_field_has_more_than_one_element() {
             let elements = vec![rich_text_section(), rich_text_section()];
-            let result = test(Some(elements));
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::RichTextSingleElement]
-            );
+            let errors = test(Some(elements));
+            assert_eq!(errors, vec![ValidationErrorKind::RichTextSingleElement]);
         }
 
         #[test]
         fn it_sets_an_error_if_elements_field_is_none() {
-            let result = test(None);
-            assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::RichTextSingleElement]
-            );
+            let errors = test(None);
+            assert_eq!(errors, vec![ValidationErrorKind::RichTextSingleElement]);
         }
 
         fn rich_text_section() -> RichTextSubElement {
             RichTextSection { elements: None }.into()
         }
 
-        fn rich_text(elements: Option<Vec<RichTextSubElement>>) -> Value<RichText> {
-            Value::new(Some(RichText {
+        fn rich_text(elements: Option<Vec<RichTextSubElement>>) -> RichText {
+            RichText {
                 block_id: None,
                 elements,
-            }))
+            }
         }
 
-        fn test(elements: Option<Vec<RichTextSubElement>>) -> Value<RichText> {
-            single_element(rich_text(elements))
+        fn test(elements: Option<Vec<RichTextSubElement>>) -> Vec<ValidationErrorKind> {
+            single_element(ValueRef::new(Some(&rich_text(elements)))).errors
         }
     }
 }
@@ -0,0 +1,67 @@
+use super::*;
+use crate::Surface;
+use crate::blocks::Block;
+
+type Blocks<'a> = ValueRef<'a, Vec<Block>>;
+
+fn available_in(surface: Surface, mut value: Blocks) -> Blocks {
+    let errors = value
+        .inner_ref()
+        .map(|blocks| surface.validate(blocks))
+        .unwrap_or_default();
+
+    for error in errors {
+        value.push(error);
+    }
+    value
+}
+
+pub(crate) fn message(value: Blocks) -> Blocks {
+    available_in(Surface::Message, value)
+}
+
+pub(crate) fn modal(value: Blocks) -> Blocks {
+    available_in(Surface::Modal, value)
+}
+
+pub(crate) fn home(value: Blocks) -> Blocks {
+    available_in(Surface::Home, value)
+}
+
+pub(crate) fn attachment(value: Blocks) -> Blocks {
+    available_in(Surface::Attachment, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::test_helpers::*;
+
+    #[test]
+    fn it_sets_errors_for_blocks_not_available_in_the_surface() {
+        let blocks: Vec<Block> = vec![section("foo").into(), task_card().into()];
+
+        let result = message(ValueRef::new(Some(&blocks)));
+        assert!(result.errors.is_empty());
+
+        let result = modal(ValueRef::new(Some(&blocks)));
+        assert_eq!(
+            result.errors,
+            vec![ValidationErrorKind::UnsupportedBlock(
+                1,
+                "task_card",
+                Surface::Modal
+            )]
+        );
+
+        let result = home(ValueRef::new(Some(&blocks)));
+        assert_eq!(
+            result.errors,
+            vec![ValidationErrorKind::UnsupportedBlock(
+                1,
+                "task_card",
+                Surface::Home
+            )]
+        );
+    }
+}
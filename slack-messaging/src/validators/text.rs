@@ -6,7 +6,7 @@ use paste::paste;
 use regex::Regex;
 use std::error::Error;
 
-type Text = Value<String>;
+type Text<'a> = ValueRef<'a, String>;
 
 static DATE_FORMAT: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^(?x)(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})$").unwrap());
@@ -14,6 +14,12 @@ static DATE_FORMAT: Lazy<Regex> =
 static TIME_FORMAT: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^(?x)(?P<hour>\d{2}):(?P<minute>\d{2})$").unwrap());
 
+static COLOR_FORMAT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(good|warning|danger|#?[0-9a-fA-F]{6}|#?[0-9a-fA-F]{3})$").unwrap());
+
+static EVENT_TYPE_FORMAT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-z0-9]+(_[a-z0-9]+)*$").unwrap());
+
 fn inner_validator(
     mut value: Text,
     error: ValidationErrorKind,
@@ -50,28 +56,37 @@ fn is_invalid_time(text: &str) -> bool {
     validate_time(text).is_err()
 }
 
+fn max(max: usize, mut value: Text) -> Text {
+    if let Some(actual) = value
+        .inner_ref()
+        .map(|v| text_length(v))
+        .filter(|&len| len > max)
+    {
+        value.push(ValidationErrorKind::MaxTextLength { max, actual });
+    }
+    value
+}
+
 macro_rules! impl_max {
     ($($e:expr),*) => {
         paste! {
             $(
                 pub(crate) fn [<max_ $e>](value: Text) -> Text {
-                    inner_validator(
-                        value,
-                        ValidationErrorKind::MaxTextLength($e),
-                        |v| { v.len() > $e }
-                    )
+                    max($e, value)
                 }
             )*
         }
     }
 }
 
-impl_max!(20, 50, 75, 150, 255, 2000, 3000, 12000);
+impl_max!(20, 50, 75, 150, 255, 300, 2000, 3000, 12000);
 
 pub(crate) fn min_1(value: Text) -> Text {
-    inner_validator(value, ValidationErrorKind::MinTextLength(1), |v| {
-        v.is_empty()
-    })
+    inner_validator(
+        value,
+        ValidationErrorKind::MinTextLength { min: 1, actual: 0 },
+        |v| v.is_empty(),
+    )
 }
 
 pub(crate) fn date_format(value: Text) -> Text {
@@ -90,6 +105,22 @@ pub(crate) fn time_format(value: Text) -> Text {
     )
 }
 
+pub(crate) fn color_format(value: Text) -> Text {
+    inner_validator(
+        value,
+        ValidationErrorKind::InvalidFormat("hex color code or good, warning, danger"),
+        |v| !COLOR_FORMAT.is_match(v),
+    )
+}
+
+pub(crate) fn event_type_format(value: Text) -> Text {
+    inner_validator(
+        value,
+        ValidationErrorKind::InvalidFormat("snake_case alphanumeric"),
+        |v| !EVENT_TYPE_FORMAT.is_match(v),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,10 +132,13 @@ mod tests {
         fn it_sets_an_error_if_the_value_has_more_than_3000_characters() {
             let text = "a".repeat(3001);
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
-                vec![ValidationErrorKind::MaxTextLength(3000)]
+                errors,
+                vec![ValidationErrorKind::MaxTextLength {
+                    max: 3000,
+                    actual: 3001
+                }]
             );
         }
 
@@ -112,12 +146,27 @@ mod tests {
         fn it_passes_if_the_value_has_less_than_3000_characters() {
             let text = "a".repeat(3000);
 
-            let result = test(text);
-            assert!(result.errors.is_empty());
+            let errors = test(text);
+            assert!(errors.is_empty());
+        }
+
+        #[test]
+        fn it_counts_multibyte_characters_as_one() {
+            let errors = test("あ".repeat(3000));
+            assert!(errors.is_empty());
+
+            let errors = test("あ".repeat(3001));
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MaxTextLength {
+                    max: 3000,
+                    actual: 3001
+                }]
+            );
         }
 
-        fn test(text: impl Into<String>) -> Text {
-            max_3000(Value::new(Some(text.into())))
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            max_3000(ValueRef::new(Some(&text.into()))).errors
         }
     }
 
@@ -128,20 +177,23 @@ mod tests {
         fn it_sets_an_error_if_the_value_is_empty_string() {
             let text = "".to_string();
 
-            let result = test(text);
-            assert_eq!(result.errors, vec![ValidationErrorKind::MinTextLength(1)]);
+            let errors = test(text);
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MinTextLength { min: 1, actual: 0 }]
+            );
         }
 
         #[test]
         fn it_passes_if_the_value_has_more_than_1_characters() {
             let text = "a".to_string();
 
-            let result = test(text);
-            assert!(result.errors.is_empty());
+            let errors = test(text);
+            assert!(errors.is_empty());
         }
 
-        fn test(text: impl Into<String>) -> Text {
-            min_1(Value::new(Some(text.into())))
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            min_1(ValueRef::new(Some(&text.into()))).errors
         }
     }
 
@@ -152,17 +204,17 @@ mod tests {
         fn it_passes_if_the_value_match_the_date_format() {
             let text = "2010-03-14";
 
-            let result = test(text);
-            assert!(result.errors.is_empty());
+            let errors = test(text);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_value_is_invalid_date() {
             let text = "2015-02-29";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("YYYY-MM-DD")]
             );
         }
@@ -171,23 +223,23 @@ mod tests {
         fn it_set_an_error_if_the_value_does_not_match_the_date_format() {
             let text = "foobar";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("YYYY-MM-DD")]
             );
 
             let text = "foo2025-12-11bar";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("YYYY-MM-DD")]
             );
         }
 
-        fn test(text: impl Into<String>) -> Text {
-            date_format(Value::new(Some(text.into())))
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            date_format(ValueRef::new(Some(&text.into()))).errors
         }
     }
 
@@ -198,38 +250,38 @@ mod tests {
         fn it_passes_if_the_value_match_the_time_format() {
             let text = "00:00";
 
-            let result = test(text);
-            assert!(result.errors.is_empty());
+            let errors = test(text);
+            assert!(errors.is_empty());
 
             let text = "23:59";
 
-            let result = test(text);
-            assert!(result.errors.is_empty());
+            let errors = test(text);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_value_is_invalid_time() {
             let text = "24:00";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("24-hour format HH:mm")]
             );
 
             let text = "23:60";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("24-hour format HH:mm")]
             );
 
             let text = "0:0";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("24-hour format HH:mm")]
             );
         }
@@ -238,23 +290,88 @@ mod tests {
         fn it_set_an_error_if_the_value_does_not_match_the_time_format() {
             let text = "foobar";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("24-hour format HH:mm")]
             );
 
             let text = "foo12:30bar";
 
-            let result = test(text);
+            let errors = test(text);
             assert_eq!(
-                result.errors,
+                errors,
                 vec![ValidationErrorKind::InvalidFormat("24-hour format HH:mm")]
             );
         }
 
-        fn test(text: impl Into<String>) -> Text {
-            time_format(Value::new(Some(text.into())))
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            time_format(ValueRef::new(Some(&text.into()))).errors
+        }
+    }
+
+    mod fn_color_format {
+        use super::*;
+
+        #[test]
+        fn it_passes_if_the_value_is_hex_color_code_or_predefined_color() {
+            for text in ["#439FE0", "439fe0", "#fff", "good", "warning", "danger"] {
+                let errors = test(text);
+                assert!(errors.is_empty());
+            }
+        }
+
+        #[test]
+        fn it_sets_an_error_if_the_value_is_not_a_color() {
+            for text in ["#439FE", "red", "#GGGGGG", "good!"] {
+                let errors = test(text);
+                assert_eq!(
+                    errors,
+                    vec![ValidationErrorKind::InvalidFormat(
+                        "hex color code or good, warning, danger"
+                    )]
+                );
+            }
+        }
+
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            color_format(ValueRef::new(Some(&text.into()))).errors
+        }
+    }
+
+    mod fn_event_type_format {
+        use super::*;
+
+        #[test]
+        fn it_passes_if_the_value_is_snake_case() {
+            for text in ["task_created", "deploy", "incident_2_resolved"] {
+                let errors = test(text);
+                assert!(errors.is_empty());
+            }
+        }
+
+        #[test]
+        fn it_sets_an_error_if_the_value_is_not_snake_case() {
+            for text in [
+                "",
+                "TaskCreated",
+                "task-created",
+                "task created",
+                "_task",
+                "task__created",
+            ] {
+                let errors = test(text);
+                assert_eq!(
+                    errors,
+                    vec![ValidationErrorKind::InvalidFormat(
+                        "snake_case alphanumeric"
+                    )]
+                );
+            }
+        }
+
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            event_type_format(ValueRef::new(Some(&text.into()))).errors
         }
     }
 }
@@ -3,7 +3,7 @@ use crate::composition_objects::TextExt;
 
 use paste::paste;
 
-fn inner_validator<T, F>(mut value: Value<T>, error: ValidationErrorKind, f: F) -> Value<T>
+fn inner_validator<T, F>(mut value: ValueRef<T>, error: ValidationErrorKind, f: F) -> ValueRef<T>
 where
     T: TextExt,
     F: Fn(&str) -> bool,
@@ -14,17 +14,23 @@ where
     value
 }
 
-fn max<T: TextExt>(max: usize, value: Value<T>) -> Value<T> {
-    inner_validator(value, ValidationErrorKind::MaxTextLength(max), |t| {
-        t.len() > max
-    })
+fn max<T: TextExt>(max: usize, mut value: ValueRef<T>) -> ValueRef<T> {
+    if let Some(actual) = value
+        .inner_ref()
+        .and_then(|v| v.text())
+        .map(text_length)
+        .filter(|&len| len > max)
+    {
+        value.push(ValidationErrorKind::MaxTextLength { max, actual });
+    }
+    value
 }
 
 macro_rules! impl_max {
     ($($e:expr),*) => {
         paste! {
             $(
-                pub(crate) fn [<max_ $e>]<T: TextExt>(value: Value<T>) -> Value<T> {
+                pub(crate) fn [<max_ $e>]<T: TextExt>(value: ValueRef<T>) -> ValueRef<T> {
                     max($e, value)
                 }
             )*
@@ -32,12 +38,14 @@ macro_rules! impl_max {
     }
 }
 
-impl_max!(30, 75, 100, 150, 200, 300, 2000, 3000);
+impl_max!(24, 30, 75, 100, 150, 200, 300, 2000, 3000);
 
-pub(crate) fn min_1<T: TextExt>(value: Value<T>) -> Value<T> {
-    inner_validator(value, ValidationErrorKind::MinTextLength(1), |t| {
-        t.is_empty()
-    })
+pub(crate) fn min_1<T: TextExt>(value: ValueRef<T>) -> ValueRef<T> {
+    inner_validator(
+        value,
+        ValidationErrorKind::MinTextLength { min: 1, actual: 0 },
+        |t| t.is_empty(),
+    )
 }
 
 #[cfg(test)]
@@ -45,13 +53,13 @@ mod tests {
     use super::*;
     use crate::composition_objects::{Plain, Text};
 
-    fn plain_text(text: impl Into<String>) -> Value<Text<Plain>> {
-        Value::new(Some(Text::<Plain> {
+    fn plain_text(text: impl Into<String>) -> Text<Plain> {
+        Text::<Plain> {
             r#type: std::marker::PhantomData,
             text: Some(text.into()),
             emoji: None,
             verbatim: None,
-        }))
+        }
     }
 
     mod fn_max_30 {
@@ -60,19 +68,31 @@ mod tests {
         #[test]
         fn it_passes_if_the_text_length_is_smaller_than_30() {
             let text = "a".repeat(30);
-            let result = test(text);
-            assert!(result.errors.is_empty());
+            let errors = test(text);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_text_length_is_greater_than_31() {
             let text = "a".repeat(31);
-            let result = test(text);
-            assert_eq!(result.errors, vec![ValidationErrorKind::MaxTextLength(30)]);
+            let errors = test(text);
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MaxTextLength {
+                    max: 30,
+                    actual: 31
+                }]
+            );
+        }
+
+        #[test]
+        fn it_counts_multibyte_characters_as_one() {
+            let errors = test("日本語".repeat(10));
+            assert!(errors.is_empty());
         }
 
-        fn test(text: impl Into<String>) -> Value<Text<Plain>> {
-            max_30(plain_text(text))
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            max_30(ValueRef::new(Some(&plain_text(text)))).errors
         }
     }
 
@@ -82,19 +102,22 @@ mod tests {
         #[test]
         fn it_passes_if_the_text_length_is_greater_than_1() {
             let text = "a";
-            let result = test(text);
-            assert!(result.errors.is_empty());
+            let errors = test(text);
+            assert!(errors.is_empty());
         }
 
         #[test]
         fn it_sets_an_error_if_the_text_length_is_smaller_than_0() {
             let text = "";
-            let result = test(text);
-            assert_eq!(result.errors, vec![ValidationErrorKind::MinTextLength(1)]);
+            let errors = test(text);
+            assert_eq!(
+                errors,
+                vec![ValidationErrorKind::MinTextLength { min: 1, actual: 0 }]
+            );
         }
 
-        fn test(text: impl Into<String>) -> Value<Text<Plain>> {
-            min_1(plain_text(text))
+        fn test(text: impl Into<String>) -> Vec<ValidationErrorKind> {
+            min_1(ValueRef::new(Some(&plain_text(text)))).errors
         }
     }
 }
@@ -21,6 +21,27 @@ impl<T> Value<T> {
     pub(crate) fn take_inner(&mut self) -> Option<T> {
         self.inner.take()
     }
+}
+
+// Borrowed value the validators of a field check, collecting the errors. Validators take it
+// rather than Value so that built objects are validated without cloning their fields.
+#[derive(Debug)]
+pub(crate) struct ValueRef<'a, T> {
+    pub(crate) inner: Option<&'a T>,
+    pub(crate) errors: Vec<ValidationErrorKind>,
+}
+
+impl<'a, T> ValueRef<'a, T> {
+    pub(crate) fn new(inner: Option<&'a T>) -> Self {
+        Self {
+            inner,
+            errors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn inner_ref(&self) -> Option<&'a T> {
+        self.inner
+    }
 
     pub(crate) fn push(&mut self, error: ValidationErrorKind) {
         self.errors.push(error);
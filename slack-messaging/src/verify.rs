@@ -0,0 +1,250 @@
+use crate::errors::VerificationError;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Name of the header containing the signature of the request.
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+
+/// Name of the header containing the timestamp of the request.
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+
+/// Default replay window in seconds.
+pub const DEFAULT_TOLERANCE: u64 = 60 * 5;
+
+const VERSION: &str = "v0";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// [Verifier](https://docs.slack.dev/authentication/verifying-requests-from-slack) of the
+/// requests Slack sends to your app.
+///
+/// It computes the `v0` HMAC-SHA256 signature over the raw request body with your app's
+/// signing secret, compares it with the `X-Slack-Signature` header in constant time and rejects
+/// requests whose `X-Slack-Request-Timestamp` is outside of the replay window (5 minutes by
+/// default).
+///
+/// # Example
+///
+/// ```
+/// use slack_messaging::errors::VerificationError;
+/// use slack_messaging::verify::Verifier;
+///
+/// let verifier = Verifier::new("8f742231b10e8888abcd99yyyzzz85a5");
+///
+/// let timestamp = "1531420618";
+/// let body = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J";
+/// let signature = verifier.sign(timestamp, body);
+///
+/// assert_eq!(verifier.verify_at(timestamp, &signature, body, 1531420618), Ok(()));
+///
+/// // Requests older than the replay window are rejected.
+/// assert!(matches!(
+///     verifier.verify_at(timestamp, &signature, body, 1531420618 + 301),
+///     Err(VerificationError::Expired { .. }),
+/// ));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Verifier {
+    secret: String,
+    tolerance: u64,
+}
+
+// The signing secret must not end up in logs.
+impl fmt::Debug for Verifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Verifier")
+            .field("secret", &"[REDACTED]")
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+impl Verifier {
+    /// Constructs a verifier with the signing secret of your app.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Sets the replay window in seconds.
+    pub fn tolerance(mut self, seconds: u64) -> Self {
+        self.tolerance = seconds;
+        self
+    }
+
+    /// Verifies the request with the values of `X-Slack-Request-Timestamp` and
+    /// `X-Slack-Signature` headers and the raw body, against the current system time.
+    pub fn verify(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), VerificationError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        self.verify_at(timestamp, signature, body, now)
+    }
+
+    /// Same as [`verify`](Self::verify) but checks the replay window against the given unix
+    /// timestamp instead of the current system time.
+    pub fn verify_at(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+        now: i64,
+    ) -> Result<(), VerificationError> {
+        let ts: i64 = timestamp
+            .trim()
+            .parse()
+            .map_err(|_| VerificationError::InvalidTimestamp(timestamp.into()))?;
+
+        if ts.abs_diff(now) > self.tolerance {
+            return Err(VerificationError::Expired {
+                timestamp: ts,
+                now,
+                tolerance: self.tolerance,
+            });
+        }
+
+        let digest = signature
+            .strip_prefix(VERSION)
+            .and_then(|s| s.strip_prefix('='))
+            .and_then(|s| hex::decode(s).ok())
+            .ok_or_else(|| VerificationError::InvalidSignatureFormat(signature.into()))?;
+
+        self.mac(timestamp, body)
+            .verify_slice(&digest)
+            .map_err(|_| VerificationError::SignatureMismatch)
+    }
+
+    /// Computes the `v0` signature of the request. This is useful to build requests in tests.
+    pub fn sign(&self, timestamp: &str, body: &[u8]) -> String {
+        let digest = self.mac(timestamp, body).finalize().into_bytes();
+        format!("{VERSION}={}", hex::encode(digest))
+    }
+
+    fn mac(&self, timestamp: &str, body: &[u8]) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(VERSION.as_bytes());
+        mac.update(b":");
+        mac.update(timestamp.as_bytes());
+        mac.update(b":");
+        mac.update(body);
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // https://docs.slack.dev/authentication/verifying-requests-from-slack
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: &str = "1531420618";
+    const BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+    #[test]
+    fn it_redacts_the_secret_in_debug_output() {
+        let debug = format!("{:?}", Verifier::new(SECRET));
+
+        assert!(!debug.contains(SECRET));
+        assert_eq!(debug, "Verifier { secret: \"[REDACTED]\", tolerance: 300 }");
+    }
+
+    #[test]
+    fn it_signs_the_request_as_slack_does() {
+        let verifier = Verifier::new(SECRET);
+        assert_eq!(verifier.sign(TIMESTAMP, BODY.as_bytes()), SIGNATURE);
+    }
+
+    #[test]
+    fn it_verifies_slack_test_vector() {
+        let verifier = Verifier::new(SECRET);
+        let result = verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn it_rejects_tampered_body() {
+        let verifier = Verifier::new(SECRET);
+        let body = BODY.replace("roadrunner", "coyote");
+        let result = verifier.verify_at(TIMESTAMP, SIGNATURE, body.as_bytes(), 1531420618);
+        assert_eq!(result, Err(VerificationError::SignatureMismatch));
+    }
+
+    #[test]
+    fn it_rejects_wrong_secret() {
+        let verifier = Verifier::new("another-secret");
+        let result = verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618);
+        assert_eq!(result, Err(VerificationError::SignatureMismatch));
+    }
+
+    #[test]
+    fn it_rejects_requests_outside_of_the_replay_window() {
+        let verifier = Verifier::new(SECRET);
+
+        let result = verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618 + 300);
+        assert_eq!(result, Ok(()));
+
+        let result = verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618 + 301);
+        assert_eq!(
+            result,
+            Err(VerificationError::Expired {
+                timestamp: 1531420618,
+                now: 1531420618 + 301,
+                tolerance: 300,
+            })
+        );
+
+        let result = verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618 - 301);
+        assert!(matches!(result, Err(VerificationError::Expired { .. })));
+    }
+
+    #[test]
+    fn it_applies_configured_tolerance() {
+        let verifier = Verifier::new(SECRET).tolerance(10);
+
+        let result = verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618 + 11);
+        assert!(matches!(
+            result,
+            Err(VerificationError::Expired { tolerance: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn it_rejects_invalid_timestamp() {
+        let verifier = Verifier::new(SECRET);
+        let result = verifier.verify_at("yesterday", SIGNATURE, BODY.as_bytes(), 1531420618);
+        assert_eq!(
+            result,
+            Err(VerificationError::InvalidTimestamp("yesterday".into()))
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_signature_format() {
+        let verifier = Verifier::new(SECRET);
+
+        for signature in [
+            "a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            "v1=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            "v0=not-hex",
+        ] {
+            let result = verifier.verify_at(TIMESTAMP, signature, BODY.as_bytes(), 1531420618);
+            assert_eq!(
+                result,
+                Err(VerificationError::InvalidSignatureFormat(signature.into()))
+            );
+        }
+    }
+}